@@ -188,6 +188,7 @@ fn test_chart_data_updates() {
             tot_in_packets_prev: tot_received,
             logged_notifications: Default::default(),
             tot_emitted_notifications: 0,
+            traffic_baseline: Default::default(),
         };
 
         assert_eq!(get_min(&sent), -1000.0);
@@ -55,6 +55,8 @@ mod tests {
 
     use crate::gui::styles::types::custom_palette::ExtraStyles;
     use crate::gui::styles::types::gradient_type::GradientType;
+    use crate::networking::types::port_scan::ScanThresholds;
+    use crate::networking::types::tunnel::TunnelAccounting;
     use crate::notifications::types::notifications::Notifications;
     use crate::{ConfigDevice, ConfigSettings, ConfigWindow, Language, Sniffer, StyleType};
 
@@ -81,6 +83,22 @@ fn test_restore_default_configs() {
                     packets_notification: Default::default(),
                     bytes_notification: Default::default(),
                     favorite_notification: Default::default(),
+                    dhcp_notification: Default::default(),
+                    blocklist_notification: Default::default(),
+                    port_scan_notification: Default::default(),
+                    anomaly_notification: Default::default(),
+                    beacon_notification: Default::default(),
+                    exfiltration_notification: Default::default(),
+                    rule_notification: Default::default(),
+                },
+                tunnel_accounting: TunnelAccounting::Inner,
+                blocklists_dir: "blocklists".to_string(),
+                blocklists_reload: 60,
+                rules_file: "rules.toml".to_string(),
+                scan_thresholds: ScanThresholds {
+                    window: 10,
+                    hosts: 10,
+                    ports: 100,
                 },
                 style: StyleType::Custom(ExtraStyles::DraculaDark),
             },
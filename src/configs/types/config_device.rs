@@ -60,6 +60,7 @@ pub fn to_my_device(&self) -> MyDevice {
                     desc: device.desc,
                     addresses: Arc::new(Mutex::new(device.addresses)),
                     link_type: MyLinkType::default(),
+                    netns: None,
                 };
             }
         }
@@ -75,6 +76,7 @@ pub fn to_my_device(&self) -> MyDevice {
             desc: standard_device.desc,
             addresses: Arc::new(Mutex::new(standard_device.addresses)),
             link_type: MyLinkType::default(),
+            netns: None,
         }
     }
 }
@@ -4,6 +4,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::gui::styles::types::gradient_type::GradientType;
+use crate::networking::types::port_scan::ScanThresholds;
+use crate::networking::types::tunnel::TunnelAccounting;
 use crate::notifications::types::notifications::Notifications;
 #[cfg(not(test))]
 use crate::SNIFFNET_LOWERCASE;
@@ -18,6 +20,13 @@ pub struct ConfigSettings {
     pub mmdb_asn: String,
     pub style_path: String,
     pub notifications: Notifications,
+    pub tunnel_accounting: TunnelAccounting,
+    pub blocklists_dir: String,
+    /// Minutes between two reloads of the blocklists (0 means never)
+    pub blocklists_reload: u32,
+    /// TOML file of the custom alert rules
+    pub rules_file: String,
+    pub scan_thresholds: ScanThresholds,
     // StyleType should be last in order to deserialize as a table properly
     pub style: StyleType,
 }
@@ -56,6 +65,11 @@ fn default() -> Self {
             mmdb_asn: String::new(),
             style_path: String::new(),
             notifications: Notifications::default(),
+            tunnel_accounting: TunnelAccounting::default(),
+            blocklists_dir: String::new(),
+            blocklists_reload: 0,
+            rules_file: String::new(),
+            scan_thresholds: ScanThresholds::default(),
             style: StyleType::default(),
         }
     }
@@ -9,7 +9,10 @@
 use crate::gui::components::header::header;
 use crate::gui::components::modal::{get_clear_all_overlay, get_exit_overlay, Modal};
 use crate::gui::components::types::my_modal::MyModal;
+use crate::gui::pages::beacons_page::beacons_page;
 use crate::gui::pages::connection_details_page::connection_details_page;
+use crate::gui::pages::devices_page::devices_page;
+use crate::gui::pages::dns_page::dns_page;
 use crate::gui::pages::initial_page::initial_page;
 use crate::gui::pages::inspect_page::inspect_page;
 use crate::gui::pages::notifications_page::notifications_page;
@@ -67,6 +70,9 @@ fn view(&self) -> Element<Message, StyleType> {
                 RunningPage::Init => initial_page(self),
                 RunningPage::Overview => overview_page(self),
                 RunningPage::Inspect => inspect_page(self),
+                RunningPage::Dns => dns_page(self),
+                RunningPage::Devices => devices_page(self),
+                RunningPage::Beacons => beacons_page(self),
                 RunningPage::Notifications => notifications_page(self),
             }
         };
@@ -5,6 +5,7 @@
 use iced::widget::{button, horizontal_space, Button, Container, Row, Space, Text};
 use iced::{alignment, Alignment, Font, Length};
 
+use crate::gui::pages::types::connection_details_tab::ConnectionDetailsTab;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::button::ButtonType;
 use crate::gui::styles::container::ContainerType;
@@ -31,6 +32,24 @@ pub fn get_settings_tabs(
     tabs
 }
 
+pub fn get_connection_details_tabs(
+    active: ConnectionDetailsTab,
+    font: Font,
+    language: Language,
+) -> Row<'static, Message, StyleType> {
+    let mut tabs = Row::new()
+        .width(Length::Fill)
+        .align_items(Alignment::Start)
+        .spacing(2)
+        .padding([0, 3]);
+
+    for tab in &ConnectionDetailsTab::ALL {
+        let active = tab.eq(&active);
+        tabs = tabs.push(new_connection_details_tab(*tab, active, language, font));
+    }
+    tabs
+}
+
 pub fn get_pages_tabs(
     active: RunningPage,
     font: Font,
@@ -170,6 +189,54 @@ fn new_settings_tab(
         .on_press(page.action())
 }
 
+fn new_connection_details_tab(
+    tab: ConnectionDetailsTab,
+    active: bool,
+    language: Language,
+    font: Font,
+) -> Button<'static, Message, StyleType> {
+    let content = Row::new()
+        .height(Length::Fill)
+        .align_items(Alignment::Center)
+        .push(horizontal_space())
+        .push(
+            tab.icon()
+                .size(15)
+                .style(if active {
+                    TextType::Title
+                } else {
+                    TextType::Standard
+                })
+                .horizontal_alignment(alignment::Horizontal::Center)
+                .vertical_alignment(alignment::Vertical::Center),
+        )
+        .push(Space::with_width(10))
+        .push(
+            Text::new(tab.get_tab_label(language).to_string())
+                .font(font)
+                .size(FONT_SIZE_SUBTITLE)
+                .style(if active {
+                    TextType::Title
+                } else {
+                    TextType::Standard
+                })
+                .horizontal_alignment(alignment::Horizontal::Center)
+                .vertical_alignment(alignment::Vertical::Center),
+        )
+        .push(horizontal_space());
+
+    button(content)
+        .height(if active { 35 } else { 30 })
+        .padding(0)
+        .width(Length::Fill)
+        .style(if active {
+            ButtonType::TabActive
+        } else {
+            ButtonType::TabInactive
+        })
+        .on_press(tab.action())
+}
+
 pub fn notifications_badge(
     font_headers: Font,
     num: usize,
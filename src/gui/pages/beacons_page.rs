@@ -0,0 +1,236 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::scrollable::Direction;
+use iced::widget::{button, horizontal_space, lazy, vertical_space, Rule, Space};
+use iced::widget::{Column, Container, Row, Scrollable, Text};
+use iced::{Alignment, Font, Length};
+
+use crate::gui::components::tab::get_pages_tabs;
+use crate::gui::pages::inspect_page::get_change_page_row;
+use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
+use crate::gui::styles::text::TextType;
+use crate::gui::types::message::Message;
+use crate::networking::types::beaconing::Beacon;
+use crate::report::get_report_entries::get_beacon_entries;
+use crate::report::types::sort_type::SortType;
+use crate::translations::translations::address_translation;
+use crate::translations::translations_4::{
+    bursts_translation, host_name_translation, jitter_translation, no_periodic_hosts_translation,
+    period_translation, score_translation,
+};
+use crate::utils::types::icon::Icon;
+use crate::{ConfigSettings, Language, RunningPage, Sniffer, StyleType};
+
+const ADDRESS_COL_WIDTH: f32 = 250.0;
+const HOSTNAME_COL_WIDTH: f32 = 300.0;
+const PERIOD_COL_WIDTH: f32 = 120.0;
+const JITTER_COL_WIDTH: f32 = 100.0;
+const BURSTS_COL_WIDTH: f32 = 100.0;
+const SCORE_COL_WIDTH: f32 = 120.0;
+
+const ADDRESS_COL_MAX_CHARS: usize = 30;
+const HOSTNAME_COL_MAX_CHARS: usize = 36;
+
+/// Computes the body of gui beacons page
+pub fn beacons_page(sniffer: &Sniffer) -> Container<'_, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let font_headers = style.get_extension().font_headers;
+
+    let tabs = get_pages_tabs(
+        RunningPage::Beacons,
+        font,
+        font_headers,
+        language,
+        sniffer.unread_notifications,
+    );
+
+    let report = lazy(
+        (
+            sniffer.runtime_data.tot_out_packets + sniffer.runtime_data.tot_in_packets,
+            style,
+            language,
+            sniffer.beacon_sort_type,
+            sniffer.beacon_page_number,
+        ),
+        move |_| lazy_beacons_report(sniffer),
+    );
+
+    let col_report = Column::new()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_items(Alignment::Start)
+        .push(beacons_header_row(language, font, sniffer.beacon_sort_type))
+        .push(Space::with_height(4))
+        .push(Rule::horizontal(5))
+        .push(report);
+
+    let body = Container::new(col_report)
+        .align_y(Vertical::Center)
+        .align_x(Horizontal::Center)
+        .padding([10, 7, 3, 7])
+        .width(Length::Fill)
+        .style(ContainerType::BorderedRound);
+
+    Container::new(
+        Column::new()
+            .height(Length::Fill)
+            .push(tabs)
+            .push(Container::new(body).padding(10)),
+    )
+    .height(Length::Fill)
+}
+
+fn lazy_beacons_report(sniffer: &Sniffer) -> Column<'static, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+
+    let (entries, results_number) = get_beacon_entries(sniffer);
+
+    let mut ret_val = Column::new()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_items(Alignment::Start);
+
+    if results_number == 0 {
+        return ret_val.push(
+            Column::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(20)
+                .align_items(Alignment::Center)
+                .push(vertical_space())
+                .push(Icon::Clock.to_text().size(60))
+                .push(Space::with_height(15))
+                .push(Text::new(no_periodic_hosts_translation(language)).font(font))
+                .push(Space::with_height(Length::FillPortion(2))),
+        );
+    }
+
+    let start_entry_num = (sniffer.beacon_page_number - 1) * 20 + 1;
+    let end_entry_num = start_entry_num + entries.len() - 1;
+    let mut scroll_report = Column::new().align_items(Alignment::Start);
+    for (address, host_name, beacon) in &entries {
+        scroll_report = scroll_report.push(beacon_entry_row(address, host_name, beacon, font));
+    }
+    ret_val = ret_val
+        .push(
+            Scrollable::new(scroll_report)
+                .height(Length::Fill)
+                .width(Length::Fill)
+                .direction(Direction::Vertical(ScrollbarType::properties())),
+        )
+        .push(Rule::horizontal(5))
+        .push(get_change_page_row(
+            font,
+            language,
+            sniffer.beacon_page_number,
+            start_entry_num,
+            end_entry_num,
+            results_number,
+        ));
+    ret_val
+}
+
+fn beacons_header_row(
+    language: Language,
+    font: Font,
+    sort_type: SortType,
+) -> Row<'static, Message, StyleType> {
+    let title = |text: &str, width: f32| {
+        Container::new(Text::new(text.to_string()).font(font))
+            .width(width)
+            .align_x(Horizontal::Center)
+    };
+    Row::new()
+        .padding([0, 2])
+        .height(30)
+        .align_items(Alignment::Center)
+        .push(title(address_translation(language), ADDRESS_COL_WIDTH))
+        .push(title(host_name_translation(language), HOSTNAME_COL_WIDTH))
+        .push(title(period_translation(language), PERIOD_COL_WIDTH))
+        .push(title(jitter_translation(language), JITTER_COL_WIDTH))
+        .push(title(bursts_translation(language), BURSTS_COL_WIDTH))
+        .push(
+            Row::new()
+                .width(SCORE_COL_WIDTH)
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .push(horizontal_space())
+                .push(Text::new(score_translation(language)).font(font))
+                .push(sort_arrows(sort_type))
+                .push(horizontal_space()),
+        )
+}
+
+fn beacon_entry_row(
+    address: &str,
+    host_name: &str,
+    beacon: &Beacon,
+    font: Font,
+) -> Row<'static, Message, StyleType> {
+    let text_type = if beacon.is_suspicious() {
+        TextType::Danger
+    } else {
+        TextType::Standard
+    };
+    let cell = |value: String, max_chars: usize, width: f32| {
+        let chars: Vec<char> = value.chars().collect();
+        let value = if chars.len() <= max_chars {
+            value
+        } else {
+            [&chars[..max_chars - 2].iter().collect::<String>(), "…"].concat()
+        };
+        Container::new(Text::new(value).font(font).style(text_type))
+            .width(width)
+            .align_x(Horizontal::Center)
+    };
+
+    Row::new()
+        .padding(2)
+        .align_items(Alignment::Center)
+        .push(cell(
+            address.to_string(),
+            ADDRESS_COL_MAX_CHARS,
+            ADDRESS_COL_WIDTH,
+        ))
+        .push(cell(
+            host_name.to_string(),
+            HOSTNAME_COL_MAX_CHARS,
+            HOSTNAME_COL_WIDTH,
+        ))
+        .push(cell(
+            format!("{:.1} s", beacon.period_secs()),
+            usize::MAX,
+            PERIOD_COL_WIDTH,
+        ))
+        .push(cell(
+            format!("{:.1}%", beacon.jitter * 100.0),
+            usize::MAX,
+            JITTER_COL_WIDTH,
+        ))
+        .push(cell(
+            beacon.bursts.to_string(),
+            usize::MAX,
+            BURSTS_COL_WIDTH,
+        ))
+        .push(cell(beacon.score.to_string(), usize::MAX, SCORE_COL_WIDTH))
+}
+
+fn sort_arrows(active_sort_type: SortType) -> Container<'static, Message, StyleType> {
+    Container::new(
+        button(
+            active_sort_type
+                .icon()
+                .horizontal_alignment(Horizontal::Center)
+                .vertical_alignment(Vertical::Center),
+        )
+        .style(active_sort_type.button_type())
+        .on_press(Message::BeaconSortSelection(active_sort_type.next_sort())),
+    )
+    .align_y(Vertical::Center)
+}
@@ -3,12 +3,15 @@
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::scrollable::Direction;
 use iced::widget::tooltip::Position;
-use iced::widget::{button, horizontal_space, lazy, vertical_space, Rule, Scrollable};
+use iced::widget::{button, horizontal_space, lazy, vertical_space, Rule, Scrollable, Space};
 use iced::widget::{Column, Container, Row, Text, Tooltip};
 use iced::{Alignment, Font, Length};
 
 use crate::countries::country_utils::{get_computer_tooltip, get_flag_tooltip};
 use crate::gui::components::button::button_hide;
+use crate::gui::components::tab::get_connection_details_tabs;
+use crate::gui::pages::types::connection_details_tab::ConnectionDetailsTab;
+use crate::gui::styles::button::ButtonType;
 use crate::gui::styles::container::ContainerType;
 use crate::gui::styles::scrollbar::ScrollbarType;
 use crate::gui::styles::style_constants::FONT_SIZE_TITLE;
@@ -21,8 +24,11 @@
 };
 use crate::networking::types::address_port_pair::AddressPortPair;
 use crate::networking::types::host::Host;
+use crate::networking::types::http_message::HttpRequest;
 use crate::networking::types::icmp_type::IcmpType;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::networking::types::os_fingerprint::OsGuess;
+use crate::networking::types::packet_samples::PacketSample;
 use crate::networking::types::traffic_direction::TrafficDirection;
 use crate::translations::translations::{
     address_translation, incoming_translation, outgoing_translation, packets_translation,
@@ -36,7 +42,15 @@
 use crate::translations::translations_3::{
     copy_translation, messages_translation, service_translation,
 };
-use crate::utils::formatted_strings::get_socket_address;
+use crate::translations::translations_4::{
+    by_port_translation, container_translation, detected_translation, handshake_rtt_translation,
+    hop_distance_translation, host_name_translation, http_requests_translation,
+    no_packets_kept_translation, operating_system_translation, out_of_order_translation,
+    process_translation, queried_domain_translation, retransmissions_translation,
+    round_trip_time_translation, server_name_translation, state_translation, truncated_translation,
+    tunnel_translation,
+};
+use crate::utils::formatted_strings::{get_formatted_rtt, get_socket_address};
 use crate::utils::types::icon::Icon;
 use crate::{ByteMultiple, ConfigSettings, Language, Protocol, Sniffer, StyleType};
 
@@ -49,6 +63,9 @@ pub fn connection_details_page(
             sniffer.runtime_data.tot_out_packets + sniffer.runtime_data.tot_in_packets,
             sniffer.timing_events.was_just_copy_ip(&key.address1),
             sniffer.timing_events.was_just_copy_ip(&key.address2),
+            sniffer.timing_events.was_just_copy(),
+            sniffer.connection_details_tab,
+            sniffer.selected_packet,
         ),
         move |_| page_content(sniffer, &key),
     ))
@@ -67,6 +84,33 @@ fn page_content(
     let font = style.get_extension().font;
     let font_headers = style.get_extension().font_headers;
 
+    let header_and_content = Column::new()
+        .width(Length::Fill)
+        .push(page_header(font, font_headers, color_gradient, language))
+        .push(Space::with_height(5))
+        .push(get_connection_details_tabs(
+            sniffer.connection_details_tab,
+            font,
+            language,
+        ));
+
+    let content = match sniffer.connection_details_tab {
+        ConnectionDetailsTab::Overview => overview_content(sniffer, key, font, language),
+        ConnectionDetailsTab::Packets => packets_content(sniffer, key, font, language),
+    };
+
+    Container::new(header_and_content.push(content))
+        .width(1000)
+        .height(540)
+        .style(ContainerType::Modal)
+}
+
+fn overview_content(
+    sniffer: &Sniffer,
+    key: &AddressPortPair,
+    font: Font,
+    language: Language,
+) -> Row<'static, Message, StyleType> {
     let info_traffic_lock = sniffer
         .info_traffic
         .lock()
@@ -81,15 +125,15 @@ fn page_content(
         .hosts
         .get(&host_option.clone().unwrap_or_default().1)
         .copied();
+    let dns_name = info_traffic_lock.dns_names.get(&address_to_lookup).cloned();
+    let dhcp_name = info_traffic_lock
+        .dhcp_names
+        .get(&address_to_lookup)
+        .cloned();
+    let [source_os, dest_os] = [&key.address1, &key.address2]
+        .map(|address| info_traffic_lock.os_guesses.get(address).copied());
     drop(info_traffic_lock);
 
-    let header_and_content = Column::new().width(Length::Fill).push(page_header(
-        font,
-        font_headers,
-        color_gradient,
-        language,
-    ));
-
     let mut source_caption = Row::new().align_items(Alignment::Center).spacing(10).push(
         Text::new(source_translation(language))
             .font(font)
@@ -104,7 +148,14 @@ fn page_content(
     );
     let mut host_info_col = Column::new();
     if let Some((r_dns, host)) = host_option {
-        host_info_col = get_host_info_col(&r_dns, &host, font, language);
+        host_info_col = get_host_info_col(
+            &r_dns,
+            dns_name.as_deref(),
+            dhcp_name.as_deref(),
+            &host,
+            font,
+            language,
+        );
         let host_info = host_info_option.unwrap_or_default();
         let flag = get_flag_tooltip(host.country, &host_info, language, font, false);
         let computer = get_local_tooltip(sniffer, &address_to_lookup, key);
@@ -136,6 +187,13 @@ fn page_content(
         &sniffer.timing_events,
     );
 
+    if let Some(source_os) = source_os {
+        source_col = source_col.push(get_os_col(source_os, font, language));
+    }
+    if let Some(dest_os) = dest_os {
+        dest_col = dest_col.push(get_os_col(dest_os, font, language));
+    }
+
     if address_to_lookup.eq(&key.address1) {
         source_col = source_col.push(host_info_col);
     } else {
@@ -144,12 +202,129 @@ fn page_content(
 
     let col_info = col_info(key, &val, font, language);
 
-    let content = assemble_widgets(col_info, source_col, dest_col);
+    assemble_widgets(col_info, source_col, dest_col)
+}
 
-    Container::new(header_and_content.push(content))
-        .width(1000)
-        .height(500)
-        .style(ContainerType::Modal)
+fn packets_content(
+    sniffer: &Sniffer,
+    key: &AddressPortPair,
+    font: Font,
+    language: Language,
+) -> Row<'static, Message, StyleType> {
+    let packets: Vec<(bool, PacketSample)> = sniffer
+        .info_traffic
+        .lock()
+        .expect("Error acquiring mutex\n\r")
+        .packet_samples
+        .connection(key)
+        .into_iter()
+        .map(|(from_source, sample)| (from_source, sample.clone()))
+        .collect();
+
+    let content = Row::new()
+        .padding([10, 10])
+        .spacing(10)
+        .width(Length::Fill)
+        .height(Length::Fill);
+
+    if packets.is_empty() {
+        return content.align_items(Alignment::Center).push(
+            Text::new(no_packets_kept_translation(language))
+                .font(font)
+                .width(Length::Fill)
+                .horizontal_alignment(Horizontal::Center),
+        );
+    }
+
+    let selected = sniffer.selected_packet.min(packets.len() - 1);
+
+    let mut packets_col = Column::new().spacing(3).padding([0, 12, 0, 0]);
+    for (i, (from_source, sample)) in packets.iter().enumerate() {
+        let direction_icon = if *from_source {
+            Icon::ArrowRight
+        } else {
+            Icon::ArrowLeft
+        };
+        let flags = sample
+            .tcp_flags
+            .map_or_else(|| "-".to_string(), |flags| flags.to_string());
+        let packet_row = Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(Text::new(format!("{:>2}", i + 1)).font(font))
+            .push(Text::new(sample.formatted_timestamp()).font(font))
+            .push(direction_icon.to_text().size(12))
+            .push(Text::new(format!("{:>5} B", sample.original_len)).font(font))
+            .push(Text::new(flags).font(font));
+        packets_col = packets_col.push(
+            button(packet_row)
+                .padding([2, 7])
+                .width(Length::Fill)
+                .style(if i == selected {
+                    ButtonType::BorderedRoundSelected
+                } else {
+                    ButtonType::BorderedRound
+                })
+                .on_press(Message::SelectPacket(i)),
+        );
+    }
+
+    let sample = &packets[selected].1;
+    let mut length = format!("{} B", sample.original_len);
+    if sample.is_truncated() {
+        length.push_str(&format!(
+            " ({} B, {})",
+            sample.data.len(),
+            truncated_translation(language)
+        ));
+    }
+    let dump_header = Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(
+            Text::new(format!("#{}", selected + 1))
+                .font(font)
+                .style(TextType::Subtitle),
+        )
+        .push(Text::new(length).font(font))
+        .push(horizontal_space())
+        .push(get_button_copy_hex(
+            language,
+            font,
+            &sample.hex_stream(),
+            &sniffer.timing_events,
+        ));
+    let dump_col = Column::new()
+        .spacing(10)
+        .width(Length::FillPortion(3))
+        .push(dump_header)
+        .push(Rule::horizontal(10.0))
+        .push(
+            Scrollable::new(
+                Column::new()
+                    .padding([0, 10, 10, 0])
+                    .push(Text::new(sample.hex_dump()).font(font).size(13)),
+            )
+            .direction(Direction::Both {
+                vertical: ScrollbarType::properties(),
+                horizontal: ScrollbarType::properties(),
+            }),
+        );
+
+    content
+        .push(
+            Container::new(
+                Scrollable::new(packets_col)
+                    .direction(Direction::Vertical(ScrollbarType::properties())),
+            )
+            .width(Length::FillPortion(2)),
+        )
+        .push(
+            Container::new(dump_col)
+                .padding(7)
+                .height(Length::Fill)
+                .style(ContainerType::BorderedRound),
+        )
 }
 
 fn page_header(
@@ -206,14 +381,86 @@ fn col_info(
         )
         .push(TextType::highlighted_subtitle_with_desc(
             protocol_translation(language),
-            &key.protocol.to_string(),
+            &key.protocol.to_string_with_number(),
             font,
         ));
 
     if !is_icmp {
         ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
             service_translation(language),
-            &val.service.to_string(),
+            &format!(
+                "{} ({})",
+                val.service,
+                if val.service_detected {
+                    detected_translation(language)
+                } else {
+                    by_port_translation(language)
+                }
+            ),
+            font,
+        ));
+    }
+
+    if let Some(client_hello) = &val.client_hello {
+        if let Some(server_name) = &client_hello.server_name {
+            ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+                &format!("{} (SNI)", server_name_translation(language)),
+                server_name,
+                font,
+            ));
+        }
+        if !client_hello.alpn.is_empty() {
+            ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+                "ALPN",
+                &client_hello.alpn.join(", "),
+                font,
+            ));
+        }
+    }
+
+    if let Some(state) = val.tcp_state() {
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            state_translation(language),
+            &state.to_string(),
+            font,
+        ));
+    }
+
+    if let Some(metrics) = &val.tcp_metrics {
+        if let Some(handshake_rtt) = metrics.handshake_rtt {
+            ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+                handshake_rtt_translation(language),
+                &get_formatted_rtt(handshake_rtt),
+                font,
+            ));
+        }
+        if let Some(average_rtt) = metrics.rtt.average() {
+            ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+                &format!(
+                    "{} (min / avg / max)",
+                    round_trip_time_translation(language)
+                ),
+                &format!(
+                    "{} / {} / {}",
+                    get_formatted_rtt(metrics.rtt.min),
+                    get_formatted_rtt(average_rtt),
+                    get_formatted_rtt(metrics.rtt.max)
+                ),
+                font,
+            ));
+        }
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            retransmissions_translation(language),
+            &format!(
+                "{} ({:.1}%)",
+                metrics.retransmissions,
+                100.0 * metrics.retransmission_rate()
+            ),
+            font,
+        ));
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            out_of_order_translation(language),
+            &metrics.out_of_order.to_string(),
             font,
         ));
     }
@@ -237,6 +484,60 @@ fn col_info(
         font,
     ));
 
+    if let Some(process) = &val.process {
+        let mut process_str = process.to_string();
+        if let Some(executable) = &process.executable {
+            process_str.push_str(&format!("\n   {executable}"));
+        }
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            process_translation(language),
+            &process_str,
+            font,
+        ));
+        if let Some(container) = &process.container {
+            let mut container_str = container.to_string();
+            if let Some(netns) = process.netns {
+                container_str.push_str(&format!("\n   net:[{netns}]"));
+            }
+            ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+                container_translation(language),
+                &container_str,
+                font,
+            ));
+        }
+    }
+
+    if let Some(tunnel) = &val.tunnel {
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            tunnel_translation(language),
+            &tunnel.to_string(),
+            font,
+        ));
+    }
+
+    if !val.http_requests.is_empty() {
+        ret_val = ret_val.push(
+            Column::new()
+                .push(
+                    Text::new(format!("{}:", http_requests_translation(language)))
+                        .style(TextType::Subtitle)
+                        .font(font),
+                )
+                .push(
+                    Scrollable::new(
+                        Column::new().padding([0, 10, 10, 0]).push(
+                            Text::new(HttpRequest::pretty_print_requests(&val.http_requests))
+                                .font(font),
+                        ),
+                    )
+                    .direction(Direction::Both {
+                        vertical: ScrollbarType::properties(),
+                        horizontal: ScrollbarType::properties(),
+                    }),
+                ),
+        );
+    }
+
     if is_icmp {
         ret_val =
             ret_val.push(
@@ -265,15 +566,34 @@ fn col_info(
 
 fn get_host_info_col(
     r_dns: &str,
+    dns_name: Option<&str>,
+    dhcp_name: Option<&str>,
     host: &Host,
     font: Font,
     language: Language,
 ) -> Column<'static, Message, StyleType> {
     let mut host_info_col = Column::new().spacing(4);
-    if r_dns.parse::<IpAddr>().is_err() || (!host.asn.name.is_empty() && !host.asn.code.is_empty())
+    if r_dns.parse::<IpAddr>().is_err()
+        || dns_name.is_some()
+        || dhcp_name.is_some()
+        || (!host.asn.name.is_empty() && !host.asn.code.is_empty())
     {
         host_info_col = host_info_col.push(Rule::horizontal(10.0));
     }
+    if let Some(dns_name) = dns_name {
+        host_info_col = host_info_col.push(TextType::highlighted_subtitle_with_desc(
+            queried_domain_translation(language),
+            dns_name,
+            font,
+        ));
+    }
+    if let Some(dhcp_name) = dhcp_name {
+        host_info_col = host_info_col.push(TextType::highlighted_subtitle_with_desc(
+            &format!("{} (DHCP)", host_name_translation(language)),
+            dhcp_name,
+            font,
+        ));
+    }
     if r_dns.parse::<IpAddr>().is_err() {
         host_info_col = host_info_col.push(TextType::highlighted_subtitle_with_desc(
             fqdn_translation(language),
@@ -366,6 +686,25 @@ fn get_src_or_dest_col(
         ))
 }
 
+fn get_os_col(
+    os_guess: OsGuess,
+    font: Font,
+    language: Language,
+) -> Column<'static, Message, StyleType> {
+    Column::new()
+        .spacing(4)
+        .push(TextType::highlighted_subtitle_with_desc(
+            operating_system_translation(language),
+            &format!("{} ({}%)", os_guess.os, os_guess.confidence),
+            font,
+        ))
+        .push(TextType::highlighted_subtitle_with_desc(
+            hop_distance_translation(language),
+            &os_guess.hop_distance.to_string(),
+            font,
+        ))
+}
+
 fn assemble_widgets(
     col_info: Column<'static, Message, StyleType>,
     source_col: Column<'static, Message, StyleType>,
@@ -397,6 +736,36 @@ fn assemble_widgets(
         )
 }
 
+fn get_button_copy_hex(
+    language: Language,
+    font: Font,
+    hex: &String,
+    timing_events: &TimingEvents,
+) -> Tooltip<'static, Message, StyleType> {
+    let icon = if timing_events.was_just_copy_ip(hex) {
+        Text::new("✔").font(font).size(14)
+    } else {
+        Icon::Copy.to_text().size(12)
+    };
+
+    let content = button(
+        icon.horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .padding(0)
+    .height(25)
+    .width(25)
+    .on_press(Message::CopyIp(hex.clone()));
+
+    Tooltip::new(
+        content,
+        Text::new(format!("{} (hex)", copy_translation(language))).font(font),
+        Position::Left,
+    )
+    .gap(5)
+    .style(ContainerType::Tooltip)
+}
+
 fn get_button_copy(
     language: Language,
     font: Font,
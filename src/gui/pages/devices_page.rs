@@ -0,0 +1,420 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::scrollable::Direction;
+use iced::widget::{horizontal_space, lazy, vertical_space, Rule, Space};
+use iced::widget::{Column, Container, Row, Scrollable, Text};
+use iced::{Alignment, Element, Font, Length};
+
+use crate::gui::components::tab::get_pages_tabs;
+use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
+use crate::gui::styles::style_constants::FONT_SIZE_SUBTITLE;
+use crate::gui::styles::text::TextType;
+use crate::gui::types::message::Message;
+use crate::networking::types::dhcp_analytics::{DhcpClient, DhcpServer};
+use crate::networking::types::local_names::LocalDevice;
+use crate::translations::translations::{address_translation, protocol_translation};
+use crate::translations::translations_2::mac_address_translation;
+use crate::translations::translations_4::{
+    announced_names_translation, dhcp_leases_translation, dhcp_servers_translation,
+    host_name_translation, lease_time_translation, no_announced_names_translation,
+    no_dhcp_traffic_translation, services_translation, vendor_class_translation,
+};
+use crate::utils::formatted_strings::get_formatted_duration;
+use crate::utils::types::icon::Icon;
+use crate::{ConfigSettings, Language, RunningPage, Sniffer, StyleType};
+
+const ADDRESS_COL_WIDTH: f32 = 170.0;
+const MAC_COL_WIDTH: f32 = 150.0;
+const HOSTNAME_COL_WIDTH: f32 = 200.0;
+const VENDOR_COL_WIDTH: f32 = 200.0;
+const LEASE_COL_WIDTH: f32 = 110.0;
+const NAME_COL_WIDTH: f32 = 250.0;
+const PROTOCOL_COL_WIDTH: f32 = 110.0;
+const SERVICES_COL_WIDTH: f32 = 300.0;
+
+const ADDRESS_COL_MAX_CHARS: usize = 20;
+const HOSTNAME_COL_MAX_CHARS: usize = 22;
+const VENDOR_COL_MAX_CHARS: usize = 22;
+const NAME_COL_MAX_CHARS: usize = 28;
+const SERVICES_COL_MAX_CHARS: usize = 34;
+
+/// Computes the body of gui devices page
+pub fn devices_page(sniffer: &Sniffer) -> Container<'_, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let font_headers = style.get_extension().font_headers;
+
+    let tabs = get_pages_tabs(
+        RunningPage::Devices,
+        font,
+        font_headers,
+        language,
+        sniffer.unread_notifications,
+    );
+
+    let lazy_key = (
+        sniffer.runtime_data.tot_out_packets + sniffer.runtime_data.tot_in_packets,
+        style,
+        language,
+    );
+    let leases_report = lazy(lazy_key, move |_| lazy_leases_report(sniffer));
+    let names_report = lazy(lazy_key, move |_| lazy_names_report(sniffer));
+
+    let col_reports = Column::new()
+        .spacing(10)
+        .width(Length::Fill)
+        .push(report_container(
+            dhcp_leases_translation(language),
+            leases_header_row(language, font),
+            leases_report.into(),
+            font,
+        ))
+        .push(report_container(
+            announced_names_translation(language),
+            names_header_row(language, font),
+            names_report.into(),
+            font,
+        ));
+
+    let body = Row::new()
+        .padding(10)
+        .spacing(10)
+        .push(
+            Container::new(dhcp_servers_col(sniffer, font, language))
+                .padding(10)
+                .width(300)
+                .height(Length::Fill)
+                .style(ContainerType::BorderedRound),
+        )
+        .push(col_reports);
+
+    Container::new(Column::new().height(Length::Fill).push(tabs).push(body)).height(Length::Fill)
+}
+
+fn report_container<'a>(
+    title: &str,
+    header_row: Row<'a, Message, StyleType>,
+    report: Element<'a, Message, StyleType>,
+    font: Font,
+) -> Container<'a, Message, StyleType> {
+    let col_report = Column::new()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_items(Alignment::Start)
+        .push(
+            Text::new(title.to_string())
+                .font(font)
+                .style(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(header_row)
+        .push(Space::with_height(4))
+        .push(Rule::horizontal(5))
+        .push(report);
+
+    Container::new(col_report)
+        .align_y(Vertical::Center)
+        .align_x(Horizontal::Center)
+        .padding([10, 7, 3, 7])
+        .width(Length::Fill)
+        .height(Length::FillPortion(1))
+        .style(ContainerType::BorderedRound)
+}
+
+fn lazy_leases_report(sniffer: &Sniffer) -> Column<'static, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+
+    let info_traffic_lock = sniffer.info_traffic.lock().unwrap();
+    let clients: Vec<DhcpClient> = info_traffic_lock
+        .dhcp_analytics
+        .sorted_clients()
+        .into_iter()
+        .cloned()
+        .collect();
+    drop(info_traffic_lock);
+
+    let ret_val = Column::new()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_items(Alignment::Start);
+
+    if clients.is_empty() {
+        return ret_val.push(empty_report(no_dhcp_traffic_translation(language), font));
+    }
+
+    let mut scroll_report = Column::new().align_items(Alignment::Start);
+    for client in &clients {
+        scroll_report = scroll_report.push(lease_entry_row(client, font));
+    }
+    ret_val.push(
+        Scrollable::new(scroll_report)
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .direction(Direction::Vertical(ScrollbarType::properties())),
+    )
+}
+
+fn lazy_names_report(sniffer: &Sniffer) -> Column<'static, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+
+    let info_traffic_lock = sniffer.info_traffic.lock().unwrap();
+    let devices: Vec<(String, LocalDevice)> = info_traffic_lock
+        .local_names
+        .sorted_devices()
+        .into_iter()
+        .map(|(address, device)| (address.clone(), device.clone()))
+        .collect();
+    drop(info_traffic_lock);
+
+    let ret_val = Column::new()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_items(Alignment::Start);
+
+    if devices.is_empty() {
+        return ret_val.push(empty_report(no_announced_names_translation(language), font));
+    }
+
+    let mut scroll_report = Column::new().align_items(Alignment::Start);
+    for (address, device) in devices {
+        scroll_report = scroll_report.push(name_entry_row(address, &device, font));
+    }
+    ret_val.push(
+        Scrollable::new(scroll_report)
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .direction(Direction::Vertical(ScrollbarType::properties())),
+    )
+}
+
+fn empty_report(text: &str, font: Font) -> Column<'static, Message, StyleType> {
+    Column::new()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .align_items(Alignment::Center)
+        .push(vertical_space())
+        .push(Icon::Waves.to_text().size(60))
+        .push(Space::with_height(15))
+        .push(Text::new(text.to_string()).font(font))
+        .push(Space::with_height(Length::FillPortion(2)))
+}
+
+fn header_title(text: &str, width: f32, font: Font) -> Container<'static, Message, StyleType> {
+    Container::new(Text::new(text.to_string()).font(font))
+        .width(width)
+        .align_x(Horizontal::Center)
+}
+
+fn entry_cell(
+    value: Option<String>,
+    max_chars: usize,
+    width: f32,
+    font: Font,
+) -> Container<'static, Message, StyleType> {
+    let value = value.unwrap_or_else(|| "-".to_string());
+    let chars: Vec<char> = value.chars().collect();
+    let value = if chars.len() <= max_chars {
+        value
+    } else {
+        [&chars[..max_chars - 2].iter().collect::<String>(), "…"].concat()
+    };
+    Container::new(Text::new(value).font(font))
+        .width(width)
+        .align_x(Horizontal::Center)
+}
+
+fn leases_header_row(language: Language, font: Font) -> Row<'static, Message, StyleType> {
+    Row::new()
+        .padding([0, 2])
+        .height(30)
+        .align_items(Alignment::Center)
+        .push(header_title(
+            address_translation(language),
+            ADDRESS_COL_WIDTH,
+            font,
+        ))
+        .push(header_title(
+            mac_address_translation(language),
+            MAC_COL_WIDTH,
+            font,
+        ))
+        .push(header_title(
+            host_name_translation(language),
+            HOSTNAME_COL_WIDTH,
+            font,
+        ))
+        .push(header_title(
+            vendor_class_translation(language),
+            VENDOR_COL_WIDTH,
+            font,
+        ))
+        .push(header_title(
+            lease_time_translation(language),
+            LEASE_COL_WIDTH,
+            font,
+        ))
+}
+
+fn lease_entry_row(client: &DhcpClient, font: Font) -> Row<'static, Message, StyleType> {
+    Row::new()
+        .padding(2)
+        .align_items(Alignment::Center)
+        .push(entry_cell(
+            client.address.clone(),
+            ADDRESS_COL_MAX_CHARS,
+            ADDRESS_COL_WIDTH,
+            font,
+        ))
+        .push(entry_cell(
+            client.mac_address.clone(),
+            usize::MAX,
+            MAC_COL_WIDTH,
+            font,
+        ))
+        .push(entry_cell(
+            client.hostname.clone(),
+            HOSTNAME_COL_MAX_CHARS,
+            HOSTNAME_COL_WIDTH,
+            font,
+        ))
+        .push(entry_cell(
+            client.vendor_class.clone(),
+            VENDOR_COL_MAX_CHARS,
+            VENDOR_COL_WIDTH,
+            font,
+        ))
+        .push(entry_cell(
+            client.lease_time.map(get_formatted_duration),
+            usize::MAX,
+            LEASE_COL_WIDTH,
+            font,
+        ))
+}
+
+fn names_header_row(language: Language, font: Font) -> Row<'static, Message, StyleType> {
+    Row::new()
+        .padding([0, 2])
+        .height(30)
+        .align_items(Alignment::Center)
+        .push(header_title(
+            address_translation(language),
+            ADDRESS_COL_WIDTH,
+            font,
+        ))
+        .push(header_title(
+            host_name_translation(language),
+            NAME_COL_WIDTH,
+            font,
+        ))
+        .push(header_title(
+            protocol_translation(language),
+            PROTOCOL_COL_WIDTH,
+            font,
+        ))
+        .push(header_title(
+            services_translation(language),
+            SERVICES_COL_WIDTH,
+            font,
+        ))
+}
+
+fn name_entry_row(
+    address: String,
+    device: &LocalDevice,
+    font: Font,
+) -> Row<'static, Message, StyleType> {
+    let (name, protocol) = device
+        .name
+        .as_ref()
+        .map_or((None, None), |(name, protocol)| {
+            (Some(name.clone()), Some(protocol.to_string()))
+        });
+    let services = (!device.services.is_empty()).then(|| {
+        device
+            .services
+            .iter()
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(", ")
+    });
+
+    Row::new()
+        .padding(2)
+        .align_items(Alignment::Center)
+        .push(entry_cell(
+            Some(address),
+            ADDRESS_COL_MAX_CHARS,
+            ADDRESS_COL_WIDTH,
+            font,
+        ))
+        .push(entry_cell(name, NAME_COL_MAX_CHARS, NAME_COL_WIDTH, font))
+        .push(entry_cell(protocol, usize::MAX, PROTOCOL_COL_WIDTH, font))
+        .push(entry_cell(
+            services,
+            SERVICES_COL_MAX_CHARS,
+            SERVICES_COL_WIDTH,
+            font,
+        ))
+}
+
+fn dhcp_servers_col(
+    sniffer: &Sniffer,
+    font: Font,
+    language: Language,
+) -> Column<'static, Message, StyleType> {
+    let (servers, multiple_servers): (Vec<DhcpServer>, bool) = {
+        let dhcp_analytics = &sniffer.info_traffic.lock().unwrap().dhcp_analytics;
+        (
+            dhcp_analytics.servers.clone(),
+            dhcp_analytics.multiple_servers(),
+        )
+    };
+    // more than one server answering on the same network is usually a misconfiguration
+    let text_type = if multiple_servers {
+        TextType::Danger
+    } else {
+        TextType::Standard
+    };
+
+    let mut servers_col = Column::new().spacing(10).padding([0, 10, 0, 0]);
+    for server in servers {
+        servers_col = servers_col.push(
+            Column::new()
+                .push(
+                    Row::new()
+                        .spacing(5)
+                        .push(Text::new(server.address).font(font).style(text_type))
+                        .push(horizontal_space())
+                        .push(Text::new(server.messages.to_string()).font(font)),
+                )
+                .push(
+                    Text::new(server.mac_address.unwrap_or_else(|| "-".to_string()))
+                        .font(font)
+                        .style(TextType::Subtitle),
+                ),
+        );
+    }
+
+    Column::new()
+        .spacing(10)
+        .push(
+            Text::new(dhcp_servers_translation(language))
+                .font(font)
+                .style(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(
+            Scrollable::new(servers_col)
+                .height(Length::Fill)
+                .direction(Direction::Vertical(ScrollbarType::properties())),
+        )
+}
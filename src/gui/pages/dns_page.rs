@@ -0,0 +1,309 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::scrollable::Direction;
+use iced::widget::{button, horizontal_space, lazy, vertical_space, Rule, Space};
+use iced::widget::{Column, Container, Row, Scrollable, Text};
+use iced::{Alignment, Font, Length};
+
+use crate::gui::components::tab::get_pages_tabs;
+use crate::gui::pages::inspect_page::get_change_page_row;
+use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
+use crate::gui::styles::style_constants::FONT_SIZE_SUBTITLE;
+use crate::gui::styles::text::TextType;
+use crate::gui::types::message::Message;
+use crate::networking::types::dns_analytics::DnsTransaction;
+use crate::networking::types::dns_message::{record_type_name, response_code_name};
+use crate::report::get_report_entries::get_dns_entries;
+use crate::report::types::sort_type::SortType;
+use crate::translations::translations_2::domain_name_translation;
+use crate::translations::translations_4::{
+    average_latency_translation, latency_translation, no_dns_traffic_translation,
+    queries_translation, record_type_translation, resolver_translation, response_translation,
+    top_queried_domains_translation,
+};
+use crate::utils::formatted_strings::get_formatted_rtt;
+use crate::utils::types::icon::Icon;
+use crate::{ConfigSettings, Language, RunningPage, Sniffer, StyleType};
+
+const NAME_COL_WIDTH: f32 = 330.0;
+const TYPE_COL_WIDTH: f32 = 80.0;
+const RESOLVER_COL_WIDTH: f32 = 200.0;
+const RESPONSE_COL_WIDTH: f32 = 110.0;
+const LATENCY_COL_WIDTH: f32 = 110.0;
+
+const NAME_COL_MAX_CHARS: usize = 38;
+const RESOLVER_COL_MAX_CHARS: usize = 22;
+
+/// Number of domains displayed in the top queried domains list
+const TOP_DOMAINS: usize = 15;
+
+/// Computes the body of gui DNS page
+pub fn dns_page(sniffer: &Sniffer) -> Container<'_, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let font_headers = style.get_extension().font_headers;
+
+    let tabs = get_pages_tabs(
+        RunningPage::Dns,
+        font,
+        font_headers,
+        language,
+        sniffer.unread_notifications,
+    );
+
+    let report = lazy(
+        (
+            sniffer.runtime_data.tot_out_packets + sniffer.runtime_data.tot_in_packets,
+            style,
+            language,
+            sniffer.dns_sort_type,
+            sniffer.dns_page_number,
+        ),
+        move |_| lazy_dns_report(sniffer),
+    );
+
+    let col_report = Column::new()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_items(Alignment::Start)
+        .push(dns_header_row(language, font, sniffer.dns_sort_type))
+        .push(Space::with_height(4))
+        .push(Rule::horizontal(5))
+        .push(report);
+
+    let body = Row::new()
+        .padding(10)
+        .spacing(10)
+        .push(
+            Container::new(dns_summary_col(sniffer, font, language))
+                .padding(10)
+                .width(300)
+                .height(Length::Fill)
+                .style(ContainerType::BorderedRound),
+        )
+        .push(
+            Container::new(col_report)
+                .align_y(Vertical::Center)
+                .align_x(Horizontal::Center)
+                .padding([10, 7, 3, 7])
+                .width(Length::Fill)
+                .style(ContainerType::BorderedRound),
+        );
+
+    Container::new(Column::new().height(Length::Fill).push(tabs).push(body)).height(Length::Fill)
+}
+
+fn lazy_dns_report(sniffer: &Sniffer) -> Column<'static, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+
+    let (transactions, results_number) = get_dns_entries(sniffer);
+
+    let mut ret_val = Column::new()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_items(Alignment::Start);
+
+    if results_number == 0 {
+        return ret_val.push(
+            Column::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(20)
+                .align_items(Alignment::Center)
+                .push(vertical_space())
+                .push(Icon::Globe.to_text().size(60))
+                .push(Space::with_height(15))
+                .push(Text::new(no_dns_traffic_translation(language)).font(font))
+                .push(Space::with_height(Length::FillPortion(2))),
+        );
+    }
+
+    let start_entry_num = (sniffer.dns_page_number - 1) * 20 + 1;
+    let end_entry_num = start_entry_num + transactions.len() - 1;
+    let mut scroll_report = Column::new().align_items(Alignment::Start);
+    for transaction in &transactions {
+        scroll_report = scroll_report.push(dns_entry_row(transaction, font));
+    }
+    ret_val = ret_val
+        .push(
+            Scrollable::new(scroll_report)
+                .height(Length::Fill)
+                .width(Length::Fill)
+                .direction(Direction::Vertical(ScrollbarType::properties())),
+        )
+        .push(Rule::horizontal(5))
+        .push(get_change_page_row(
+            font,
+            language,
+            sniffer.dns_page_number,
+            start_entry_num,
+            end_entry_num,
+            results_number,
+        ));
+    ret_val
+}
+
+fn dns_header_row(
+    language: Language,
+    font: Font,
+    sort_type: SortType,
+) -> Row<'static, Message, StyleType> {
+    let title = |text: &str, width: f32| {
+        Container::new(Text::new(text.to_string()).font(font))
+            .width(width)
+            .align_x(Horizontal::Center)
+    };
+    Row::new()
+        .padding([0, 2])
+        .height(30)
+        .align_items(Alignment::Center)
+        .push(title(domain_name_translation(language), NAME_COL_WIDTH))
+        .push(title(record_type_translation(language), TYPE_COL_WIDTH))
+        .push(title(resolver_translation(language), RESOLVER_COL_WIDTH))
+        .push(title(response_translation(language), RESPONSE_COL_WIDTH))
+        .push(
+            Row::new()
+                .width(LATENCY_COL_WIDTH)
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .push(horizontal_space())
+                .push(Text::new(latency_translation(language)).font(font))
+                .push(sort_arrows(sort_type))
+                .push(horizontal_space()),
+        )
+}
+
+fn dns_entry_row(transaction: &DnsTransaction, font: Font) -> Row<'static, Message, StyleType> {
+    let cell = |value: String, max_chars: usize, width: f32, text_type: TextType| {
+        let chars: Vec<char> = value.chars().collect();
+        let value = if chars.len() <= max_chars {
+            value
+        } else {
+            [&chars[..max_chars - 2].iter().collect::<String>(), "…"].concat()
+        };
+        Container::new(Text::new(value).font(font).style(text_type))
+            .width(width)
+            .align_x(Horizontal::Center)
+    };
+
+    let (response, response_type) = match transaction.response_code {
+        Some(0) => (response_code_name(0), TextType::Standard),
+        Some(response_code) => (response_code_name(response_code), TextType::Danger),
+        None => ("-".to_string(), TextType::Standard),
+    };
+    let latency = transaction
+        .latency
+        .map_or_else(|| "-".to_string(), get_formatted_rtt);
+
+    Row::new()
+        .padding(2)
+        .align_items(Alignment::Center)
+        .push(cell(
+            transaction.name.clone(),
+            NAME_COL_MAX_CHARS,
+            NAME_COL_WIDTH,
+            TextType::Standard,
+        ))
+        .push(cell(
+            record_type_name(transaction.record_type),
+            usize::MAX,
+            TYPE_COL_WIDTH,
+            TextType::Standard,
+        ))
+        .push(cell(
+            transaction.resolver.clone(),
+            RESOLVER_COL_MAX_CHARS,
+            RESOLVER_COL_WIDTH,
+            TextType::Standard,
+        ))
+        .push(cell(
+            response,
+            usize::MAX,
+            RESPONSE_COL_WIDTH,
+            response_type,
+        ))
+        .push(cell(
+            latency,
+            usize::MAX,
+            LATENCY_COL_WIDTH,
+            TextType::Standard,
+        ))
+}
+
+fn dns_summary_col(
+    sniffer: &Sniffer,
+    font: Font,
+    language: Language,
+) -> Column<'static, Message, StyleType> {
+    let info_traffic_lock = sniffer.info_traffic.lock().unwrap();
+    let dns_analytics = &info_traffic_lock.dns_analytics;
+    let top_domains = dns_analytics.top_domains(TOP_DOMAINS);
+    let queries = dns_analytics.queries;
+    let (nxdomain, servfail) = (dns_analytics.nxdomain, dns_analytics.servfail);
+    let average_latency = dns_analytics.average_latency();
+    drop(info_traffic_lock);
+
+    let mut ret_val = Column::new()
+        .spacing(10)
+        .push(TextType::highlighted_subtitle_with_desc(
+            queries_translation(language),
+            &queries.to_string(),
+            font,
+        ))
+        .push(TextType::highlighted_subtitle_with_desc(
+            "NXDOMAIN / SERVFAIL",
+            &format!("{nxdomain} / {servfail}"),
+            font,
+        ));
+    if let Some(average_latency) = average_latency {
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            average_latency_translation(language),
+            &get_formatted_rtt(average_latency),
+            font,
+        ));
+    }
+
+    let mut domains_col = Column::new().spacing(5).padding([0, 10, 0, 0]);
+    for (domain, count) in top_domains {
+        domains_col = domains_col.push(
+            Row::new()
+                .spacing(5)
+                .push(Text::new(domain).font(font))
+                .push(horizontal_space())
+                .push(Text::new(count.to_string()).font(font)),
+        );
+    }
+
+    ret_val
+        .push(Rule::horizontal(10))
+        .push(
+            Text::new(top_queried_domains_translation(language))
+                .font(font)
+                .style(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(
+            Scrollable::new(domains_col)
+                .height(Length::Fill)
+                .direction(Direction::Vertical(ScrollbarType::properties())),
+        )
+}
+
+fn sort_arrows(active_sort_type: SortType) -> Container<'static, Message, StyleType> {
+    Container::new(
+        button(
+            active_sort_type
+                .icon()
+                .horizontal_alignment(Horizontal::Center)
+                .vertical_alignment(Vertical::Center),
+        )
+        .style(active_sort_type.button_type())
+        .on_press(Message::DnsSortSelection(active_sort_type.next_sort())),
+    )
+    .align_y(Vertical::Center)
+}
@@ -6,14 +6,14 @@
 
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::scrollable::Direction;
+use iced::widget::text::LineHeight;
 use iced::widget::tooltip::Position;
 use iced::widget::{
-    button, Button, Checkbox, Column, Container, Row, Rule, Scrollable, Space, Text, TextInput,
-    Tooltip,
+    button, Button, Checkbox, Column, Container, PickList, Row, Rule, Scrollable, Space, Text,
+    TextInput, Tooltip,
 };
 use iced::Length::FillPortion;
 use iced::{alignment, Alignment, Font, Length};
-use pcap::Device;
 
 use crate::gui::components::button::button_open_file;
 use crate::gui::styles::button::ButtonType;
@@ -28,6 +28,7 @@
 use crate::gui::types::sniffer::Sniffer;
 use crate::networking::types::filters::Filters;
 use crate::networking::types::ip_collection::AddressCollection;
+use crate::networking::types::network_namespace::list_devices;
 use crate::networking::types::port_collection::PortCollection;
 use crate::translations::translations::{
     address_translation, addresses_translation, choose_adapters_translation,
@@ -36,6 +37,9 @@
 use crate::translations::translations_3::{
     directory_translation, export_capture_translation, file_name_translation, port_translation,
 };
+use crate::translations::translations_4::{
+    host_namespace_translation, network_namespace_translation, other_translation,
+};
 use crate::utils::formatted_strings::{get_invalid_filters_string, get_path_termination_string};
 use crate::utils::types::file_info::FileInfo;
 use crate::utils::types::icon::Icon;
@@ -164,9 +168,14 @@ fn col_protocol_buttons(
     for option in Protocol::ALL {
         let is_active = active_protocol_filters.contains(&option);
         let check_symbol = if is_active { "✔" } else { "✘" };
+        let label = if option == Protocol::AnyOther {
+            other_translation(language).to_string()
+        } else {
+            option.to_string()
+        };
         buttons_row = buttons_row.push(
             Button::new(
-                Text::new(format!("{option} {check_symbol}"))
+                Text::new(format!("{label} {check_symbol}"))
                     .horizontal_alignment(Horizontal::Center)
                     .vertical_alignment(Vertical::Center)
                     .font(font),
@@ -301,8 +310,13 @@ fn button_start(
 fn get_col_adapter(sniffer: &Sniffer, font: Font) -> Column<Message, StyleType> {
     let ConfigSettings { language, .. } = sniffer.configs.lock().unwrap().settings;
 
+    let devices = if sniffer.device.netns.is_some() {
+        sniffer.namespace_devices.clone()
+    } else {
+        list_devices(None)
+    };
     let mut dev_str_list = vec![];
-    for dev in Device::list().expect("Error retrieving device list\r\n") {
+    for dev in devices {
         let mut dev_str = String::new();
         let name = dev.name;
         match dev.desc {
@@ -333,7 +347,7 @@ fn get_col_adapter(sniffer: &Sniffer, font: Font) -> Column<Message, StyleType>
         dev_str_list.push((name, dev_str));
     }
 
-    Column::new()
+    let mut col = Column::new()
         .padding(10)
         .spacing(5)
         .height(Length::Fill)
@@ -343,28 +357,74 @@ fn get_col_adapter(sniffer: &Sniffer, font: Font) -> Column<Message, StyleType>
                 .font(font)
                 .style(TextType::Title)
                 .size(FONT_SIZE_TITLE),
-        )
+        );
+
+    // network namespaces are only supported on Linux
+    if cfg!(target_os = "linux") {
+        col = col.push(row_network_namespace(sniffer, font, language));
+    }
+
+    col.push(
+        Scrollable::new(dev_str_list.iter().fold(
+            Column::new().padding(13).spacing(5),
+            |scroll_adapters, adapter| {
+                let name = adapter.0.clone();
+                let description = adapter.1.clone();
+                scroll_adapters.push(
+                    Button::new(Text::new(description).font(font))
+                        .padding([20, 30])
+                        .width(Length::Fill)
+                        .style(if name == sniffer.device.name {
+                            ButtonType::BorderedRoundSelected
+                        } else {
+                            ButtonType::BorderedRound
+                        })
+                        .on_press(Message::AdapterSelection(name)),
+                )
+            },
+        ))
+        .direction(Direction::Vertical(ScrollbarType::properties())),
+    )
+}
+
+fn row_network_namespace(
+    sniffer: &Sniffer,
+    font: Font,
+    language: Language,
+) -> Row<'_, Message, StyleType> {
+    let mut row = Row::new()
+        .padding([0, 13])
+        .spacing(5)
+        .align_items(Alignment::Center)
+        .push(Text::new(format!("{}:", network_namespace_translation(language))).font(font))
         .push(
-            Scrollable::new(dev_str_list.iter().fold(
-                Column::new().padding(13).spacing(5),
-                |scroll_adapters, adapter| {
-                    let name = adapter.0.clone();
-                    let description = adapter.1.clone();
-                    scroll_adapters.push(
-                        Button::new(Text::new(description).font(font))
-                            .padding([20, 30])
-                            .width(Length::Fill)
-                            .style(if name == sniffer.device.name {
-                                ButtonType::BorderedRoundSelected
-                            } else {
-                                ButtonType::BorderedRound
-                            })
-                            .on_press(Message::AdapterSelection(name)),
-                    )
-                },
-            ))
-            .direction(Direction::Vertical(ScrollbarType::properties())),
-        )
+            PickList::new(
+                &sniffer.network_namespaces[..],
+                sniffer.device.netns.clone(),
+                |netns| Message::NetworkNamespaceSelection(Some(netns)),
+            )
+            .on_open(Message::RefreshNetworkNamespaces)
+            .placeholder(host_namespace_translation(language))
+            .padding([2, 7])
+            .font(font),
+        );
+    if sniffer.device.netns.is_some() {
+        row = row.push(
+            button(
+                Text::new("×")
+                    .font(font)
+                    .vertical_alignment(Vertical::Center)
+                    .horizontal_alignment(Horizontal::Center)
+                    .size(15)
+                    .line_height(LineHeight::Relative(1.0)),
+            )
+            .padding(2)
+            .height(20)
+            .width(20)
+            .on_press(Message::NetworkNamespaceSelection(None)),
+        );
+    }
+    row
 }
 
 fn get_export_pcap_group(
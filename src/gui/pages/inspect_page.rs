@@ -9,7 +9,7 @@
     button, horizontal_space, text_input, vertical_space, Rule, Space, Toggler, Tooltip,
 };
 use iced::widget::{lazy, Button, Column, Container, Row, Scrollable, Text, TextInput};
-use iced::{alignment, Alignment, Font, Length, Pixels};
+use iced::{alignment, Alignment, Element, Font, Length, Pixels};
 
 use crate::gui::components::tab::get_pages_tabs;
 use crate::gui::components::types::my_modal::MyModal;
@@ -31,6 +31,9 @@
     no_search_results_translation, only_show_favorites_translation, showing_results_translation,
 };
 use crate::translations::translations_3::filter_by_host_translation;
+use crate::translations::translations_4::{
+    blocklisted_translation, http_host_translation, operating_system_translation,
+};
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, Language, ReportSortType, RunningPage, Sniffer, StyleType};
 
@@ -72,9 +75,10 @@ pub fn inspect_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
         move |_| lazy_report(sniffer),
     );
 
+    // the report is wider than the page and scrolls horizontally (rows have a padding of 2)
     let col_report = Column::new()
         .height(Length::Fill)
-        .width(Length::Fill)
+        .width(ReportCol::total_width() + 4.0)
         .align_items(Alignment::Start)
         .push(report_header_row(
             language,
@@ -93,12 +97,17 @@ pub fn inspect_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
                 .style(ContainerType::BorderedRound),
         )
         .push(
-            Container::new(col_report)
-                .align_y(Vertical::Center)
-                .align_x(Horizontal::Center)
-                .padding([10, 7, 3, 7])
-                .width(1042)
-                .style(ContainerType::BorderedRound),
+            Container::new(
+                Scrollable::new(col_report)
+                    .height(Length::Fill)
+                    .width(Length::Fill)
+                    .direction(Direction::Horizontal(ScrollbarType::properties())),
+            )
+            .align_y(Vertical::Center)
+            .align_x(Horizontal::Center)
+            .padding([10, 7, 3, 7])
+            .width(1180)
+            .style(ContainerType::BorderedRound),
         );
 
     Container::new(Column::new().push(tab_and_body.push(body))).height(Length::Fill)
@@ -122,12 +131,17 @@ fn lazy_report(sniffer: &Sniffer) -> Column<'static, Message, StyleType> {
     let end_entry_num = start_entry_num + search_results.len() - 1;
     for report_entry in search_results {
         scroll_report = scroll_report.push(
-            button(row_report_entry(&report_entry.0, &report_entry.1, font))
-                .padding(2)
-                .on_press(Message::ShowModal(MyModal::ConnectionDetails(
-                    report_entry.0,
-                )))
-                .style(ButtonType::Neutral),
+            button(row_report_entry(
+                &report_entry.0,
+                &report_entry.1,
+                font,
+                language,
+            ))
+            .padding(2)
+            .on_press(Message::ShowModal(MyModal::ConnectionDetails(
+                report_entry.0,
+            )))
+            .style(ButtonType::Neutral),
         );
     }
     if results_number > 0 {
@@ -200,7 +214,7 @@ fn report_header_row(
             .width(report_col.get_width())
             .height(56)
             .push(title_tooltip);
-        if report_col != ReportCol::Packets && report_col != ReportCol::Bytes {
+        if !report_col.is_sortable() {
             col_header = col_header.push(
                 Container::new(filter_input(
                     report_col.get_filter_input_type(),
@@ -277,35 +291,65 @@ fn row_report_entry(
     key: &AddressPortPair,
     val: &InfoAddressPortPair,
     font: Font,
+    language: Language,
 ) -> Row<'static, Message, StyleType> {
-    let text_type = if val.traffic_direction == TrafficDirection::Outgoing {
-        TextType::Outgoing
+    let (text_type, remote_col) = if val.traffic_direction == TrafficDirection::Outgoing {
+        (TextType::Outgoing, ReportCol::DstIp)
     } else {
-        TextType::Incoming
+        (TextType::Incoming, ReportCol::SrcIp)
     };
 
     let mut ret_val = Row::new().align_items(Alignment::Center);
 
     for report_col in ReportCol::ALL {
-        let max_chars = report_col.get_max_chars(None);
+        let blocklist = val.blocklist.as_ref().filter(|_| report_col == remote_col);
+        // leave room for the blocklist badge
+        let max_chars = report_col.get_max_chars(None) - if blocklist.is_some() { 2 } else { 0 };
         let col_value = report_col.get_value(key, val);
+        let text = Text::new(if col_value.len() <= max_chars {
+            col_value
+        } else {
+            [&col_value[..max_chars - 2], "…"].concat()
+        })
+        .font(font)
+        .style(text_type);
+        let cell: Element<Message, StyleType> = if let Some(blocklist) = blocklist {
+            blocklist_badge(text, blocklist, font, language).into()
+        } else {
+            text.into()
+        };
         ret_val = ret_val.push(
-            Container::new(
-                Text::new(if col_value.len() <= max_chars {
-                    col_value
-                } else {
-                    [&col_value[..max_chars - 2], "…"].concat()
-                })
-                .font(font)
-                .style(text_type),
-            )
-            .align_x(Horizontal::Center)
-            .width(report_col.get_width()),
+            Container::new(cell)
+                .align_x(Horizontal::Center)
+                .width(report_col.get_width()),
         );
     }
     ret_val
 }
 
+/// Prepends a badge to the remote address of connections matched by a blocklist
+fn blocklist_badge(
+    text: Text<'static, StyleType>,
+    blocklist: &str,
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, StyleType> {
+    Tooltip::new(
+        Row::new()
+            .spacing(3)
+            .align_items(Alignment::Center)
+            .push(Icon::Forbidden.to_text().size(14).style(TextType::Danger))
+            .push(text),
+        Text::new(format!(
+            "{}: {blocklist}",
+            blocklisted_translation(language)
+        ))
+        .font(font),
+        Position::FollowCursor,
+    )
+    .style(ContainerType::Tooltip)
+}
+
 fn host_filters_col(
     search_params: &SearchParameters,
     font: Font,
@@ -319,7 +363,10 @@ fn host_filters_col(
             .style(TextType::Subtitle)
             .size(FONT_SIZE_SUBTITLE),
     );
-    if search_params.is_some_host_filter_active() {
+    if search_params.is_some_host_filter_active()
+        || !search_params.http_host.is_empty()
+        || !search_params.os.is_empty()
+    {
         title_row = title_row.push(button_clear_filter(
             search_params.reset_host_filters(),
             font,
@@ -332,6 +379,9 @@ fn host_filters_col(
         filter_input(FilterInputType::Domain, search_params.clone(), font).width(190);
     let input_as_name =
         filter_input(FilterInputType::AsName, search_params.clone(), font).width(190);
+    let input_http_host =
+        filter_input(FilterInputType::HttpHost, search_params.clone(), font).width(190);
+    let input_os = filter_input(FilterInputType::Os, search_params.clone(), font).width(190);
 
     let container_country = Row::new()
         .spacing(5)
@@ -351,6 +401,18 @@ fn host_filters_col(
         .push(Text::new(format!("{}:", administrative_entity_translation(language))).font(font))
         .push(input_as_name);
 
+    let container_http_host = Row::new()
+        .spacing(5)
+        .align_items(Alignment::Center)
+        .push(Text::new(format!("{}:", http_host_translation(language))).font(font))
+        .push(input_http_host);
+
+    let container_os = Row::new()
+        .spacing(5)
+        .align_items(Alignment::Center)
+        .push(Text::new(format!("{}:", operating_system_translation(language))).font(font))
+        .push(input_os);
+
     let col1 = Column::new()
         .align_items(Alignment::Start)
         .spacing(5)
@@ -381,6 +443,12 @@ fn host_filters_col(
         .push(container_country)
         .push(container_as_name);
 
+    let col3 = Column::new()
+        .align_items(Alignment::Start)
+        .spacing(5)
+        .push(container_http_host)
+        .push(container_os);
+
     Column::new()
         .align_items(Alignment::Start)
         .push(title_row)
@@ -390,7 +458,8 @@ fn host_filters_col(
                 .align_items(Alignment::Center)
                 .spacing(30)
                 .push(col1)
-                .push(col2),
+                .push(col2)
+                .push(col3),
         )
 }
 
@@ -468,7 +537,7 @@ fn get_button_change_page(increment: bool) -> Button<'static, Message, StyleType
     .on_press(Message::UpdatePageNumber(increment))
 }
 
-fn get_change_page_row(
+pub fn get_change_page_row(
     font: Font,
     language: Language,
     page_number: usize,
@@ -1,4 +1,7 @@
+pub mod beacons_page;
 pub mod connection_details_page;
+pub mod devices_page;
+pub mod dns_page;
 pub mod initial_page;
 pub mod inspect_page;
 pub mod notifications_page;
@@ -8,6 +8,7 @@
 use iced::{Alignment, Font, Length};
 
 use crate::countries::country_utils::get_flag_tooltip;
+use crate::countries::types::country::Country;
 use crate::gui::components::header::get_button_settings;
 use crate::gui::components::tab::get_pages_tabs;
 use crate::gui::components::types::my_modal::MyModal;
@@ -17,8 +18,15 @@
 use crate::gui::styles::style_constants::FONT_SIZE_FOOTER;
 use crate::gui::styles::text::TextType;
 use crate::gui::types::message::Message;
+use crate::networking::types::dns_message::record_type_name;
+use crate::networking::types::exfiltration::ExfiltrationEvidence;
+use crate::networking::types::port_scan::ScanKind;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::notifications::types::alert_rules::RuleEvidence;
 use crate::notifications::types::logged_notification::{
-    BytesThresholdExceeded, FavoriteTransmitted, LoggedNotification, PacketsThresholdExceeded,
+    Beacons, BlocklistMatches, BytesThresholdExceeded, Exfiltration, FavoriteTransmitted,
+    LoggedNotification, MultipleDhcpServers, PacketsThresholdExceeded, PortScans, RuleMatches,
+    TrafficAnomaly,
 };
 use crate::translations::translations::{
     bytes_exceeded_translation, bytes_exceeded_value_translation, clear_all_translation,
@@ -27,8 +35,18 @@
     packets_exceeded_translation, packets_exceeded_value_translation, per_second_translation,
     threshold_translation,
 };
+use crate::translations::translations_4::{
+    beacon_description_translation, blocklist_matches_translation,
+    blocklisted_addresses_translation, dhcp_servers_translation, exfiltration_translation,
+    expected_observed_translation, horizontal_scan_translation, large_records_evidence_translation,
+    multiple_dhcp_servers_translation, periodic_hosts_translation, port_scans_translation,
+    rule_connection_evidence_translation, rule_matches_translation,
+    rule_new_host_evidence_translation, rule_traffic_evidence_translation, sensitivity_translation,
+    skewed_flow_evidence_translation, suspicious_label_evidence_translation,
+    traffic_anomaly_translation, unique_subdomains_evidence_translation, vertical_scan_translation,
+};
 use crate::utils::types::icon::Icon;
-use crate::{ByteMultiple, ConfigSettings, Language, RunningPage, Sniffer, StyleType};
+use crate::{ByteMultiple, ChartType, ConfigSettings, Language, RunningPage, Sniffer, StyleType};
 
 /// Computes the body of gui notifications page
 pub fn notifications_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
@@ -58,6 +76,13 @@ pub fn notifications_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
     if notifications.packets_notification.threshold.is_none()
         && notifications.bytes_notification.threshold.is_none()
         && !notifications.favorite_notification.notify_on_favorite
+        && !notifications.dhcp_notification.notify_on_multiple_servers
+        && !notifications.blocklist_notification.notify_on_match
+        && !notifications.port_scan_notification.notify_on_scan
+        && !notifications.anomaly_notification.notify_on_anomaly
+        && !notifications.beacon_notification.notify_on_beacon
+        && !notifications.exfiltration_notification.is_any_enabled()
+        && !notifications.rule_notification.notify_on_match
         && sniffer.runtime_data.logged_notifications.is_empty()
     {
         let body = body_no_notifications_set(font, language);
@@ -378,6 +403,519 @@ fn favorite_notification_log(
         .style(ContainerType::BorderedRound)
 }
 
+fn dhcp_notification_log(
+    logged_notification: MultipleDhcpServers,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, StyleType> {
+    let mut servers_col = Column::new().spacing(3);
+    for server in logged_notification.servers {
+        let mut server_str = format!(" - {}", server.address);
+        if let Some(mac_address) = server.mac_address {
+            server_str.push_str(&format!(" ({mac_address})"));
+        }
+        servers_col = servers_col.push(Text::new(server_str).font(font));
+    }
+
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Warning
+                    .to_text()
+                    .size(80)
+                    .line_height(LineHeight::Relative(1.0)),
+                Text::new(multiple_dhcp_servers_translation(language)).font(font),
+                Position::FollowCursor,
+            )
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(5)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp).font(font)),
+                )
+                .push(
+                    Text::new(multiple_dhcp_servers_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fill)
+                .push(Text::new(format!("{}:", dhcp_servers_translation(language))).font(font))
+                .push(
+                    Scrollable::new(servers_col)
+                        .direction(Direction::Vertical(ScrollbarType::properties())),
+                ),
+        );
+    Container::new(content)
+        .height(120)
+        .width(800)
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn blocklist_notification_log(
+    logged_notification: BlocklistMatches,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, StyleType> {
+    let mut matches_col = Column::new().spacing(3);
+    for (address, list) in logged_notification.matches {
+        matches_col = matches_col.push(Text::new(format!(" - {address} ({list})")).font(font));
+    }
+
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Forbidden
+                    .to_text()
+                    .size(80)
+                    .style(TextType::Danger)
+                    .line_height(LineHeight::Relative(1.0)),
+                Text::new(blocklist_matches_translation(language)).font(font),
+                Position::FollowCursor,
+            )
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(5)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp).font(font)),
+                )
+                .push(
+                    Text::new(blocklist_matches_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fill)
+                .push(
+                    Text::new(format!("{}:", blocklisted_addresses_translation(language)))
+                        .font(font),
+                )
+                .push(
+                    Scrollable::new(matches_col)
+                        .direction(Direction::Vertical(ScrollbarType::properties())),
+                ),
+        );
+    Container::new(content)
+        .height(120)
+        .width(800)
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn anomaly_notification_log(
+    logged_notification: TrafficAnomaly,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, StyleType> {
+    let mut anomalies_col = Column::new().spacing(3);
+    for anomaly in logged_notification.anomalies {
+        let formatted = |value: u128| match anomaly.chart_type {
+            ChartType::Bytes => ByteMultiple::formatted_string(value),
+            ChartType::Packets => value.to_string(),
+        };
+        let direction = match anomaly.direction {
+            TrafficDirection::Incoming => incoming_translation(language),
+            TrafficDirection::Outgoing => outgoing_translation(language),
+        };
+        anomalies_col = anomalies_col.push(
+            Text::new(format!(
+                " - {direction} ({}): {} {}",
+                anomaly.chart_type.get_label(language).to_lowercase(),
+                expected_observed_translation(
+                    language,
+                    &formatted(anomaly.expected),
+                    &formatted(anomaly.observed)
+                ),
+                per_second_translation(language)
+            ))
+            .font(font),
+        );
+    }
+
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Warning
+                    .to_text()
+                    .size(80)
+                    .line_height(LineHeight::Relative(1.0)),
+                Text::new(traffic_anomaly_translation(language)).font(font),
+                Position::FollowCursor,
+            )
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(5)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp).font(font)),
+                )
+                .push(
+                    Text::new(traffic_anomaly_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                )
+                .push(
+                    Text::new(format!(
+                        "{}: {}",
+                        sensitivity_translation(language),
+                        logged_notification.sensitivity.get_label(language)
+                    ))
+                    .size(FONT_SIZE_FOOTER)
+                    .style(TextType::Subtitle)
+                    .font(font),
+                ),
+        )
+        .push(
+            Column::new().spacing(7).width(Length::Fill).push(
+                Scrollable::new(anomalies_col)
+                    .direction(Direction::Vertical(ScrollbarType::properties())),
+            ),
+        );
+    Container::new(content)
+        .height(120)
+        .width(800)
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn beacon_notification_log(
+    logged_notification: Beacons,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, StyleType> {
+    let mut beacons_col = Column::new().spacing(3);
+    for (address, beacon) in logged_notification.beacons {
+        beacons_col = beacons_col.push(
+            Text::new(format!(
+                " - {address}: {}",
+                beacon_description_translation(language, beacon.period_secs(), beacon.score)
+            ))
+            .font(font),
+        );
+    }
+
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Clock
+                    .to_text()
+                    .size(80)
+                    .style(TextType::Danger)
+                    .line_height(LineHeight::Relative(1.0)),
+                Text::new(periodic_hosts_translation(language)).font(font),
+                Position::FollowCursor,
+            )
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(5)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp).font(font)),
+                )
+                .push(
+                    Text::new(periodic_hosts_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new().spacing(7).width(Length::Fill).push(
+                Scrollable::new(beacons_col)
+                    .direction(Direction::Vertical(ScrollbarType::properties())),
+            ),
+        );
+    Container::new(content)
+        .height(120)
+        .width(800)
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn port_scan_notification_log(
+    logged_notification: PortScans,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, StyleType> {
+    let mut scans_col = Column::new().spacing(3);
+    for scan in logged_notification.scans {
+        let description = match &scan.kind {
+            ScanKind::Horizontal => {
+                horizontal_scan_translation(language, &scan.scanner, scan.targets, scan.ports.0)
+            }
+            ScanKind::Vertical(target) => {
+                vertical_scan_translation(language, &scan.scanner, target, scan.targets, scan.ports)
+            }
+        };
+        scans_col =
+            scans_col.push(Text::new(format!(" - [{}] {description}", scan.protocol)).font(font));
+    }
+
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Warning
+                    .to_text()
+                    .size(80)
+                    .style(TextType::Danger)
+                    .line_height(LineHeight::Relative(1.0)),
+                Text::new(port_scans_translation(language)).font(font),
+                Position::FollowCursor,
+            )
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(5)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp).font(font)),
+                )
+                .push(
+                    Text::new(port_scans_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                ),
+        )
+        .push(Column::new().spacing(7).width(Length::Fill).push(
+            Scrollable::new(scans_col).direction(Direction::Vertical(ScrollbarType::properties())),
+        ));
+    Container::new(content)
+        .height(120)
+        .width(800)
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn exfiltration_notification_log(
+    logged_notification: Exfiltration,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, StyleType> {
+    let mut alerts_col = Column::new().spacing(3);
+    for alert in logged_notification.alerts {
+        let description = match &alert.evidence {
+            ExfiltrationEvidence::SuspiciousLabel {
+                name,
+                label_len,
+                entropy,
+            } => suspicious_label_evidence_translation(language, name, *label_len, *entropy),
+            ExfiltrationEvidence::UniqueSubdomains { parent, count } => {
+                unique_subdomains_evidence_translation(language, parent, *count)
+            }
+            ExfiltrationEvidence::LargeRecords {
+                name,
+                record_type,
+                size,
+            } => large_records_evidence_translation(
+                language,
+                name,
+                &record_type_name(*record_type),
+                *size,
+            ),
+            ExfiltrationEvidence::SkewedFlow {
+                remote_address,
+                remote_port,
+                outgoing_bytes,
+                incoming_bytes,
+            } => {
+                let remote = match remote_port {
+                    Some(port) => format!("{remote_address}:{port}"),
+                    None => remote_address.clone(),
+                };
+                skewed_flow_evidence_translation(
+                    language,
+                    &remote,
+                    &ByteMultiple::formatted_string(*outgoing_bytes),
+                    &ByteMultiple::formatted_string(*incoming_bytes),
+                )
+            }
+        };
+        alerts_col =
+            alerts_col.push(Text::new(format!(" - {}: {description}", alert.host)).font(font));
+    }
+
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Warning
+                    .to_text()
+                    .size(80)
+                    .style(TextType::Danger)
+                    .line_height(LineHeight::Relative(1.0)),
+                Text::new(exfiltration_translation(language)).font(font),
+                Position::FollowCursor,
+            )
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(5)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp).font(font)),
+                )
+                .push(
+                    Text::new(exfiltration_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                ),
+        )
+        .push(Column::new().spacing(7).width(Length::Fill).push(
+            Scrollable::new(alerts_col).direction(Direction::Vertical(ScrollbarType::properties())),
+        ));
+    Container::new(content)
+        .height(120)
+        .width(800)
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn rule_notification_log(
+    logged_notification: RuleMatches,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, StyleType> {
+    let mut matches_col = Column::new().spacing(3);
+    for rule_match in logged_notification.matches {
+        let description = match &rule_match.evidence {
+            RuleEvidence::Connection(key) => {
+                let endpoint = |address: &String, port: Option<u16>| match port {
+                    Some(port) => format!("{address}:{port}"),
+                    None => address.clone(),
+                };
+                rule_connection_evidence_translation(
+                    language,
+                    &key.protocol.to_string(),
+                    &endpoint(&key.address1, key.port1),
+                    &endpoint(&key.address2, key.port2),
+                )
+            }
+            RuleEvidence::Traffic {
+                packets,
+                bytes,
+                window,
+            } => rule_traffic_evidence_translation(
+                language,
+                &ByteMultiple::formatted_string(*bytes),
+                *packets,
+                *window,
+            ),
+            RuleEvidence::NewHost { address, host } => {
+                let mut details = vec![address.clone()];
+                if !host.asn.code.is_empty() {
+                    details.push(format!("AS{}", host.asn.code));
+                }
+                if host.country != Country::ZZ {
+                    details.push(host.country.to_string());
+                }
+                rule_new_host_evidence_translation(
+                    language,
+                    &format!("{} ({})", host.domain, details.join(", ")),
+                )
+            }
+        };
+        matches_col = matches_col
+            .push(Text::new(format!(" - {}: {description}", rule_match.rule)).font(font));
+    }
+
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Warning
+                    .to_text()
+                    .size(80)
+                    .style(TextType::Danger)
+                    .line_height(LineHeight::Relative(1.0)),
+                Text::new(rule_matches_translation(language)).font(font),
+                Position::FollowCursor,
+            )
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(250)
+                .spacing(7)
+                .push(
+                    Row::new()
+                        .spacing(5)
+                        .push(Icon::Clock.to_text())
+                        .push(Text::new(logged_notification.timestamp).font(font)),
+                )
+                .push(
+                    Text::new(rule_matches_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new().spacing(7).width(Length::Fill).push(
+                Scrollable::new(matches_col)
+                    .direction(Direction::Vertical(ScrollbarType::properties())),
+            ),
+        );
+    Container::new(content)
+        .height(120)
+        .width(800)
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
 fn get_button_clear_all(font: Font, language: Language) -> Tooltip<'static, Message, StyleType> {
     let content = button(
         Icon::Bin
@@ -422,6 +960,27 @@ fn lazy_logged_notifications(sniffer: &Sniffer) -> Column<'static, Message, Styl
             LoggedNotification::FavoriteTransmitted(favorite_transmitted) => {
                 favorite_notification_log(favorite_transmitted.clone(), language, font)
             }
+            LoggedNotification::MultipleDhcpServers(multiple_dhcp_servers) => {
+                dhcp_notification_log(multiple_dhcp_servers.clone(), language, font)
+            }
+            LoggedNotification::BlocklistMatches(blocklist_matches) => {
+                blocklist_notification_log(blocklist_matches.clone(), language, font)
+            }
+            LoggedNotification::PortScans(port_scans) => {
+                port_scan_notification_log(port_scans.clone(), language, font)
+            }
+            LoggedNotification::TrafficAnomaly(traffic_anomaly) => {
+                anomaly_notification_log(traffic_anomaly.clone(), language, font)
+            }
+            LoggedNotification::Beacons(beacons) => {
+                beacon_notification_log(beacons.clone(), language, font)
+            }
+            LoggedNotification::Exfiltration(exfiltration) => {
+                exfiltration_notification_log(exfiltration.clone(), language, font)
+            }
+            LoggedNotification::RuleMatches(rule_matches) => {
+                rule_notification_log(rule_matches.clone(), language, font)
+            }
         });
     }
     ret_val
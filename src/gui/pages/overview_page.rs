@@ -30,7 +30,9 @@
 use crate::networking::types::filters::Filters;
 use crate::networking::types::host::Host;
 use crate::networking::types::my_device::MyDevice;
-use crate::report::get_report_entries::{get_host_entries, get_service_entries};
+use crate::report::get_report_entries::{
+    get_app_entries, get_blocklisted_hosts, get_host_entries, get_service_entries,
+};
 use crate::report::types::search_parameters::SearchParameters;
 use crate::report::types::sort_type::SortType;
 use crate::translations::translations::{
@@ -44,6 +46,9 @@
     only_top_30_items_translation,
 };
 use crate::translations::translations_3::{service_translation, unsupported_link_type_translation};
+use crate::translations::translations_4::{
+    application_translation, blocklisted_translation, container_translation,
+};
 use crate::utils::formatted_strings::{get_active_filters_string, get_percentage_string};
 use crate::utils::types::icon::Icon;
 use crate::{ByteMultiple, ChartType, ConfigSettings, Language, RunningPage, StyleType};
@@ -110,6 +115,8 @@ pub fn overview_page(sniffer: &Sniffer) -> Container<Message, StyleType> {
                         sniffer.traffic_chart.chart_type,
                         sniffer.host_sort_type,
                         sniffer.service_sort_type,
+                        sniffer.app_sort_type,
+                        sniffer.group_by_container,
                     ),
                     move |_| lazy_row_report(sniffer),
                 );
@@ -244,14 +251,22 @@ fn body_pcap_error(
 }
 
 fn lazy_row_report(sniffer: &Sniffer) -> Container<'static, Message, StyleType> {
-    let col_host = col_host(840.0, sniffer);
-    let col_service = col_service(250.0, sniffer);
-
-    let row_report = Row::new()
-        .padding([0, 10, 5, 10])
-        .push(col_host)
+    // the local processes are only resolved on Linux
+    let show_apps =
+        cfg!(target_os = "linux") || !sniffer.info_traffic.lock().unwrap().apps.is_empty();
+
+    let mut row_report = Row::new().padding([0, 10, 5, 10]);
+    if show_apps {
+        row_report = row_report
+            .push(col_host(550.0, sniffer))
+            .push(Rule::vertical(40))
+            .push(col_app(250.0, sniffer));
+    } else {
+        row_report = row_report.push(col_host(840.0, sniffer));
+    }
+    row_report = row_report
         .push(Rule::vertical(40))
-        .push(col_service);
+        .push(col_service(250.0, sniffer));
 
     Container::new(row_report)
         .height(FillPortion(4))
@@ -267,6 +282,7 @@ fn col_host(width: f32, sniffer: &Sniffer) -> Column<'static, Message, StyleType
 
     let mut scroll_host = Column::new().width(width).align_items(Alignment::Center);
     let entries = get_host_entries(&sniffer.info_traffic, chart_type, sniffer.host_sort_type);
+    let blocklisted_hosts = get_blocklisted_hosts(&sniffer.info_traffic);
     let first_entry_data_info = entries
         .iter()
         .map(|(_, d)| d.data_info)
@@ -283,11 +299,18 @@ fn col_host(width: f32, sniffer: &Sniffer) -> Column<'static, Message, StyleType
 
         let star_button = get_star_button(data_info_host.is_favorite, host.clone());
 
+        let mut host_name_row = Row::new().align_items(Alignment::Center);
+        if let Some(blocklist) = blocklisted_hosts.get(host) {
+            host_name_row = host_name_row
+                .push(get_blocklist_badge(blocklist, font, language))
+                .push(Space::with_width(5));
+        }
+
         let host_bar = Column::new()
             .width(width)
             .spacing(1)
             .push(
-                Row::new()
+                host_name_row
                     .push(Text::new(host.domain.clone()).font(font))
                     .push(
                         Text::new(if host.asn.name.is_empty() {
@@ -363,6 +386,124 @@ fn col_host(width: f32, sniffer: &Sniffer) -> Column<'static, Message, StyleType
         )
 }
 
+fn col_app(width: f32, sniffer: &Sniffer) -> Column<'static, Message, StyleType> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let chart_type = sniffer.traffic_chart.chart_type;
+
+    let mut scroll_app = Column::new().width(width).align_items(Alignment::Center);
+    let group_by_container = sniffer.group_by_container;
+    let entries = get_app_entries(
+        &sniffer.info_traffic,
+        chart_type,
+        sniffer.app_sort_type,
+        group_by_container,
+    );
+    let first_entry_data_info = entries
+        .iter()
+        .map(|&(_, d)| d)
+        .max_by(|d1, d2| d1.compare(d2, SortType::Ascending, chart_type))
+        .unwrap_or_default();
+
+    for (app, data_info) in &entries {
+        let (incoming_bar_len, outgoing_bar_len) =
+            get_bars_length(width * 0.88, chart_type, &first_entry_data_info, data_info);
+
+        let content = Column::new()
+            .spacing(1)
+            .width(width)
+            .push(
+                Row::new()
+                    .push(Text::new(app.clone()).font(font))
+                    .push(horizontal_space())
+                    .push(
+                        Text::new(if chart_type.eq(&ChartType::Packets) {
+                            data_info.tot_packets().to_string()
+                        } else {
+                            ByteMultiple::formatted_string(data_info.tot_bytes())
+                        })
+                        .font(font),
+                    ),
+            )
+            .push(get_bars(incoming_bar_len, outgoing_bar_len));
+
+        scroll_app = scroll_app.push(
+            button(content)
+                .padding([5, 15, 8, 10])
+                .on_press(Message::Search(if group_by_container {
+                    SearchParameters::new_container_search(app)
+                } else {
+                    SearchParameters::new_app_search(app)
+                }))
+                .style(ButtonType::Neutral),
+        );
+    }
+
+    if entries.len() >= 30 {
+        scroll_app = scroll_app.push(Space::with_height(25)).push(
+            Text::new(only_top_30_items_translation(language))
+                .font(font)
+                .horizontal_alignment(Horizontal::Center),
+        );
+    }
+
+    Column::new()
+        .width(width + 11.0)
+        .push(
+            Row::new()
+                .height(45)
+                .align_items(Alignment::Center)
+                .spacing(10)
+                .push(app_grouping_title(
+                    application_translation(language),
+                    false,
+                    group_by_container,
+                    font,
+                ))
+                .push(app_grouping_title(
+                    container_translation(language),
+                    true,
+                    group_by_container,
+                    font,
+                ))
+                .push(horizontal_space())
+                .push(sort_arrows(
+                    sniffer.app_sort_type,
+                    Message::AppSortSelection,
+                )),
+        )
+        .push(
+            Scrollable::new(scroll_app)
+                .width(Length::Fill)
+                .direction(Direction::Vertical(ScrollbarType::properties())),
+        )
+}
+
+/// Title of the applications column, which can be clicked to group the traffic accordingly
+fn app_grouping_title(
+    title: &'static str,
+    by_container: bool,
+    group_by_container: bool,
+    font: Font,
+) -> Button<'static, Message, StyleType> {
+    let is_active = by_container == group_by_container;
+    button(
+        Text::new(title)
+            .font(font)
+            .style(if is_active {
+                TextType::Title
+            } else {
+                TextType::Subtitle
+            })
+            .size(FONT_SIZE_TITLE),
+    )
+    .padding(0)
+    .style(ButtonType::Neutral)
+    .on_press(Message::GroupByContainer(by_container))
+}
+
 fn col_service(width: f32, sniffer: &Sniffer) -> Column<'static, Message, StyleType> {
     let ConfigSettings {
         style, language, ..
@@ -747,6 +888,23 @@ fn get_bars(in_len: f32, out_len: f32) -> Row<'static, Message, StyleType> {
         })
 }
 
+fn get_blocklist_badge(
+    blocklist: &str,
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, StyleType> {
+    Tooltip::new(
+        Icon::Forbidden.to_text().style(TextType::Danger),
+        Text::new(format!(
+            "{}: {blocklist}",
+            blocklisted_translation(language)
+        ))
+        .font(font),
+        Position::FollowCursor,
+    )
+    .style(ContainerType::Tooltip)
+}
+
 fn get_star_button(is_favorite: bool, host: Host) -> Button<'static, Message, StyleType> {
     button(
         Icon::Star
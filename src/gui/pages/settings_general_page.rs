@@ -1,10 +1,13 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use iced::alignment::{Horizontal, Vertical};
+use iced::widget::scrollable::Direction;
 use iced::widget::text::LineHeight;
 use iced::widget::tooltip::Position;
 use iced::widget::{
-    button, vertical_space, Column, Container, PickList, Row, Rule, Slider, Space, Text, Tooltip,
+    button, vertical_space, Column, Container, PickList, Row, Rule, Scrollable, Slider, Space,
+    Text, Tooltip,
 };
 use iced::{Alignment, Font, Length};
 
@@ -14,15 +17,28 @@
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::button::ButtonType;
 use crate::gui::styles::container::ContainerType;
-use crate::gui::styles::style_constants::FONT_SIZE_SUBTITLE;
+use crate::gui::styles::scrollbar::ScrollbarType;
+use crate::gui::styles::style_constants::{FONT_SIZE_FOOTER, FONT_SIZE_SUBTITLE};
 use crate::gui::styles::text::TextType;
 use crate::gui::types::message::Message;
 use crate::mmdb::types::mmdb_reader::MmdbReader;
+use crate::networking::types::blocklist::Blocklists;
+use crate::networking::types::port_scan::ScanThresholds;
+use crate::networking::types::tunnel::TunnelAccounting;
+use crate::notifications::types::alert_rules::AlertRules;
+use crate::report::get_report_entries::get_blocklist_hits;
 use crate::translations::translations::language_translation;
 use crate::translations::translations_2::country_translation;
 use crate::translations::translations_3::{
-    learn_more_translation, mmdb_files_translation, params_not_editable_translation,
-    zoom_translation,
+    directory_translation, learn_more_translation, mmdb_files_translation,
+    params_not_editable_translation, zoom_translation,
+};
+use crate::translations::translations_4::{
+    alert_rules_translation, applied_next_capture_translation, blocklist_hits_translation,
+    blocklists_loaded_translation, blocklists_translation, hosts_on_same_port_translation,
+    never_translation, port_scan_detection_translation, ports_on_same_host_translation,
+    reload_every_translation, rules_file_translation, rules_loaded_translation,
+    time_window_translation, tunnel_accounting_translation,
 };
 use crate::utils::formatted_strings::get_path_termination_string;
 use crate::utils::types::file_info::FileInfo;
@@ -51,7 +67,10 @@ pub fn settings_general_page(sniffer: &Sniffer) -> Container<Message, StyleType>
         ))
         .push(get_settings_tabs(SettingsPage::General, font, language))
         .push(Space::with_height(10))
-        .push(column_all_general_setting(sniffer, font));
+        .push(
+            Scrollable::new(column_all_general_setting(sniffer, font))
+                .direction(Direction::Vertical(ScrollbarType::properties())),
+        );
 
     Container::new(content)
         .height(400)
@@ -68,6 +87,11 @@ fn column_all_general_setting(
         scale_factor,
         mmdb_country,
         mmdb_asn,
+        tunnel_accounting,
+        blocklists_dir,
+        blocklists_reload,
+        rules_file,
+        scan_thresholds,
         ..
     } = sniffer.configs.lock().unwrap().settings.clone();
 
@@ -99,6 +123,38 @@ fn column_all_general_setting(
         &sniffer.asn_mmdb_reader,
     ));
 
+    column = column.push(Rule::horizontal(25)).push(tunnel_settings(
+        is_editable,
+        language,
+        font,
+        tunnel_accounting,
+    ));
+
+    column = column.push(Rule::horizontal(25)).push(port_scan_settings(
+        is_editable,
+        language,
+        font,
+        scan_thresholds,
+    ));
+
+    // info_traffic must be released before locking the blocklists (see Sniffer::blocklists)
+    let blocklist_hits = get_blocklist_hits(&sniffer.info_traffic);
+    column = column.push(Rule::horizontal(25)).push(blocklists_settings(
+        language,
+        font,
+        &blocklists_dir,
+        blocklists_reload,
+        &sniffer.blocklists.lock().unwrap(),
+        &blocklist_hits,
+    ));
+
+    column = column.push(Rule::horizontal(25)).push(rules_settings(
+        language,
+        font,
+        &rules_file,
+        &sniffer.alert_rules.lock().unwrap(),
+    ));
+
     column
 }
 
@@ -285,6 +341,300 @@ fn mmdb_settings(
         ))
 }
 
+fn tunnel_settings(
+    is_editable: bool,
+    language: Language,
+    font: Font,
+    tunnel_accounting: TunnelAccounting,
+) -> Column<'static, Message, StyleType> {
+    let mut buttons_row = Row::new().spacing(5);
+    for option in TunnelAccounting::ALL {
+        let is_active = option.eq(&tunnel_accounting);
+        let mut button = button(
+            Text::new(option.get_label(language))
+                .horizontal_alignment(Horizontal::Center)
+                .vertical_alignment(Vertical::Center)
+                .font(font),
+        )
+        .width(90)
+        .height(35)
+        .style(if is_active {
+            ButtonType::BorderedRoundSelected
+        } else {
+            ButtonType::BorderedRound
+        });
+        if is_editable {
+            button = button.on_press(Message::TunnelAccountingSelection(option));
+        }
+        buttons_row = buttons_row.push(button);
+    }
+
+    Column::new()
+        .spacing(5)
+        .padding([0, 0, 10, 0])
+        .align_items(Alignment::Center)
+        .push(
+            Text::new(tunnel_accounting_translation(language))
+                .font(font)
+                .style(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(buttons_row)
+        // the flows of a running capture are not split or merged again
+        .push(
+            Text::new(applied_next_capture_translation(language))
+                .font(font)
+                .size(FONT_SIZE_FOOTER),
+        )
+}
+
+fn port_scan_settings(
+    is_editable: bool,
+    language: Language,
+    font: Font,
+    scan_thresholds: ScanThresholds,
+) -> Column<'static, Message, StyleType> {
+    let thresholds_row =
+        |label: &str,
+         options: [(u32, String); 4],
+         current: u32,
+         update: fn(ScanThresholds, u32) -> ScanThresholds| {
+            let mut row = Row::new()
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .push(Text::new(format!("{label}:")).font(font).width(200));
+            for (value, label) in options {
+                let mut button = button(
+                    Text::new(label)
+                        .horizontal_alignment(Horizontal::Center)
+                        .vertical_alignment(Vertical::Center)
+                        .font(font),
+                )
+                .width(70)
+                .height(30)
+                .style(if value == current {
+                    ButtonType::BorderedRoundSelected
+                } else {
+                    ButtonType::BorderedRound
+                });
+                if is_editable {
+                    button =
+                        button.on_press(Message::ScanThresholds(update(scan_thresholds, value)));
+                }
+                row = row.push(button);
+            }
+            row
+        };
+
+    let window_options = ScanThresholds::WINDOW_OPTIONS.map(|secs| {
+        let label = if secs < 60 {
+            format!("{secs} s")
+        } else {
+            format!("{} min", secs / 60)
+        };
+        (secs, label)
+    });
+    let targets_options = ScanThresholds::TARGETS_OPTIONS.map(|n| (n, n.to_string()));
+
+    Column::new()
+        .spacing(5)
+        .padding([0, 0, 10, 0])
+        .align_items(Alignment::Center)
+        .push(
+            Text::new(port_scan_detection_translation(language))
+                .font(font)
+                .style(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(thresholds_row(
+            time_window_translation(language),
+            window_options,
+            scan_thresholds.window,
+            |thresholds, window| ScanThresholds {
+                window,
+                ..thresholds
+            },
+        ))
+        .push(thresholds_row(
+            hosts_on_same_port_translation(language),
+            targets_options.clone(),
+            scan_thresholds.hosts,
+            |thresholds, hosts| ScanThresholds {
+                hosts,
+                ..thresholds
+            },
+        ))
+        .push(thresholds_row(
+            ports_on_same_host_translation(language),
+            targets_options,
+            scan_thresholds.ports,
+            |thresholds, ports| ScanThresholds {
+                ports,
+                ..thresholds
+            },
+        ))
+}
+
+/// Blocklists can be changed while running: they're matched again against all the connections
+fn blocklists_settings(
+    language: Language,
+    font: Font,
+    directory: &str,
+    reload_minutes: u32,
+    blocklists: &Blocklists,
+    hits: &HashMap<String, usize>,
+) -> Column<'static, Message, StyleType> {
+    let directory_row = Row::new()
+        .align_items(Alignment::Center)
+        .push(Text::new(format!("{}: ", directory_translation(language))).font(font))
+        .push(Text::new(get_path_termination_string(directory, 25)).font(font))
+        .push(if directory.is_empty() {
+            button_open_file(
+                directory.to_owned(),
+                FileInfo::Directory,
+                language,
+                font,
+                true,
+                Message::BlocklistsDirectory,
+            )
+        } else {
+            button_clear_mmdb(Message::BlocklistsDirectory, font, true)
+        });
+
+    let mut reload_row = Row::new()
+        .spacing(5)
+        .align_items(Alignment::Center)
+        .push(Text::new(format!("{}:", reload_every_translation(language))).font(font));
+    for (minutes, label) in [
+        (0, never_translation(language)),
+        (15, "15 min"),
+        (60, "1 h"),
+        (360, "6 h"),
+        (1440, "24 h"),
+    ] {
+        reload_row = reload_row.push(
+            button(
+                Text::new(label)
+                    .horizontal_alignment(Horizontal::Center)
+                    .vertical_alignment(Vertical::Center)
+                    .font(font),
+            )
+            .width(70)
+            .height(30)
+            .style(if minutes == reload_minutes {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::BlocklistsReload(minutes)),
+        );
+    }
+
+    let mut ret_val = Column::new()
+        .spacing(5)
+        .padding([0, 0, 10, 0])
+        .align_items(Alignment::Center)
+        .push(
+            Text::new(blocklists_translation(language))
+                .font(font)
+                .style(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(directory_row);
+    if !directory.is_empty() {
+        let (lists, ranges) = blocklists.summary();
+        ret_val = ret_val.push(
+            Text::new(blocklists_loaded_translation(language, lists, ranges))
+                .font(font)
+                .style(if lists == 0 {
+                    TextType::Danger
+                } else {
+                    TextType::Standard
+                }),
+        );
+        // entries and connections matched by each list
+        for list in blocklists.lists() {
+            let list_hits = hits.get(&list.name).copied().unwrap_or_default();
+            ret_val = ret_val.push(
+                Row::new()
+                    .spacing(5)
+                    .push(Text::new(format!("{}:", list.name)).font(font))
+                    .push(
+                        Text::new(blocklist_hits_translation(language, list.len(), list_hits))
+                            .font(font)
+                            .style(if list_hits > 0 {
+                                TextType::Danger
+                            } else {
+                                TextType::Subtitle
+                            }),
+                    ),
+            );
+        }
+    }
+    ret_val.push(reload_row)
+}
+
+/// Alert rules can be changed while running: they're reloaded whenever their file is modified
+fn rules_settings(
+    language: Language,
+    font: Font,
+    path: &str,
+    alert_rules: &AlertRules,
+) -> Column<'static, Message, StyleType> {
+    let file_row = Row::new()
+        .align_items(Alignment::Center)
+        .push(Text::new(format!("{}: ", rules_file_translation(language))).font(font))
+        .push(Text::new(get_path_termination_string(path, 25)).font(font))
+        .push(if path.is_empty() {
+            button_open_file(
+                path.to_owned(),
+                FileInfo::Rules,
+                language,
+                font,
+                true,
+                Message::RulesFile,
+            )
+        } else {
+            button_clear_mmdb(Message::RulesFile, font, true)
+        });
+
+    let mut ret_val = Column::new()
+        .spacing(5)
+        .padding([0, 0, 10, 0])
+        .align_items(Alignment::Center)
+        .push(
+            Text::new(alert_rules_translation(language))
+                .font(font)
+                .style(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        )
+        .push(file_row);
+    if !path.is_empty() {
+        ret_val = ret_val.push(if let Some(error) = alert_rules.error() {
+            Text::new(error.to_string())
+                .font(font)
+                .style(TextType::Danger)
+        } else {
+            Text::new(rules_loaded_translation(
+                language,
+                alert_rules.rules().len(),
+            ))
+            .font(font)
+        });
+        for rule in alert_rules.rules() {
+            ret_val = ret_val.push(Text::new(format!(" - {}", rule.name)).font(font));
+        }
+        for error in alert_rules.skipped() {
+            ret_val = ret_val.push(
+                Text::new(format!(" - {error}"))
+                    .font(font)
+                    .style(TextType::Danger),
+            );
+        }
+    }
+    ret_val
+}
+
 fn mmdb_selection_row(
     is_editable: bool,
     font: Font,
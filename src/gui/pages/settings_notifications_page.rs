@@ -14,16 +14,25 @@
 use crate::gui::styles::text::TextType;
 use crate::gui::styles::types::gradient_type::GradientType;
 use crate::gui::types::message::Message;
+use crate::networking::types::exfiltration::ExfiltrationHeuristic;
 use crate::notifications::types::notifications::{
-    BytesNotification, FavoriteNotification, Notification, PacketsNotification,
+    AnomalyNotification, BeaconNotification, BlocklistNotification, BytesNotification,
+    DhcpNotification, ExfiltrationNotification, FavoriteNotification, Notification,
+    PacketsNotification, PortScanNotification, RuleNotification,
 };
 use crate::notifications::types::sound::Sound;
+use crate::notifications::types::traffic_baseline::AnomalySensitivity;
 use crate::translations::translations::{
     bytes_threshold_translation, favorite_notification_translation,
     notifications_title_translation, packets_threshold_translation, per_second_translation,
     settings_translation, sound_translation, specify_multiples_translation, threshold_translation,
     volume_translation,
 };
+use crate::translations::translations_4::{
+    anomaly_notification_translation, beacon_notification_translation,
+    blocklist_notification_translation, dhcp_notification_translation, exfiltration_translation,
+    port_scan_notification_translation, rule_notification_translation, sensitivity_translation,
+};
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, Language, Sniffer, StyleType};
 
@@ -81,10 +90,45 @@ pub fn settings_notifications_page(sniffer: &Sniffer) -> Container<Message, Styl
                         language,
                         font,
                     ))
+                    .push(get_anomaly_notify(
+                        notifications.anomaly_notification,
+                        language,
+                        font,
+                    ))
                     .push(get_favorite_notify(
                         notifications.favorite_notification,
                         language,
                         font,
+                    ))
+                    .push(get_dhcp_notify(
+                        notifications.dhcp_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_blocklist_notify(
+                        notifications.blocklist_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_port_scan_notify(
+                        notifications.port_scan_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_beacon_notify(
+                        notifications.beacon_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_exfiltration_notify(
+                        notifications.exfiltration_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_rule_notify(
+                        notifications.rule_notification,
+                        language,
+                        font,
                     )),
             )
             .direction(Direction::Vertical(ScrollbarType::properties())),
@@ -204,6 +248,74 @@ fn get_bytes_notify(
     }
 }
 
+fn get_anomaly_notify(
+    anomaly_notification: AnomalyNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, StyleType> {
+    let AnomalyNotification {
+        notify_on_anomaly,
+        sensitivity,
+        sound,
+    } = anomaly_notification;
+    let checkbox = Checkbox::new(
+        anomaly_notification_translation(language),
+        notify_on_anomaly,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            if toggled {
+                Notification::Anomaly(AnomalyNotification::on(sensitivity, sound))
+            } else {
+                Notification::Anomaly(AnomalyNotification::off(sensitivity, sound))
+            },
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(10).push(checkbox);
+
+    if notify_on_anomaly {
+        let mut sensitivity_row = Row::new()
+            .align_items(Alignment::Center)
+            .spacing(5)
+            .push(Space::with_width(45))
+            .push(Text::new(format!("{}:", sensitivity_translation(language))).font(font));
+        for option in AnomalySensitivity::ALL {
+            sensitivity_row = sensitivity_row.push(
+                Button::new(
+                    Text::new(option.get_label(language))
+                        .horizontal_alignment(Horizontal::Center)
+                        .vertical_alignment(Vertical::Center)
+                        .font(font),
+                )
+                .padding(0)
+                .width(80)
+                .height(25)
+                .style(if option == sensitivity {
+                    ButtonType::BorderedRoundSelected
+                } else {
+                    ButtonType::BorderedRound
+                })
+                .on_press(Message::UpdateNotificationSettings(
+                    Notification::Anomaly(AnomalyNotification::on(option, sound)),
+                    false,
+                )),
+            );
+        }
+        let sound_row = sound_buttons(Notification::Anomaly(anomaly_notification), font, language);
+        ret_val = ret_val.push(sensitivity_row).push(sound_row);
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .style(ContainerType::BorderedRound),
+    )
+}
+
 fn get_favorite_notify(
     favorite_notification: FavoriteNotification,
     language: Language,
@@ -251,6 +363,235 @@ fn get_favorite_notify(
     }
 }
 
+fn get_dhcp_notify(
+    dhcp_notification: DhcpNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, StyleType> {
+    let checkbox = Checkbox::new(
+        dhcp_notification_translation(language),
+        dhcp_notification.notify_on_multiple_servers,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            if toggled {
+                Notification::Dhcp(DhcpNotification::on(dhcp_notification.sound))
+            } else {
+                Notification::Dhcp(DhcpNotification::off(dhcp_notification.sound))
+            },
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(10).push(checkbox);
+
+    if dhcp_notification.notify_on_multiple_servers {
+        let sound_row = sound_buttons(Notification::Dhcp(dhcp_notification), font, language);
+        ret_val = ret_val.push(sound_row);
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn get_blocklist_notify(
+    blocklist_notification: BlocklistNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, StyleType> {
+    let checkbox = Checkbox::new(
+        blocklist_notification_translation(language),
+        blocklist_notification.notify_on_match,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            if toggled {
+                Notification::Blocklist(BlocklistNotification::on(blocklist_notification.sound))
+            } else {
+                Notification::Blocklist(BlocklistNotification::off(blocklist_notification.sound))
+            },
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(10).push(checkbox);
+
+    if blocklist_notification.notify_on_match {
+        let sound_row = sound_buttons(
+            Notification::Blocklist(blocklist_notification),
+            font,
+            language,
+        );
+        ret_val = ret_val.push(sound_row);
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn get_port_scan_notify(
+    port_scan_notification: PortScanNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, StyleType> {
+    let checkbox = Checkbox::new(
+        port_scan_notification_translation(language),
+        port_scan_notification.notify_on_scan,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            if toggled {
+                Notification::PortScan(PortScanNotification::on(port_scan_notification.sound))
+            } else {
+                Notification::PortScan(PortScanNotification::off(port_scan_notification.sound))
+            },
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(10).push(checkbox);
+
+    if port_scan_notification.notify_on_scan {
+        let sound_row = sound_buttons(
+            Notification::PortScan(port_scan_notification),
+            font,
+            language,
+        );
+        ret_val = ret_val.push(sound_row);
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn get_beacon_notify(
+    beacon_notification: BeaconNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, StyleType> {
+    let checkbox = Checkbox::new(
+        beacon_notification_translation(language),
+        beacon_notification.notify_on_beacon,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            if toggled {
+                Notification::Beacon(BeaconNotification::on(beacon_notification.sound))
+            } else {
+                Notification::Beacon(BeaconNotification::off(beacon_notification.sound))
+            },
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(10).push(checkbox);
+
+    if beacon_notification.notify_on_beacon {
+        let sound_row = sound_buttons(Notification::Beacon(beacon_notification), font, language);
+        ret_val = ret_val.push(sound_row);
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn get_exfiltration_notify(
+    exfiltration_notification: ExfiltrationNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, StyleType> {
+    let mut ret_val = Column::new()
+        .spacing(10)
+        .push(Text::new(exfiltration_translation(language)).font(font));
+
+    for heuristic in ExfiltrationHeuristic::ALL {
+        let checkbox = Checkbox::new(
+            heuristic.get_label(language),
+            exfiltration_notification.is_enabled(heuristic),
+        )
+        .on_toggle(move |toggled| {
+            Message::UpdateNotificationSettings(
+                Notification::Exfiltration(exfiltration_notification.with(heuristic, toggled)),
+                false,
+            )
+        })
+        .size(18)
+        .font(font);
+        ret_val = ret_val.push(checkbox);
+    }
+
+    if exfiltration_notification.is_any_enabled() {
+        let sound_row = sound_buttons(
+            Notification::Exfiltration(exfiltration_notification),
+            font,
+            language,
+        );
+        ret_val = ret_val.push(sound_row);
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn get_rule_notify(
+    rule_notification: RuleNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, StyleType> {
+    let checkbox = Checkbox::new(
+        rule_notification_translation(language),
+        rule_notification.notify_on_match,
+    )
+    .on_toggle(move |toggled| {
+        Message::UpdateNotificationSettings(
+            if toggled {
+                Notification::Rule(RuleNotification::on(rule_notification.sound))
+            } else {
+                Notification::Rule(RuleNotification::off(rule_notification.sound))
+            },
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(10).push(checkbox);
+
+    if rule_notification.notify_on_match {
+        let sound_row = sound_buttons(Notification::Rule(rule_notification), font, language);
+        ret_val = ret_val.push(sound_row);
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(700)
+            .style(ContainerType::BorderedRound),
+    )
+}
+
 fn input_group_packets(
     packets_notification: PacketsNotification,
     font: Font,
@@ -390,6 +731,13 @@ fn sound_buttons(
         Notification::Packets(n) => n.sound,
         Notification::Bytes(n) => n.sound,
         Notification::Favorite(n) => n.sound,
+        Notification::Dhcp(n) => n.sound,
+        Notification::Blocklist(n) => n.sound,
+        Notification::PortScan(n) => n.sound,
+        Notification::Anomaly(n) => n.sound,
+        Notification::Beacon(n) => n.sound,
+        Notification::Exfiltration(n) => n.sound,
+        Notification::Rule(n) => n.sound,
     };
 
     let mut ret_val = Row::new()
@@ -408,6 +756,23 @@ fn sound_buttons(
             Notification::Favorite(n) => {
                 Notification::Favorite(FavoriteNotification { sound: option, ..n })
             }
+            Notification::Dhcp(n) => Notification::Dhcp(DhcpNotification { sound: option, ..n }),
+            Notification::Blocklist(n) => {
+                Notification::Blocklist(BlocklistNotification { sound: option, ..n })
+            }
+            Notification::PortScan(n) => {
+                Notification::PortScan(PortScanNotification { sound: option, ..n })
+            }
+            Notification::Anomaly(n) => {
+                Notification::Anomaly(AnomalyNotification { sound: option, ..n })
+            }
+            Notification::Beacon(n) => {
+                Notification::Beacon(BeaconNotification { sound: option, ..n })
+            }
+            Notification::Exfiltration(n) => {
+                Notification::Exfiltration(ExfiltrationNotification { sound: option, ..n })
+            }
+            Notification::Rule(n) => Notification::Rule(RuleNotification { sound: option, ..n }),
         };
         ret_val = ret_val.push(
             Button::new(option.get_text(font))
@@ -0,0 +1,41 @@
+use crate::gui::types::message::Message;
+use crate::translations::translations::overview_translation;
+use crate::translations::translations_4::packets_tab_translation;
+use crate::utils::types::icon::Icon;
+use crate::{Language, StyleType};
+
+/// This enum defines the current tab of the connection details modal.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Hash)]
+pub enum ConnectionDetailsTab {
+    /// Addresses, hosts, and statistics of the connection.
+    #[default]
+    Overview,
+    /// First packets of the connection, with their bytes.
+    Packets,
+}
+
+impl ConnectionDetailsTab {
+    pub const ALL: [ConnectionDetailsTab; 2] = [
+        ConnectionDetailsTab::Overview,
+        ConnectionDetailsTab::Packets,
+    ];
+
+    pub fn get_tab_label(&self, language: Language) -> &str {
+        match self {
+            ConnectionDetailsTab::Overview => overview_translation(language),
+            ConnectionDetailsTab::Packets => packets_tab_translation(language),
+        }
+    }
+
+    pub fn icon(self) -> iced::widget::Text<'static, StyleType> {
+        match self {
+            ConnectionDetailsTab::Overview => Icon::Overview,
+            ConnectionDetailsTab::Packets => Icon::PacketsThreshold,
+        }
+        .to_text()
+    }
+
+    pub fn action(self) -> Message {
+        Message::ChangeConnectionDetailsTab(self)
+    }
+}
@@ -1,2 +1,3 @@
+pub mod connection_details_tab;
 pub mod running_page;
 pub mod settings_page;
@@ -1,6 +1,7 @@
 use crate::gui::types::message::Message;
 use crate::translations::translations::{notifications_translation, overview_translation};
 use crate::translations::translations_2::inspect_translation;
+use crate::translations::translations_4::{beacons_translation, devices_translation};
 use crate::utils::types::icon::Icon;
 use crate::{Language, StyleType};
 
@@ -13,14 +14,23 @@ pub enum RunningPage {
     Overview,
     /// Inspect page.
     Inspect,
+    /// DNS page.
+    Dns,
+    /// Devices page.
+    Devices,
+    /// Beacons page.
+    Beacons,
     /// Notifications page.
     Notifications,
 }
 
 impl RunningPage {
-    pub const ALL: [RunningPage; 3] = [
+    pub const ALL: [RunningPage; 6] = [
         RunningPage::Overview,
         RunningPage::Inspect,
+        RunningPage::Dns,
+        RunningPage::Devices,
+        RunningPage::Beacons,
         RunningPage::Notifications,
     ];
 
@@ -28,6 +38,9 @@ pub fn get_tab_label(&self, language: Language) -> &str {
         match self {
             RunningPage::Overview => overview_translation(language),
             RunningPage::Inspect => inspect_translation(language),
+            RunningPage::Dns => "DNS",
+            RunningPage::Devices => devices_translation(language),
+            RunningPage::Beacons => beacons_translation(language),
             RunningPage::Notifications => notifications_translation(language),
             RunningPage::Init => "",
         }
@@ -36,7 +49,10 @@ pub fn get_tab_label(&self, language: Language) -> &str {
     pub fn next(self) -> Self {
         match self {
             RunningPage::Overview => RunningPage::Inspect,
-            RunningPage::Inspect => RunningPage::Notifications,
+            RunningPage::Inspect => RunningPage::Dns,
+            RunningPage::Dns => RunningPage::Devices,
+            RunningPage::Devices => RunningPage::Beacons,
+            RunningPage::Beacons => RunningPage::Notifications,
             RunningPage::Notifications => RunningPage::Overview,
             RunningPage::Init => RunningPage::Init,
         }
@@ -46,7 +62,10 @@ pub fn previous(self) -> Self {
         match self {
             RunningPage::Overview => RunningPage::Notifications,
             RunningPage::Inspect => RunningPage::Overview,
-            RunningPage::Notifications => RunningPage::Inspect,
+            RunningPage::Dns => RunningPage::Inspect,
+            RunningPage::Devices => RunningPage::Dns,
+            RunningPage::Beacons => RunningPage::Devices,
+            RunningPage::Notifications => RunningPage::Beacons,
             RunningPage::Init => RunningPage::Init,
         }
     }
@@ -55,6 +74,9 @@ pub fn icon(self) -> iced::widget::Text<'static, StyleType> {
         match self {
             RunningPage::Overview => Icon::Overview,
             RunningPage::Inspect => Icon::Inspect,
+            RunningPage::Dns => Icon::Globe,
+            RunningPage::Devices => Icon::Waves,
+            RunningPage::Beacons => Icon::Clock,
             RunningPage::Notifications => Icon::Notification,
             RunningPage::Init => Icon::Sniffnet,
         }
@@ -73,14 +95,20 @@ mod tests {
     #[test]
     fn test_previous_running_page() {
         assert_eq!(RunningPage::Overview.previous(), RunningPage::Notifications);
-        assert_eq!(RunningPage::Notifications.previous(), RunningPage::Inspect);
+        assert_eq!(RunningPage::Notifications.previous(), RunningPage::Beacons);
+        assert_eq!(RunningPage::Beacons.previous(), RunningPage::Devices);
+        assert_eq!(RunningPage::Devices.previous(), RunningPage::Dns);
+        assert_eq!(RunningPage::Dns.previous(), RunningPage::Inspect);
         assert_eq!(RunningPage::Inspect.previous(), RunningPage::Overview);
     }
 
     #[test]
     fn test_next_running_page() {
         assert_eq!(RunningPage::Overview.next(), RunningPage::Inspect);
-        assert_eq!(RunningPage::Inspect.next(), RunningPage::Notifications);
+        assert_eq!(RunningPage::Inspect.next(), RunningPage::Dns);
+        assert_eq!(RunningPage::Dns.next(), RunningPage::Devices);
+        assert_eq!(RunningPage::Devices.next(), RunningPage::Beacons);
+        assert_eq!(RunningPage::Beacons.next(), RunningPage::Notifications);
         assert_eq!(RunningPage::Notifications.next(), RunningPage::Overview);
     }
 }
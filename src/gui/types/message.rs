@@ -1,8 +1,12 @@
 use crate::gui::components::types::my_modal::MyModal;
+use crate::gui::pages::types::connection_details_tab::ConnectionDetailsTab;
 use crate::gui::pages::types::running_page::RunningPage;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::types::gradient_type::GradientType;
 use crate::networking::types::host::Host;
+use crate::networking::types::network_namespace::NetworkNamespace;
+use crate::networking::types::port_scan::ScanThresholds;
+use crate::networking::types::tunnel::TunnelAccounting;
 use crate::notifications::types::notifications::Notification;
 use crate::report::types::search_parameters::SearchParameters;
 use crate::report::types::sort_type::SortType;
@@ -19,6 +23,10 @@ pub enum Message {
     TickRun,
     /// Select adapter
     AdapterSelection(String),
+    /// Select the network namespace to capture in (None for the current one)
+    NetworkNamespaceSelection(Option<NetworkNamespace>),
+    /// Refresh the network namespaces that can be chosen to capture in
+    RefreshNetworkNamespaces,
     /// Select IP filter
     IpVersionSelection(IpVersion, bool),
     /// Select protocol filter
@@ -35,6 +43,14 @@ pub enum Message {
     HostSortSelection(SortType),
     /// Select service sort type to be displayed (overview page)
     ServiceSortSelection(SortType),
+    /// Select application sort type to be displayed (overview page)
+    AppSortSelection(SortType),
+    /// Whether to group the local traffic by container instead of by application (overview page)
+    GroupByContainer(bool),
+    /// Select DNS transactions sort type to be displayed (DNS page)
+    DnsSortSelection(SortType),
+    /// Select beaconing hosts sort type to be displayed (beacons page)
+    BeaconSortSelection(SortType),
     /// Adds or removes the given host into/from the favorites
     AddOrRemoveFavorite(Host, bool),
     /// Open the supplied web page
@@ -57,6 +73,10 @@ pub enum Message {
     OpenLastSettings,
     /// Hides the current modal
     HideModal,
+    /// Changes the tab of the connection details modal
+    ChangeConnectionDetailsTab(ConnectionDetailsTab),
+    /// Selects the packet to be dumped in the connection details modal
+    SelectPacket(usize),
     /// Hides the current setting page
     CloseSettings,
     /// Permits to change the current running page
@@ -69,6 +89,10 @@ pub enum Message {
     ClearAllNotifications,
     /// Set notifications volume
     ChangeVolume(u8),
+    /// Select which flows to account for when tunnelled traffic is sniffed
+    TunnelAccountingSelection(TunnelAccounting),
+    /// Set the thresholds used to detect port scans
+    ScanThresholds(ScanThresholds),
     /// Switch from a page to the next (previous) one if true (false), when the tab (shift+tab) key is pressed.
     SwitchPage(bool),
     /// The enter (return) key has been pressed
@@ -81,7 +105,7 @@ pub enum Message {
     CtrlDPressed,
     /// Update search parameters of inspect page
     Search(SearchParameters),
-    /// Update page result number in inspect
+    /// Update page result number in inspect or DNS page
     UpdatePageNumber(bool),
     /// Left (false) or Right (true) arrow key has been pressed
     ArrowPressed(bool),
@@ -99,6 +123,12 @@ pub enum Message {
     CustomCountryDb(String),
     /// The ASN MMDB custom path has been updated
     CustomAsnDb(String),
+    /// The blocklists directory has been updated
+    BlocklistsDirectory(String),
+    /// Set the minutes between two reloads of the blocklists (0 means never)
+    BlocklistsReload(u32),
+    /// The alert rules file has been updated
+    RulesFile(String),
     /// Save the configurations of the app and quit
     CloseRequested,
     /// Copies the given string to clipboard
@@ -3,6 +3,7 @@
 use std::collections::VecDeque;
 
 use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::notifications::types::traffic_baseline::TrafficBaseline;
 
 /// Struct containing useful data to display statistics about network traffic and the relative notifications
 pub struct RunTimeData {
@@ -32,6 +33,8 @@ pub struct RunTimeData {
     pub logged_notifications: VecDeque<LoggedNotification>,
     /// The total number of emitted notifications
     pub tot_emitted_notifications: usize,
+    /// Usual traffic rates, learned to detect the anomalous ones
+    pub traffic_baseline: TrafficBaseline,
 }
 
 impl RunTimeData {
@@ -51,6 +54,7 @@ pub fn new() -> Self {
             tot_in_packets_prev: 0,
             logged_notifications: VecDeque::default(),
             tot_emitted_notifications: 0,
+            traffic_baseline: TrafficBaseline::default(),
         }
     }
 }
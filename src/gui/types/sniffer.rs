@@ -20,6 +20,7 @@
 use crate::configs::types::config_window::{ConfigWindow, ScaleAndCheck, ToPoint, ToSize};
 use crate::gui::app::PERIOD_TICK;
 use crate::gui::components::types::my_modal::MyModal;
+use crate::gui::pages::types::connection_details_tab::ConnectionDetailsTab;
 use crate::gui::pages::types::running_page::RunningPage;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::types::custom_palette::{CustomPalette, ExtraStyles};
@@ -30,14 +31,17 @@
 use crate::mmdb::asn::ASN_MMDB;
 use crate::mmdb::country::COUNTRY_MMDB;
 use crate::mmdb::types::mmdb_reader::MmdbReader;
+use crate::networking::types::blocklist::Blocklists;
 use crate::networking::types::capture_context::CaptureContext;
 use crate::networking::types::filters::Filters;
 use crate::networking::types::host::Host;
 use crate::networking::types::ip_collection::AddressCollection;
 use crate::networking::types::my_device::MyDevice;
 use crate::networking::types::my_link_type::MyLinkType;
+use crate::networking::types::network_namespace::{list_devices, NetworkNamespace};
 use crate::networking::types::port_collection::PortCollection;
 use crate::notifications::notify_and_log::notify_and_log;
+use crate::notifications::types::alert_rules::{modified_time, AlertRules};
 use crate::notifications::types::notifications::Notification;
 use crate::notifications::types::sound::{play, Sound};
 use crate::report::get_report_entries::get_searched_entries;
@@ -66,6 +70,11 @@ pub struct Sniffer {
     pub runtime_data: RunTimeData,
     /// Network adapter to be analyzed
     pub device: MyDevice,
+    /// Network namespaces that can be chosen to capture in
+    pub network_namespaces: Vec<NetworkNamespace>,
+    /// Network adapters of the selected network namespace
+    /// (listing them requires a thread joining the namespace, so they're cached)
+    pub namespace_devices: Vec<Device>,
     /// Active filters on the observed traffic
     pub filters: Filters,
     /// Signals if a pcap error occurred
@@ -80,8 +89,20 @@ pub struct Sniffer {
     pub host_sort_type: SortType,
     /// Service sort type (overview page)
     pub service_sort_type: SortType,
+    /// Applications sort type (overview page)
+    pub app_sort_type: SortType,
+    /// Whether the local traffic is grouped by container instead of by application (overview page)
+    pub group_by_container: bool,
+    /// DNS transactions sort type (DNS page)
+    pub dns_sort_type: SortType,
+    /// Beaconing hosts sort type (beacons page)
+    pub beacon_sort_type: SortType,
     /// Currently displayed modal; None if no modal is displayed
     pub modal: Option<MyModal>,
+    /// Currently displayed tab of the connection details modal
+    pub connection_details_tab: ConnectionDetailsTab,
+    /// Index of the packet dumped in the connection details modal
+    pub selected_packet: usize,
     /// Currently displayed settings page; None if settings is closed
     pub settings_page: Option<SettingsPage>,
     /// Remembers the last opened setting page
@@ -94,10 +115,23 @@ pub struct Sniffer {
     pub search: SearchParameters,
     /// Current page number of inspect search results
     pub page_number: usize,
+    /// Current page number of DNS transactions
+    pub dns_page_number: usize,
+    /// Current page number of beaconing hosts
+    pub beacon_page_number: usize,
     /// MMDB reader for countries
     pub country_mmdb_reader: Arc<MmdbReader>,
     /// MMDB reader for ASN
     pub asn_mmdb_reader: Arc<MmdbReader>,
+    /// IP blocklists loaded from the local directory.
+    ///
+    /// When both are needed, `info_traffic` must be locked before the blocklists
+    /// (the capture and reverse DNS threads hold it while checking them)
+    pub blocklists: Arc<Mutex<Blocklists>>,
+    /// Custom alert rules loaded from the rules file.
+    ///
+    /// Same as the blocklists, they must be locked after `info_traffic`
+    pub alert_rules: Arc<Mutex<AlertRules>>,
     /// Time-related events
     pub timing_events: TimingEvents,
     /// Information about PCAP file export
@@ -119,13 +153,15 @@ pub fn new(
             ..
         } = configs.lock().unwrap().settings.clone();
         let device = configs.lock().unwrap().device.to_my_device();
-        Self {
+        let sniffer = Self {
             configs: configs.clone(),
             current_capture_id: Arc::new(Mutex::new(0)),
             info_traffic: Arc::new(Mutex::new(InfoTraffic::new())),
             newer_release_available,
             runtime_data: RunTimeData::new(),
             device,
+            network_namespaces: NetworkNamespace::list(),
+            namespace_devices: Vec::new(),
             filters: Filters::default(),
             pcap_error: None,
             waiting: ".".to_string(),
@@ -133,19 +169,32 @@ pub fn new(
             report_sort_type: ReportSortType::default(),
             host_sort_type: SortType::default(),
             service_sort_type: SortType::default(),
+            app_sort_type: SortType::default(),
+            group_by_container: false,
+            dns_sort_type: SortType::default(),
+            beacon_sort_type: SortType::default(),
             modal: None,
+            connection_details_tab: ConnectionDetailsTab::default(),
+            selected_packet: 0,
             settings_page: None,
             last_opened_setting: SettingsPage::Notifications,
             running_page: RunningPage::Init,
             unread_notifications: 0,
             search: SearchParameters::default(),
             page_number: 1,
+            dns_page_number: 1,
+            beacon_page_number: 1,
             country_mmdb_reader: Arc::new(MmdbReader::from(&mmdb_country, COUNTRY_MMDB)),
             asn_mmdb_reader: Arc::new(MmdbReader::from(&mmdb_asn, ASN_MMDB)),
+            blocklists: Arc::new(Mutex::new(Blocklists::default())),
+            alert_rules: Arc::new(Mutex::new(AlertRules::default())),
             timing_events: TimingEvents::default(),
             export_pcap: ExportPcap::default(),
             thumbnail: false,
-        }
+        };
+        sniffer.load_blocklists();
+        sniffer.load_rules();
+        sniffer
     }
 
     pub(crate) fn keyboard_subscription(&self) -> Subscription<Message> {
@@ -231,6 +280,8 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::TickRun => return self.refresh_data(),
             Message::AdapterSelection(name) => self.set_adapter(&name),
+            Message::NetworkNamespaceSelection(netns) => self.set_network_namespace(netns),
+            Message::RefreshNetworkNamespaces => self.network_namespaces = NetworkNamespace::list(),
             Message::IpVersionSelection(version, insert) => {
                 if insert {
                     self.filters.ip_versions.insert(version);
@@ -289,9 +340,13 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
             Message::ShowModal(modal) => {
                 if self.settings_page.is_none() && self.modal.is_none() {
                     self.modal = Some(modal);
+                    self.connection_details_tab = ConnectionDetailsTab::default();
+                    self.selected_packet = 0;
                 }
             }
             Message::HideModal => self.modal = None,
+            Message::ChangeConnectionDetailsTab(tab) => self.connection_details_tab = tab,
+            Message::SelectPacket(index) => self.selected_packet = index,
             Message::OpenSettings(settings_page) => {
                 if self.modal.is_none() {
                     self.settings_page = Some(settings_page);
@@ -316,6 +371,12 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
             Message::UpdateNotificationSettings(value, emit_sound) => {
                 self.update_notification_settings(value, emit_sound);
             }
+            Message::TunnelAccountingSelection(tunnel_accounting) => {
+                self.configs.lock().unwrap().settings.tunnel_accounting = tunnel_accounting;
+            }
+            Message::ScanThresholds(scan_thresholds) => {
+                self.configs.lock().unwrap().settings.scan_thresholds = scan_thresholds;
+            }
             Message::ChangeVolume(volume) => {
                 play(Sound::Pop, volume);
                 self.configs.lock().unwrap().settings.notifications.volume = volume;
@@ -341,24 +402,43 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
                 self.search = parameters;
             }
             Message::UpdatePageNumber(increment) => {
+                let page_number = match self.running_page {
+                    RunningPage::Dns => &mut self.dns_page_number,
+                    RunningPage::Beacons => &mut self.beacon_page_number,
+                    _ => &mut self.page_number,
+                };
                 let new_page = if increment {
-                    self.page_number.checked_add(1)
+                    page_number.checked_add(1)
                 } else {
-                    self.page_number.checked_sub(1)
+                    page_number.checked_sub(1)
                 }
                 .unwrap();
-                self.page_number = new_page;
+                *page_number = new_page;
             }
             Message::ArrowPressed(increment) => {
-                if self.running_page.eq(&RunningPage::Inspect)
-                    && self.settings_page.is_none()
-                    && self.modal.is_none()
-                {
+                if self.settings_page.is_none() && self.modal.is_none() {
+                    let (page_number, results_number) = match self.running_page {
+                        RunningPage::Inspect => (self.page_number, get_searched_entries(self).1),
+                        RunningPage::Dns => (
+                            self.dns_page_number,
+                            self.info_traffic
+                                .lock()
+                                .unwrap()
+                                .dns_analytics
+                                .transactions
+                                .len(),
+                        ),
+                        RunningPage::Beacons => (
+                            self.beacon_page_number,
+                            self.info_traffic.lock().unwrap().beacons.len(),
+                        ),
+                        _ => return Command::none(),
+                    };
                     if increment {
-                        if self.page_number < (get_searched_entries(self).1 + 20 - 1) / 20 {
+                        if page_number < (results_number + 20 - 1) / 20 {
                             return self.update(Message::UpdatePageNumber(increment));
                         }
-                    } else if self.page_number > 1 {
+                    } else if page_number > 1 {
                         return self.update(Message::UpdatePageNumber(increment));
                     }
                 }
@@ -408,6 +488,17 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
                     .clone_from(&db);
                 self.asn_mmdb_reader = Arc::new(MmdbReader::from(&db, ASN_MMDB));
             }
+            Message::BlocklistsDirectory(directory) => {
+                self.configs.lock().unwrap().settings.blocklists_dir = directory;
+                self.load_blocklists();
+            }
+            Message::BlocklistsReload(minutes) => {
+                self.configs.lock().unwrap().settings.blocklists_reload = minutes;
+            }
+            Message::RulesFile(path) => {
+                self.configs.lock().unwrap().settings.rules_file = path;
+                self.load_rules();
+            }
             Message::CloseRequested => {
                 self.configs.lock().unwrap().clone().store();
                 return window::close(Id::MAIN);
@@ -432,6 +523,20 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
             Message::ServiceSortSelection(sort_type) => {
                 self.service_sort_type = sort_type;
             }
+            Message::AppSortSelection(sort_type) => {
+                self.app_sort_type = sort_type;
+            }
+            Message::GroupByContainer(group_by_container) => {
+                self.group_by_container = group_by_container;
+            }
+            Message::DnsSortSelection(sort_type) => {
+                self.dns_page_number = 1;
+                self.dns_sort_type = sort_type;
+            }
+            Message::BeaconSortSelection(sort_type) => {
+                self.beacon_page_number = 1;
+                self.beacon_sort_type = sort_type;
+            }
             Message::ToggleExportPcap => {
                 self.export_pcap.toggle();
             }
@@ -521,7 +626,30 @@ fn refresh_data(&mut self) -> Command<Message> {
             self.configs.lock().unwrap().settings.notifications,
             &self.info_traffic.clone(),
         );
-        self.info_traffic.lock().unwrap().favorites_last_interval = HashSet::new();
+        let mut info_traffic_lock = self.info_traffic.lock().unwrap();
+        info_traffic_lock.favorites_last_interval = HashSet::new();
+        info_traffic_lock.dhcp_analytics.servers_last_interval = Vec::new();
+        info_traffic_lock.blocklist_matches_last_interval = Vec::new();
+        info_traffic_lock.port_scans_last_interval = Vec::new();
+        info_traffic_lock.beacons_last_interval = Vec::new();
+        info_traffic_lock.exfiltration_last_interval = Vec::new();
+        info_traffic_lock.rule_matches_last_interval = Vec::new();
+        drop(info_traffic_lock);
+        let blocklists_reload = self.configs.lock().unwrap().settings.blocklists_reload;
+        if blocklists_reload > 0
+            && self
+                .blocklists
+                .lock()
+                .unwrap()
+                .reload_due(Duration::from_secs(u64::from(blocklists_reload) * 60))
+        {
+            self.load_blocklists();
+        }
+        // reload the alert rules when their file is modified
+        let rules_file = self.configs.lock().unwrap().settings.rules_file.clone();
+        if self.alert_rules.lock().unwrap().reload_due(&rules_file) {
+            self.load_rules();
+        }
         self.runtime_data.tot_emitted_notifications += emitted_notifications;
         if self.thumbnail || self.running_page.ne(&RunningPage::Notifications) {
             self.unread_notifications += emitted_notifications;
@@ -543,6 +671,33 @@ fn refresh_data(&mut self) -> Command<Message> {
         Command::none()
     }
 
+    /// Reads the blocklists directory in a separate thread, replacing the loaded lists when done
+    fn load_blocklists(&self) {
+        let directory = self.configs.lock().unwrap().settings.blocklists_dir.clone();
+        let blocklists = self.blocklists.clone();
+        thread::Builder::new()
+            .name("thread_load_blocklists".to_string())
+            .spawn(move || {
+                let lists = Blocklists::read_directory(&directory);
+                blocklists.lock().unwrap().set(lists);
+            })
+            .unwrap();
+    }
+
+    /// Reads the alert rules file in a separate thread, replacing the loaded rules when done
+    fn load_rules(&self) {
+        let path = self.configs.lock().unwrap().settings.rules_file.clone();
+        let alert_rules = self.alert_rules.clone();
+        thread::Builder::new()
+            .name("thread_load_rules".to_string())
+            .spawn(move || {
+                let modified = modified_time(&path);
+                let rules = AlertRules::read_file(&path);
+                alert_rules.lock().unwrap().set(&path, modified, rules);
+            })
+            .unwrap();
+    }
+
     fn open_web(web_page: &WebPage) {
         let url = web_page.get_url();
         #[cfg(target_os = "windows")]
@@ -567,11 +722,19 @@ fn start(&mut self) {
         let capture_context = CaptureContext::new(&device, &pcap_path);
         self.pcap_error = capture_context.error().map(ToString::to_string);
         let info_traffic_mutex = self.info_traffic.clone();
-        *info_traffic_mutex.lock().unwrap() = InfoTraffic::new();
-        self.runtime_data = RunTimeData::new();
         let ConfigSettings {
-            style, language, ..
+            style,
+            language,
+            tunnel_accounting,
+            scan_thresholds,
+            notifications,
+            ..
         } = self.configs.lock().unwrap().settings;
+        *info_traffic_mutex.lock().unwrap() = InfoTraffic {
+            exfiltration_heuristics: notifications.exfiltration_notification.enabled_heuristics(),
+            ..InfoTraffic::new()
+        };
+        self.runtime_data = RunTimeData::new();
         self.traffic_chart = TrafficChart::new(style, language);
         self.running_page = RunningPage::Overview;
 
@@ -581,6 +744,8 @@ fn start(&mut self) {
             let filters = self.filters.clone();
             let country_mmdb_reader = self.country_mmdb_reader.clone();
             let asn_mmdb_reader = self.asn_mmdb_reader.clone();
+            let blocklists = self.blocklists.clone();
+            let alert_rules = self.alert_rules.clone();
             self.device.link_type = capture_context.my_link_type();
             thread::Builder::new()
                 .name("thread_parse_packets".to_string())
@@ -592,7 +757,11 @@ fn start(&mut self) {
                         &info_traffic_mutex,
                         &country_mmdb_reader,
                         &asn_mmdb_reader,
+                        &blocklists,
+                        &alert_rules,
                         capture_context,
+                        tunnel_accounting,
+                        scan_thresholds,
                     );
                 })
                 .unwrap();
@@ -607,11 +776,15 @@ fn reset(&mut self) -> Command<Message> {
         self.unread_notifications = 0;
         self.search = SearchParameters::default();
         self.page_number = 1;
+        self.dns_sort_type = SortType::default();
+        self.dns_page_number = 1;
+        self.beacon_sort_type = SortType::default();
+        self.beacon_page_number = 1;
         self.update(Message::HideModal)
     }
 
     fn set_adapter(&mut self, name: &str) {
-        for dev in Device::list().expect("Error retrieving device list\r\n") {
+        for dev in list_devices(self.device.netns.as_ref()) {
             if dev.name.eq(&name) {
                 let mut addresses_mutex = self.device.addresses.lock().unwrap();
                 *addresses_mutex = dev.addresses;
@@ -622,12 +795,32 @@ fn set_adapter(&mut self, name: &str) {
                     desc: dev.desc,
                     addresses: self.device.addresses.clone(),
                     link_type: MyLinkType::default(),
+                    netns: self.device.netns.clone(),
                 };
                 break;
             }
         }
     }
 
+    /// Selects the network namespace to capture in, keeping the current adapter if it's also there
+    fn set_network_namespace(&mut self, netns: Option<NetworkNamespace>) {
+        let devices = list_devices(netns.as_ref());
+        self.namespace_devices = if netns.is_some() {
+            devices.clone()
+        } else {
+            Vec::new()
+        };
+        self.device.netns = netns;
+        let name = devices
+            .iter()
+            .find(|dev| dev.name.eq(&self.device.name))
+            .or(devices.first())
+            .map(|dev| dev.name.clone());
+        if let Some(name) = name {
+            self.set_adapter(&name);
+        }
+    }
+
     fn update_waiting_dots(&mut self) {
         if self.waiting.len() > 2 {
             self.waiting = String::new();
@@ -684,6 +877,71 @@ fn update_notification_settings(&mut self, value: Notification, emit_sound: bool
                     .favorite_notification = favorite_notification;
                 favorite_notification.sound
             }
+            Notification::Dhcp(dhcp_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .dhcp_notification = dhcp_notification;
+                dhcp_notification.sound
+            }
+            Notification::Blocklist(blocklist_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .blocklist_notification = blocklist_notification;
+                blocklist_notification.sound
+            }
+            Notification::PortScan(port_scan_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .port_scan_notification = port_scan_notification;
+                port_scan_notification.sound
+            }
+            Notification::Anomaly(anomaly_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .anomaly_notification = anomaly_notification;
+                anomaly_notification.sound
+            }
+            Notification::Beacon(beacon_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .beacon_notification = beacon_notification;
+                beacon_notification.sound
+            }
+            Notification::Exfiltration(exfiltration_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .exfiltration_notification = exfiltration_notification;
+                self.info_traffic.lock().unwrap().exfiltration_heuristics =
+                    exfiltration_notification.enabled_heuristics();
+                exfiltration_notification.sound
+            }
+            Notification::Rule(rule_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .rule_notification = rule_notification;
+                rule_notification.sound
+            }
         };
         if emit_sound {
             play(
@@ -704,7 +962,12 @@ fn switch_page(&mut self, next: bool) {
                 }
             }
             (
-                RunningPage::Inspect | RunningPage::Notifications | RunningPage::Overview,
+                RunningPage::Inspect
+                | RunningPage::Dns
+                | RunningPage::Devices
+                | RunningPage::Beacons
+                | RunningPage::Notifications
+                | RunningPage::Overview,
                 None,
                 true,
             ) => {
@@ -815,11 +1078,15 @@ mod tests {
 
     use crate::countries::types::country::Country;
     use crate::gui::components::types::my_modal::MyModal;
+    use crate::gui::pages::types::connection_details_tab::ConnectionDetailsTab;
     use crate::gui::pages::types::settings_page::SettingsPage;
     use crate::gui::styles::types::custom_palette::ExtraStyles;
     use crate::gui::styles::types::gradient_type::GradientType;
     use crate::gui::types::message::Message;
+    use crate::networking::types::address_port_pair::AddressPortPair;
     use crate::networking::types::host::Host;
+    use crate::networking::types::port_scan::ScanThresholds;
+    use crate::networking::types::tunnel::TunnelAccounting;
     use crate::notifications::types::logged_notification::{
         LoggedNotification, PacketsThresholdExceeded,
     };
@@ -898,11 +1165,19 @@ fn test_correctly_update_protocol() {
         sniffer.update(Message::ProtocolSelection(Protocol::UDP, false));
         assert_eq!(
             sniffer.filters.protocols,
-            HashSet::from([Protocol::TCP, Protocol::ICMP])
+            HashSet::from([Protocol::TCP, Protocol::ICMP, Protocol::AnyOther])
         );
         sniffer.update(Message::ProtocolSelection(Protocol::TCP, false));
-        assert_eq!(sniffer.filters.protocols, HashSet::from([Protocol::ICMP]));
+        assert_eq!(
+            sniffer.filters.protocols,
+            HashSet::from([Protocol::ICMP, Protocol::AnyOther])
+        );
         sniffer.update(Message::ProtocolSelection(Protocol::ICMP, false));
+        assert_eq!(
+            sniffer.filters.protocols,
+            HashSet::from([Protocol::AnyOther])
+        );
+        sniffer.update(Message::ProtocolSelection(Protocol::AnyOther, false));
         assert_eq!(sniffer.filters.protocols, HashSet::new());
         sniffer.update(Message::ProtocolSelection(Protocol::UDP, true));
         assert_eq!(sniffer.filters.protocols, HashSet::from([Protocol::UDP]));
@@ -930,6 +1205,7 @@ fn test_correctly_update_report_sort_kind() {
         let sort = ReportSortType {
             byte_sort: SortType::Neutral,
             packet_sort: SortType::Neutral,
+            ..ReportSortType::default()
         };
 
         assert_eq!(sniffer.report_sort_type, sort);
@@ -940,7 +1216,8 @@ fn test_correctly_update_report_sort_kind() {
             sniffer.report_sort_type,
             ReportSortType {
                 byte_sort: SortType::Descending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
         sniffer.update(Message::ReportSortSelection(
@@ -951,7 +1228,8 @@ fn test_correctly_update_report_sort_kind() {
             sniffer.report_sort_type,
             ReportSortType {
                 byte_sort: SortType::Ascending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
         sniffer.update(Message::ReportSortSelection(
@@ -962,7 +1240,8 @@ fn test_correctly_update_report_sort_kind() {
             sniffer.report_sort_type,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                ..ReportSortType::default()
             }
         );
         sniffer.update(Message::ReportSortSelection(
@@ -974,7 +1253,8 @@ fn test_correctly_update_report_sort_kind() {
             sniffer.report_sort_type,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
     }
@@ -1001,6 +1281,44 @@ fn test_correctly_update_host_sort_kind() {
         assert_eq!(sniffer.host_sort_type, SortType::Neutral);
     }
 
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_correctly_update_dns_sort_kind_and_page_number() {
+        let mut sniffer = new_sniffer();
+
+        sniffer.running_page = RunningPage::Dns;
+        sniffer.update(Message::UpdatePageNumber(true));
+        assert_eq!(sniffer.dns_page_number, 2);
+        assert_eq!(sniffer.page_number, 1);
+
+        // changing the sort goes back to the first page
+        sniffer.update(Message::DnsSortSelection(SortType::Descending));
+        assert_eq!(sniffer.dns_sort_type, SortType::Descending);
+        assert_eq!(sniffer.dns_page_number, 1);
+
+        sniffer.running_page = RunningPage::Inspect;
+        sniffer.update(Message::UpdatePageNumber(true));
+        assert_eq!(sniffer.dns_page_number, 1);
+        assert_eq!(sniffer.page_number, 2);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_correctly_update_beacon_sort_kind_and_page_number() {
+        let mut sniffer = new_sniffer();
+
+        sniffer.running_page = RunningPage::Beacons;
+        sniffer.update(Message::UpdatePageNumber(true));
+        assert_eq!(sniffer.beacon_page_number, 2);
+        assert_eq!(sniffer.page_number, 1);
+        assert_eq!(sniffer.dns_page_number, 1);
+
+        // changing the sort goes back to the first page
+        sniffer.update(Message::BeaconSortSelection(SortType::Descending));
+        assert_eq!(sniffer.beacon_sort_type, SortType::Descending);
+        assert_eq!(sniffer.beacon_page_number, 1);
+    }
+
     #[test]
     #[parallel] // needed to not collide with other tests generating configs files
     fn test_correctly_update_service_sort_kind() {
@@ -1023,6 +1341,49 @@ fn test_correctly_update_service_sort_kind() {
         assert_eq!(sniffer.service_sort_type, SortType::Neutral);
     }
 
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_group_by_container_and_network_namespace() {
+        let mut sniffer = new_sniffer();
+        assert!(!sniffer.group_by_container);
+        sniffer.update(Message::GroupByContainer(true));
+        assert!(sniffer.group_by_container);
+        sniffer.update(Message::GroupByContainer(false));
+        assert!(!sniffer.group_by_container);
+
+        // going back to the current namespace keeps the selected adapter
+        let device_name = sniffer.device.name.clone();
+        sniffer.update(Message::NetworkNamespaceSelection(None));
+        assert_eq!(sniffer.device.netns, None);
+        assert_eq!(sniffer.device.name, device_name);
+        assert!(sniffer.namespace_devices.is_empty());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_correctly_update_app_sort_kind() {
+        let mut sniffer = new_sniffer();
+
+        let mut sort = SortType::Neutral;
+
+        assert_eq!(sniffer.app_sort_type, sort);
+
+        sort = sort.next_sort();
+        sniffer.update(Message::AppSortSelection(sort));
+        assert_eq!(sniffer.app_sort_type, SortType::Descending);
+
+        sort = sort.next_sort();
+        sniffer.update(Message::AppSortSelection(sort));
+        assert_eq!(sniffer.app_sort_type, SortType::Ascending);
+
+        sort = sort.next_sort();
+        sniffer.update(Message::AppSortSelection(sort));
+        assert_eq!(sniffer.app_sort_type, SortType::Neutral);
+        // the other sort types are not affected
+        assert_eq!(sniffer.host_sort_type, SortType::Neutral);
+        assert_eq!(sniffer.service_sort_type, SortType::Neutral);
+    }
+
     #[test]
     #[parallel] // needed to not collide with other tests generating configs files
     fn test_correctly_update_style() {
@@ -1261,6 +1622,45 @@ fn test_modify_favorite_connections() {
         );
     }
 
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_connection_details_tabs() {
+        let mut sniffer = new_sniffer();
+        let key = AddressPortPair::new(
+            "10.0.0.1".to_string(),
+            Some(50000),
+            "10.0.0.2".to_string(),
+            Some(80),
+            Protocol::TCP,
+        );
+
+        assert_eq!(
+            sniffer.connection_details_tab,
+            ConnectionDetailsTab::Overview
+        );
+        sniffer.update(Message::ShowModal(MyModal::ConnectionDetails(key.clone())));
+        sniffer.update(Message::ChangeConnectionDetailsTab(
+            ConnectionDetailsTab::Packets,
+        ));
+        sniffer.update(Message::SelectPacket(3));
+        assert_eq!(
+            sniffer.connection_details_tab,
+            ConnectionDetailsTab::Packets
+        );
+        assert_eq!(sniffer.selected_packet, 3);
+        sniffer.update(Message::HideModal);
+
+        // the tab and the selected packet are reset when the modal is reopened
+        sniffer.update(Message::ShowModal(MyModal::ConnectionDetails(
+            key.reversed(),
+        )));
+        assert_eq!(
+            sniffer.connection_details_tab,
+            ConnectionDetailsTab::Overview
+        );
+        assert_eq!(sniffer.selected_packet, 0);
+    }
+
     #[test]
     #[parallel] // needed to not collide with other tests generating configs files
     fn test_show_and_hide_modal_and_settings() {
@@ -1784,8 +2184,20 @@ fn test_config_settings() {
                     volume: 60,
                     packets_notification: Default::default(),
                     bytes_notification: Default::default(),
-                    favorite_notification: Default::default()
+                    favorite_notification: Default::default(),
+                    dhcp_notification: Default::default(),
+                    blocklist_notification: Default::default(),
+                    port_scan_notification: Default::default(),
+                    anomaly_notification: Default::default(),
+                    beacon_notification: Default::default(),
+                    exfiltration_notification: Default::default(),
+                    rule_notification: Default::default()
                 },
+                tunnel_accounting: TunnelAccounting::Outer,
+                blocklists_dir: "".to_string(),
+                blocklists_reload: 0,
+                rules_file: "".to_string(),
+                scan_thresholds: ScanThresholds::default(),
                 style: StyleType::Night
             }
         );
@@ -1802,6 +2214,15 @@ fn test_config_settings() {
         )));
         sniffer.update(Message::Style(StyleType::Custom(ExtraStyles::DraculaDark)));
         sniffer.update(Message::ChangeVolume(100));
+        sniffer.update(Message::TunnelAccountingSelection(TunnelAccounting::Inner));
+        sniffer.update(Message::BlocklistsDirectory("blocklists".to_string()));
+        sniffer.update(Message::BlocklistsReload(360));
+        sniffer.update(Message::RulesFile("rules.toml".to_string()));
+        sniffer.update(Message::ScanThresholds(ScanThresholds {
+            window: 300,
+            hosts: 50,
+            ports: 10,
+        }));
 
         // quit the app by sending a CloseRequested message
         sniffer.update(Message::CloseRequested);
@@ -1831,7 +2252,23 @@ fn test_config_settings() {
                     volume: 100,
                     packets_notification: Default::default(),
                     bytes_notification: Default::default(),
-                    favorite_notification: Default::default()
+                    favorite_notification: Default::default(),
+                    dhcp_notification: Default::default(),
+                    blocklist_notification: Default::default(),
+                    port_scan_notification: Default::default(),
+                    anomaly_notification: Default::default(),
+                    beacon_notification: Default::default(),
+                    exfiltration_notification: Default::default(),
+                    rule_notification: Default::default()
+                },
+                tunnel_accounting: TunnelAccounting::Inner,
+                blocklists_dir: "blocklists".to_string(),
+                blocklists_reload: 360,
+                rules_file: "rules.toml".to_string(),
+                scan_thresholds: ScanThresholds {
+                    window: 300,
+                    hosts: 50,
+                    ports: 10
                 },
                 style: StyleType::Custom(ExtraStyles::DraculaDark)
             }
@@ -35,6 +35,11 @@ pub fn was_just_copy_ip(&self, ip: &String) -> bool {
             && self.copy_ip.1.eq(ip)
     }
 
+    /// Returns true if any string was just copied (not necessarily an IP address)
+    pub fn was_just_copy(&self) -> bool {
+        self.copy_ip.0.elapsed() < Duration::from_millis(TimingEvents::TIMEOUT_COPY_IP)
+    }
+
     pub fn thumbnail_enter_now(&mut self) {
         self.thumbnail_enter = std::time::Instant::now();
     }
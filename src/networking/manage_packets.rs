@@ -1,42 +1,80 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 
 use chrono::Local;
 use dns_lookup::lookup_addr;
-use etherparse::{LaxPacketHeaders, LinkHeader, NetHeaders, TransportHeader};
-use pcap::{Address, Device};
+use etherparse::{
+    EtherType, IpNumber, LaxPacketHeaders, LaxPayloadSlice, LinkHeader, NetHeaders, TransportHeader,
+};
+use pcap::Address;
 
 use crate::mmdb::asn::get_asn;
 use crate::mmdb::country::get_country;
 use crate::mmdb::types::mmdb_reader::MmdbReader;
 use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::beaconing::{Beacon, BeaconDetector};
+use crate::networking::types::blocklist::{is_global, Blocklists};
+use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dhcp_message::{
+    DhcpMessage, DHCPV6_CLIENT_PORT, DHCPV6_SERVER_PORT, DHCP_CLIENT_PORT, DHCP_SERVER_PORT,
+};
+use crate::networking::types::dns_message::{DnsMessage, DNS_PORT, LLMNR_PORT, MDNS_PORT};
+use crate::networking::types::exfiltration::ExfiltrationDetector;
 use crate::networking::types::host::Host;
+use crate::networking::types::http_message::{HttpMessage, MAX_HTTP_REQUESTS};
 use crate::networking::types::icmp_type::{IcmpType, IcmpTypeV4, IcmpTypeV6};
-use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::networking::types::info_address_port_pair::{InfoAddressPortPair, InfoSnapshot};
+use crate::networking::types::local_names::NameProtocol;
 use crate::networking::types::my_device::MyDevice;
+use crate::networking::types::nbns_message::{NbnsMessage, NBNS_PORT};
+use crate::networking::types::os_fingerprint::TcpSignature;
 use crate::networking::types::packet_filters_fields::PacketFiltersFields;
+use crate::networking::types::payload_signature::{detect_service, is_tls_based, TLS_SERVICE};
+use crate::networking::types::port_scan::PortScanDetector;
+use crate::networking::types::process_info::{ProcessInfo, ProcessResolver};
 use crate::networking::types::service::Service;
 use crate::networking::types::service_query::ServiceQuery;
+use crate::networking::types::tcp_state::{TcpFlags, TcpFlagsSeen, TcpMetrics, TcpSegment};
+use crate::networking::types::tls_client_hello::TlsClientHello;
 use crate::networking::types::traffic_direction::TrafficDirection;
 use crate::networking::types::traffic_type::TrafficType;
+use crate::networking::types::tunnel::{Tunnel, TunnelAccounting, TunnelProtocol};
+use crate::notifications::types::alert_rules::{AlertRules, RulePacket};
 use crate::utils::formatted_strings::get_domain_from_r_dns;
 use crate::IpVersion::{IPv4, IPv6};
 use crate::{InfoTraffic, IpVersion, Protocol};
 
 include!(concat!(env!("OUT_DIR"), "/services.rs"));
 
+/// Maximum number of payloads inspected in each direction of a connection to identify its service
+const MAX_INSPECTED_PAYLOADS: u8 = 3;
+
 /// Calls methods to analyze link, network, and transport headers.
 /// Returns the relevant collected information.
-pub fn analyze_headers(
-    headers: LaxPacketHeaders,
+///
+/// If inner tunnel accounting is selected and the packet is tunnelled,
+/// the encapsulated headers are analyzed and the tunnel details are stored in `tunnel`.
+///
+/// If the packet is a TCP segment, its details are stored in `tcp_segment`.
+/// The TCP or UDP payload is stored in `app_payload`.
+#[allow(clippy::too_many_arguments)]
+pub fn analyze_headers<'a>(
+    headers: LaxPacketHeaders<'a>,
     mac_addresses: &mut (Option<String>, Option<String>),
     exchanged_bytes: &mut u128,
     icmp_type: &mut IcmpType,
     packet_filters_fields: &mut PacketFiltersFields,
+    tunnel_accounting: TunnelAccounting,
+    tunnel: &mut Option<Tunnel>,
+    tcp_segment: &mut Option<TcpSegment>,
+    app_payload: &mut &'a [u8],
 ) -> Option<AddressPortPair> {
+    let mut ip_payload_len = headers.net.as_ref().map_or(0, declared_ip_payload_len);
+    let mut ttl = headers.net.as_ref().map_or(0, ip_ttl);
+
     analyze_link_header(
         headers.link,
         &mut mac_addresses.0,
@@ -54,8 +92,54 @@ pub fn analyze_headers(
         return None;
     }
 
+    let mut transport_header = headers.transport;
+    let mut payload = headers.payload;
+
+    if tunnel_accounting.eq(&TunnelAccounting::Inner) {
+        if let Some((protocol, id, inner_headers)) =
+            decapsulate(transport_header.as_ref(), &payload)
+        {
+            *tunnel = Some(Tunnel {
+                protocol,
+                outer_source: packet_filters_fields.source,
+                outer_dest: packet_filters_fields.dest,
+                id,
+            });
+            // exchanged bytes still refer to the whole packet as seen on the wire
+            if inner_headers.link.is_some() {
+                analyze_link_header(
+                    inner_headers.link,
+                    &mut mac_addresses.0,
+                    &mut mac_addresses.1,
+                    &mut 0,
+                );
+            }
+            ip_payload_len = inner_headers
+                .net
+                .as_ref()
+                .map_or(0, declared_ip_payload_len);
+            ttl = inner_headers.net.as_ref().map_or(0, ip_ttl);
+            if !analyze_network_header(
+                inner_headers.net,
+                &mut 0,
+                &mut packet_filters_fields.ip_version,
+                &mut packet_filters_fields.source,
+                &mut packet_filters_fields.dest,
+            ) {
+                return None;
+            }
+            transport_header = inner_headers.transport;
+            payload = inner_headers.payload;
+        }
+    }
+
+    if let Some(TransportHeader::Tcp(tcp_header)) = &transport_header {
+        *tcp_segment = Some(TcpSegment::from_etherparse(tcp_header, ip_payload_len, ttl));
+    }
+
     if !analyze_transport_header(
-        headers.transport,
+        transport_header,
+        &payload,
         &mut packet_filters_fields.sport,
         &mut packet_filters_fields.dport,
         &mut packet_filters_fields.protocol,
@@ -64,6 +148,10 @@ pub fn analyze_headers(
         return None;
     }
 
+    if let LaxPayloadSlice::Tcp { payload, .. } | LaxPayloadSlice::Udp { payload, .. } = payload {
+        *app_payload = payload;
+    }
+
     Some(AddressPortPair::new(
         packet_filters_fields.source.to_string(),
         packet_filters_fields.sport,
@@ -73,6 +161,126 @@ pub fn analyze_headers(
     ))
 }
 
+/// Returns the tunneling protocol, the tunnel identifier, and the inner headers
+/// of a packet encapsulated with GRE, VXLAN, IP-in-IP, or Geneve.
+/// Returns None if the packet isn't tunnelled or if it doesn't carry an inner IP packet.
+/// Only the outermost tunnel is decapsulated.
+fn decapsulate<'a>(
+    transport_header: Option<&TransportHeader>,
+    payload: &LaxPayloadSlice<'a>,
+) -> Option<(TunnelProtocol, Option<u32>, LaxPacketHeaders<'a>)> {
+    const VXLAN_PORT: u16 = 4789;
+    const GENEVE_PORT: u16 = 6081;
+
+    let (protocol, id, inner_headers) = match (payload, transport_header) {
+        (LaxPayloadSlice::Ip(ip_payload), _) if !ip_payload.fragmented => {
+            match ip_payload.ip_number {
+                IpNumber::IPV4 | IpNumber::IPV6 => (
+                    TunnelProtocol::IpInIp,
+                    None,
+                    LaxPacketHeaders::from_ip(ip_payload.payload).ok()?,
+                ),
+                IpNumber::GRE => {
+                    let (key, inner_headers) = decapsulate_gre(ip_payload.payload)?;
+                    (TunnelProtocol::Gre, key, inner_headers)
+                }
+                _ => return None,
+            }
+        }
+        (LaxPayloadSlice::Udp { payload, .. }, Some(TransportHeader::Udp(udp_header))) => {
+            match udp_header.destination_port {
+                VXLAN_PORT => {
+                    let (vni, inner_headers) = decapsulate_vxlan(payload)?;
+                    (TunnelProtocol::Vxlan, vni, inner_headers)
+                }
+                GENEVE_PORT => {
+                    let (vni, inner_headers) = decapsulate_geneve(payload)?;
+                    (TunnelProtocol::Geneve, Some(vni), inner_headers)
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    inner_headers
+        .net
+        .is_some()
+        .then_some((protocol, id, inner_headers))
+}
+
+/// Decapsulates a GRE packet (based on RFC 2784 and RFC 2890), returning its key, if present.
+fn decapsulate_gre(gre: &[u8]) -> Option<(Option<u32>, LaxPacketHeaders<'_>)> {
+    let header = gre.get(..4)?;
+    let flags = u16::from_be_bytes([header[0], header[1]]);
+    // only version 0 is supported (version 1 is the enhanced GRE used by PPTP)
+    if flags & 0x0007 != 0 {
+        return None;
+    }
+    let protocol = EtherType(u16::from_be_bytes([header[2], header[3]]));
+
+    let mut offset = 4;
+    // checksum present
+    if flags & 0x8000 != 0 {
+        offset += 4;
+    }
+    // key present
+    let key = if flags & 0x2000 != 0 {
+        let key_bytes = gre.get(offset..offset + 4)?;
+        offset += 4;
+        Some(u32::from_be_bytes([
+            key_bytes[0],
+            key_bytes[1],
+            key_bytes[2],
+            key_bytes[3],
+        ]))
+    } else {
+        None
+    };
+    // sequence number present
+    if flags & 0x1000 != 0 {
+        offset += 4;
+    }
+
+    Some((key, inner_headers(protocol, gre.get(offset..)?)?))
+}
+
+/// Decapsulates a VXLAN packet (based on RFC 7348), returning its VNI, if valid.
+fn decapsulate_vxlan(vxlan: &[u8]) -> Option<(Option<u32>, LaxPacketHeaders<'_>)> {
+    let header = vxlan.get(..8)?;
+    // the I flag is set for a valid VNI
+    let vni =
+        (header[0] & 0x08 != 0).then(|| u32::from_be_bytes([0, header[4], header[5], header[6]]));
+    Some((vni, LaxPacketHeaders::from_ethernet(&vxlan[8..]).ok()?))
+}
+
+/// Decapsulates a Geneve packet (based on RFC 8926), returning its VNI.
+fn decapsulate_geneve(geneve: &[u8]) -> Option<(u32, LaxPacketHeaders<'_>)> {
+    let header = geneve.get(..8)?;
+    // only version 0 is defined
+    if header[0] >> 6 != 0 {
+        return None;
+    }
+    let options_len = usize::from(header[0] & 0x3F) * 4;
+    let protocol = EtherType(u16::from_be_bytes([header[2], header[3]]));
+    let vni = u32::from_be_bytes([0, header[4], header[5], header[6]]);
+    Some((
+        vni,
+        inner_headers(protocol, geneve.get(8 + options_len..)?)?,
+    ))
+}
+
+/// Parses the headers of a packet encapsulated in GRE or Geneve, given its protocol type.
+fn inner_headers(protocol: EtherType, payload: &[u8]) -> Option<LaxPacketHeaders<'_>> {
+    const TRANSPARENT_ETHERNET_BRIDGING: EtherType = EtherType(0x6558);
+
+    if protocol == TRANSPARENT_ETHERNET_BRIDGING {
+        LaxPacketHeaders::from_ethernet(payload).ok()
+    } else {
+        Some(LaxPacketHeaders::from_ether_type(protocol, payload))
+    }
+}
+
 /// This function analyzes the data link layer header passed as parameter and updates variables
 /// passed by reference on the basis of the packet header content.
 /// Returns false if packet has to be skipped.
@@ -121,11 +329,35 @@ fn analyze_network_header(
     }
 }
 
+/// Returns the length of the IP payload, as declared by the IP header
+fn declared_ip_payload_len(network_header: &NetHeaders) -> u32 {
+    let (payload_len, extensions_len) = match network_header {
+        NetHeaders::Ipv4(ipv4header, extensions) => (
+            u32::from(ipv4header.total_len).saturating_sub(u32::from(ipv4header.ihl()) * 4),
+            extensions.header_len(),
+        ),
+        NetHeaders::Ipv6(ipv6header, extensions) => (
+            u32::from(ipv6header.payload_length),
+            extensions.header_len(),
+        ),
+    };
+    payload_len.saturating_sub(u32::try_from(extensions_len).unwrap_or(u32::MAX))
+}
+
+/// Returns the TTL (IPv4) or the hop limit (IPv6) of the IP header
+fn ip_ttl(network_header: &NetHeaders) -> u8 {
+    match network_header {
+        NetHeaders::Ipv4(ipv4header, _) => ipv4header.time_to_live,
+        NetHeaders::Ipv6(ipv6header, _) => ipv6header.hop_limit,
+    }
+}
+
 /// This function analyzes the transport layer header passed as parameter and updates variables
 /// passed by reference on the basis of the packet header content.
 /// Returns false if packet has to be skipped.
 fn analyze_transport_header(
     transport_header: Option<TransportHeader>,
+    payload: &LaxPayloadSlice,
     port1: &mut Option<u16>,
     port2: &mut Option<u16>,
     protocol: &mut Protocol,
@@ -158,7 +390,24 @@ fn analyze_transport_header(
             *icmp_type = IcmpTypeV6::from_etherparse(&icmpv6_header.icmp_type);
             true
         }
-        _ => false,
+        None => match payload {
+            // TCP, UDP, and ICMP payloads without a transport header are truncated or fragmented
+            LaxPayloadSlice::Ip(ip_payload)
+                if ![
+                    IpNumber::TCP,
+                    IpNumber::UDP,
+                    IpNumber::ICMP,
+                    IpNumber::IPV6_ICMP,
+                ]
+                .contains(&ip_payload.ip_number) =>
+            {
+                *port1 = None;
+                *port2 = None;
+                *protocol = Protocol::Other(ip_payload.ip_number.0);
+                true
+            }
+            _ => false,
+        },
     }
 }
 
@@ -212,11 +461,12 @@ pub fn get_service(key: &AddressPortPair, traffic_direction: TrafficDirection) -
 pub fn modify_or_insert_in_map(
     info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
     key: &AddressPortPair,
-    my_device: &MyDevice,
+    my_interface_addresses: &[Address],
     mac_addresses: (Option<String>, Option<String>),
     icmp_type: IcmpType,
     exchanged_bytes: u128,
-) -> InfoAddressPortPair {
+    tunnel: Option<Tunnel>,
+) -> InfoSnapshot {
     let now = Local::now();
     let mut traffic_direction = TrafficDirection::default();
     let mut service = Service::Unknown;
@@ -224,17 +474,6 @@ pub fn modify_or_insert_in_map(
     if !info_traffic_mutex.lock().unwrap().map.contains_key(key) {
         // first occurrence of key
 
-        // update device addresses
-        let mut my_interface_addresses = Vec::new();
-        for dev in Device::list().expect("Error retrieving device list\r\n") {
-            if dev.name.eq(&my_device.name) {
-                let mut my_interface_addresses_mutex = my_device.addresses.lock().unwrap();
-                my_interface_addresses_mutex.clone_from(&dev.addresses);
-                drop(my_interface_addresses_mutex);
-                my_interface_addresses = dev.addresses;
-                break;
-            }
-        }
         // determine traffic direction
         let source_ip = &key.address1;
         let destination_ip = &key.address2;
@@ -243,7 +482,7 @@ pub fn modify_or_insert_in_map(
             destination_ip,
             key.port1,
             key.port2,
-            &my_interface_addresses,
+            my_interface_addresses,
         );
         // determine upper layer service
         service = get_service(key, traffic_direction);
@@ -253,7 +492,20 @@ pub fn modify_or_insert_in_map(
         .lock()
         .expect("Error acquiring mutex\n\r");
 
-    let new_info: InfoAddressPortPair = info_traffic
+    // the ClientHello may have been sent (and the service identified) before the first packet in this direction
+    let mut client_hello = None;
+    let mut service_detected = false;
+    if !info_traffic.map.contains_key(key) {
+        if let Some(reverse_info) = info_traffic.map.get(&key.reversed()) {
+            client_hello.clone_from(&reverse_info.client_hello);
+            if reverse_info.service_detected {
+                service = reverse_info.service;
+                service_detected = true;
+            }
+        }
+    }
+
+    let new_info = info_traffic
         .map
         .entry(key.clone())
         .and_modify(|info| {
@@ -275,14 +527,25 @@ pub fn modify_or_insert_in_map(
             initial_timestamp: now,
             final_timestamp: now,
             service,
+            service_detected,
+            inspected_payloads: 0,
             traffic_direction,
             icmp_types: if key.protocol.eq(&Protocol::ICMP) {
                 HashMap::from([(icmp_type, 1)])
             } else {
                 HashMap::new()
             },
+            tunnel,
+            tcp_flags: None,
+            tcp_metrics: None,
+            client_hello,
+            http_requests: VecDeque::new(),
+            source_os: None,
+            destination_os: None,
+            process: None,
+            blocklist: None,
         })
-        .clone();
+        .snapshot();
 
     if let Some(host_info) = info_traffic
         .addresses_resolved
@@ -297,6 +560,650 @@ pub fn modify_or_insert_in_map(
     new_info
 }
 
+/// Updates the TCP flags and metrics of the connection the given key belongs to,
+/// in the map entries of both its directions.
+///
+/// `timestamp` is the capture time of the segment, expressed in microseconds.
+pub fn update_tcp_connection(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    tcp_segment: TcpSegment,
+    timestamp: i64,
+) {
+    let reversed_key = key.reversed();
+    let mut received = TcpFlags::default();
+    if let Some(reverse_info) = info_traffic.map.get_mut(&reversed_key) {
+        if let Some(flags_seen) = reverse_info.tcp_flags.as_mut() {
+            flags_seen.received.merge(tcp_segment.flags);
+            received = flags_seen.sent;
+        }
+        if let (Some(metrics), Some(ack)) = (reverse_info.tcp_metrics.as_mut(), tcp_segment.ack) {
+            metrics.on_ack_received(ack, timestamp);
+        }
+    }
+
+    let mut handshake_rtt = None;
+    if let Some(info) = info_traffic.map.get_mut(key) {
+        let flags_seen = info.tcp_flags.get_or_insert_with(TcpFlagsSeen::default);
+        flags_seen.sent.merge(tcp_segment.flags);
+        flags_seen.received.merge(received);
+        let metrics = info.tcp_metrics.get_or_insert_with(TcpMetrics::default);
+        metrics.on_segment_sent(&tcp_segment, timestamp);
+        if tcp_segment.flags.ack {
+            handshake_rtt = metrics.on_handshake_ack(flags_seen, timestamp);
+        }
+    }
+
+    if let Some(handshake_rtt) = handshake_rtt {
+        if let Some(reverse_info) = info_traffic.map.get_mut(&reversed_key) {
+            reverse_info
+                .tcp_metrics
+                .get_or_insert_with(TcpMetrics::default)
+                .handshake_rtt = Some(handshake_rtt);
+        }
+    }
+}
+
+/// Parses the DNS (or mDNS) message carried by the given TCP or UDP payload, if any
+pub fn get_dns_message(key: &AddressPortPair, app_payload: &[u8]) -> Option<DnsMessage> {
+    let is_dns_port = |port: Option<u16>| matches!(port, Some(DNS_PORT | MDNS_PORT | LLMNR_PORT));
+    if !is_dns_port(key.port1) && !is_dns_port(key.port2) {
+        return None;
+    }
+    match key.protocol {
+        Protocol::UDP => DnsMessage::parse(app_payload),
+        Protocol::TCP => DnsMessage::parse_tcp(app_payload),
+        _ => None,
+    }
+}
+
+/// Remembers the domain names queried for the addresses resolved by a DNS message,
+/// and updates the DNS statistics (mDNS and LLMNR messages are excluded from the latter).
+///
+/// Names and services announced over mDNS and LLMNR are also attributed to the local devices.
+///
+/// `timestamp` is the capture time of the message, expressed in microseconds.
+pub fn update_dns_info(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    dns_message: &DnsMessage,
+    timestamp: i64,
+) {
+    let has_port = |port: u16| key.port1 == Some(port) || key.port2 == Some(port);
+    let local_protocol = if has_port(MDNS_PORT) {
+        Some(NameProtocol::Mdns)
+    } else if has_port(LLMNR_PORT) {
+        Some(NameProtocol::Llmnr)
+    } else {
+        None
+    };
+
+    for (address, name) in dns_message.resolved_addresses() {
+        let address = address.to_string();
+        if let Some(protocol) = local_protocol {
+            update_local_name(info_traffic, &address, &name, protocol);
+        }
+        if local_protocol != Some(NameProtocol::Llmnr) {
+            info_traffic.dns_names.insert(address, name);
+        }
+    }
+    if local_protocol == Some(NameProtocol::Mdns) {
+        info_traffic
+            .local_names
+            .add_services(&key.address1, dns_message.advertised_services());
+    }
+    if has_port(DNS_PORT) {
+        info_traffic
+            .dns_analytics
+            .update(key, dns_message, timestamp);
+    }
+}
+
+/// Returns the NetBIOS Name Service message carried by the given payload, if any
+pub fn get_nbns_message(key: &AddressPortPair, app_payload: &[u8]) -> Option<NbnsMessage> {
+    if key.protocol != Protocol::UDP
+        || (key.port1 != Some(NBNS_PORT) && key.port2 != Some(NBNS_PORT))
+    {
+        return None;
+    }
+    NbnsMessage::parse(app_payload)
+}
+
+/// Attributes the names bound by a NetBIOS Name Service message to the local devices
+pub fn update_nbns_info(info_traffic: &mut InfoTraffic, nbns_message: &NbnsMessage) {
+    for (address, name) in &nbns_message.names {
+        update_local_name(info_traffic, &address.to_string(), name, NameProtocol::Nbns);
+    }
+}
+
+/// Remembers the name announced by a local device, and labels its host with it
+fn update_local_name(
+    info_traffic: &mut InfoTraffic,
+    address: &str,
+    name: &str,
+    protocol: NameProtocol,
+) {
+    if info_traffic.local_names.add_name(address, name, protocol) {
+        name_unnamed_host(info_traffic, address, name);
+    }
+}
+
+/// Returns the DHCPv4 or DHCPv6 message exchanged between a client and a server, if any
+pub fn get_dhcp_message(key: &AddressPortPair, app_payload: &[u8]) -> Option<DhcpMessage> {
+    if key.protocol != Protocol::UDP {
+        return None;
+    }
+    match (key.port1?, key.port2?) {
+        (DHCP_CLIENT_PORT, DHCP_SERVER_PORT) | (DHCP_SERVER_PORT, DHCP_CLIENT_PORT) => {
+            DhcpMessage::parse(app_payload)
+        }
+        (DHCPV6_CLIENT_PORT, DHCPV6_SERVER_PORT) | (DHCPV6_SERVER_PORT, DHCPV6_CLIENT_PORT) => {
+            DhcpMessage::parse_v6(app_payload)
+        }
+        _ => None,
+    }
+}
+
+/// Updates the DHCP leases and servers with a message sent by the source of the given key,
+/// and labels the leased address with the host name declared by its client.
+pub fn update_dhcp_info(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    dhcp_message: &DhcpMessage,
+    source_mac: Option<String>,
+) {
+    let client = info_traffic
+        .dhcp_analytics
+        .update(key, dhcp_message, source_mac);
+    if let (Some(address), Some(hostname)) = (client.address.clone(), client.hostname.clone()) {
+        name_unnamed_host(info_traffic, &address, &hostname);
+        info_traffic.dhcp_names.insert(address, hostname);
+    }
+}
+
+/// Gives a name to the host of an address that was already resolved without one
+/// (i.e., identified by the address itself), moving the data it exchanged so far.
+fn name_unnamed_host(info_traffic: &mut InfoTraffic, address: &str, name: &str) {
+    let Some((_, host)) = info_traffic.addresses_resolved.get_mut(address) else {
+        return;
+    };
+    if host.domain != address {
+        return;
+    }
+    let old_host = host.clone();
+    host.domain = get_domain_from_r_dns(name.to_string());
+    let new_host = host.clone();
+
+    if let Some(data_info_host) = info_traffic.hosts.remove(&old_host) {
+        info_traffic
+            .hosts
+            .entry(new_host.clone())
+            .and_modify(|existing| existing.data_info += data_info_host.data_info)
+            .or_insert(data_info_host);
+    }
+    if info_traffic.favorite_hosts.remove(&old_host) {
+        info_traffic.favorite_hosts.insert(new_host);
+    }
+}
+
+/// Attaches a TLS ClientHello to the map entries of both directions of the connection it was sent over,
+/// and remembers the server name requested to the destination address.
+pub fn update_tls_info(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    client_hello: &TlsClientHello,
+) {
+    if let Some(server_name) = &client_hello.server_name {
+        info_traffic
+            .server_names
+            .insert(key.address2.clone(), server_name.clone());
+    }
+    for key in [key.clone(), key.reversed()] {
+        if let Some(info) = info_traffic.map.get_mut(&key) {
+            info.client_hello = Some(client_hello.clone());
+        }
+    }
+}
+
+/// Remembers the plain HTTP requests sent by the source of the given key,
+/// and completes them with the status code of the responses sent by its destination.
+pub fn update_http_info(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    http_message: &HttpMessage,
+) {
+    match http_message {
+        HttpMessage::Request(request) => {
+            if let Some(info) = info_traffic.map.get_mut(key) {
+                if info.http_requests.len() == MAX_HTTP_REQUESTS {
+                    info.http_requests.pop_front();
+                }
+                info.http_requests.push_back(request.clone());
+            }
+        }
+        // informational responses (1xx) precede the final one
+        HttpMessage::Response { status } if *status >= 200 => {
+            // responses are sent in the same order of the requests
+            if let Some(request) = info_traffic.map.get_mut(&key.reversed()).and_then(|info| {
+                info.http_requests
+                    .iter_mut()
+                    .find(|request| request.status.is_none())
+            }) {
+                request.status = Some(*status);
+            }
+        }
+        HttpMessage::Response { .. } => {}
+    }
+}
+
+/// Guesses the operating system of the source of the given key from a TCP handshake segment it sent,
+/// and attaches the guesses of both endpoints to the map entries of the connection.
+///
+/// The guess of an address is only replaced by one at least as confident.
+pub fn update_os_info(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    signature: &TcpSignature,
+) {
+    if let Some(os_guess) = signature.guess_os() {
+        let current = info_traffic.os_guesses.get(&key.address1);
+        if current.is_none_or(|current| os_guess.confidence >= current.confidence) {
+            info_traffic
+                .os_guesses
+                .insert(key.address1.clone(), os_guess);
+        }
+    }
+    let source_os = info_traffic.os_guesses.get(&key.address1).copied();
+    let destination_os = info_traffic.os_guesses.get(&key.address2).copied();
+    if let Some(info) = info_traffic.map.get_mut(key) {
+        info.source_os = source_os;
+        info.destination_os = destination_os;
+    }
+    if let Some(info) = info_traffic.map.get_mut(&key.reversed()) {
+        info.source_os = destination_os;
+        info.destination_os = source_os;
+    }
+}
+
+/// Returns the local process owning the socket of the given connection, if any
+pub fn get_process(
+    process_resolver: &ProcessResolver,
+    key: &AddressPortPair,
+    traffic_direction: TrafficDirection,
+) -> Option<ProcessInfo> {
+    if key.protocol != Protocol::TCP && key.protocol != Protocol::UDP {
+        return None;
+    }
+    let (local_address, local_port) = if traffic_direction == TrafficDirection::Outgoing {
+        (&key.address1, key.port1?)
+    } else {
+        (&key.address2, key.port2?)
+    };
+    process_resolver.lookup(key.protocol, local_address, local_port)
+}
+
+/// Attaches the local process owning its socket to the map entry of the given key
+///
+/// The data exchanged so far by the entry is added to the corresponding application and container.
+pub fn update_process_info(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    process: ProcessInfo,
+) {
+    let Some(info) = info_traffic.map.get_mut(key) else {
+        return;
+    };
+    let data = DataInfo::new_with_packets(
+        info.transmitted_packets,
+        info.transmitted_bytes,
+        info.traffic_direction,
+    );
+    *info_traffic.apps.entry(process.name.clone()).or_default() += data;
+    if let Some(container) = &process.container {
+        *info_traffic
+            .containers
+            .entry(container.name.clone())
+            .or_default() += data;
+    }
+    info.process = Some(process);
+}
+
+/// Returns the name of the blocklist containing the remote address of a connection,
+/// or one of the names known for it (server name, passive DNS name, or reverse DNS name).
+///
+/// Local, private, and multicast addresses are never looked up.
+fn get_blocklist_match(
+    info_traffic: &InfoTraffic,
+    address: &str,
+    info: &InfoAddressPortPair,
+    blocklists: &Blocklists,
+    my_interface_addresses: &Vec<Address>,
+) -> Option<String> {
+    if is_local_connection(address, my_interface_addresses)
+        || !IpAddr::from_str(address).is_ok_and(|ip| is_global(&ip))
+    {
+        return None;
+    }
+    blocklists
+        .matching(address)
+        .or_else(|| {
+            info.server_name()
+                .and_then(|name| blocklists.matching_domain(name))
+        })
+        .or_else(|| {
+            info_traffic
+                .dns_names
+                .get(address)
+                .and_then(|name| blocklists.matching_domain(name))
+        })
+        .or_else(|| {
+            info_traffic
+                .addresses_resolved
+                .get(address)
+                .and_then(|(r_dns, _)| blocklists.matching_domain(r_dns))
+        })
+        .map(ToString::to_string)
+}
+
+/// Matches the remote address of the given key (and the names known for it) against the blocklists,
+/// flagging its map entry.
+///
+/// Addresses matched for the first time (or by a different list) are reported in the last interval.
+pub fn update_blocklist_info(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    blocklists: &Blocklists,
+    my_interface_addresses: &Vec<Address>,
+) {
+    let Some(info) = info_traffic.map.get(key) else {
+        return;
+    };
+    let address = get_address_to_lookup(key, info.traffic_direction);
+    let blocklist = get_blocklist_match(
+        info_traffic,
+        &address,
+        info,
+        blocklists,
+        my_interface_addresses,
+    );
+    if let Some(list) = &blocklist {
+        if info_traffic.blocklisted_addresses.get(&address) != Some(list) {
+            info_traffic
+                .blocklist_matches_last_interval
+                .push((address.clone(), list.clone()));
+            info_traffic
+                .blocklisted_addresses
+                .insert(address, list.clone());
+        }
+    }
+    if let Some(info) = info_traffic.map.get_mut(key) {
+        info.blocklist = blocklist;
+    }
+}
+
+/// Matches again the connections with the given remote address against the blocklists,
+/// when a new name is known for it
+pub fn update_address_blocklist_info(
+    info_traffic: &mut InfoTraffic,
+    address: &str,
+    blocklists: &Blocklists,
+    my_interface_addresses: &Vec<Address>,
+) {
+    let keys: Vec<AddressPortPair> = info_traffic
+        .map
+        .iter()
+        .filter(|(key, info)| get_address_to_lookup(key, info.traffic_direction) == address)
+        .map(|(key, _)| key.clone())
+        .collect();
+    for key in keys {
+        update_blocklist_info(info_traffic, &key, blocklists, my_interface_addresses);
+    }
+}
+
+/// Matches all the map entries against the blocklists, after they've been (re)loaded
+pub fn update_blocklists_matches(
+    info_traffic: &mut InfoTraffic,
+    blocklists: &Blocklists,
+    my_interface_addresses: &Vec<Address>,
+) {
+    let previous_matches = std::mem::take(&mut info_traffic.blocklisted_addresses);
+    let matches: Vec<(AddressPortPair, String, Option<String>)> = info_traffic
+        .map
+        .iter()
+        .map(|(key, info)| {
+            let address = get_address_to_lookup(key, info.traffic_direction);
+            let blocklist = get_blocklist_match(
+                info_traffic,
+                &address,
+                info,
+                blocklists,
+                my_interface_addresses,
+            );
+            (key.clone(), address, blocklist)
+        })
+        .collect();
+    for (key, address, blocklist) in matches {
+        if let Some(list) = &blocklist {
+            if !info_traffic.blocklisted_addresses.contains_key(&address) {
+                if previous_matches.get(&address) != Some(list) {
+                    info_traffic
+                        .blocklist_matches_last_interval
+                        .push((address.clone(), list.clone()));
+                }
+                info_traffic
+                    .blocklisted_addresses
+                    .insert(address, list.clone());
+            }
+        }
+        if let Some(info) = info_traffic.map.get_mut(&key) {
+            info.blocklist = blocklist;
+        }
+    }
+}
+
+/// Feeds the port scan detector with a new connection, if it's a probe:
+/// TCP segments with the ACK flag set are answers, and can't start a connection.
+pub fn update_port_scans(
+    info_traffic: &mut InfoTraffic,
+    detector: &mut PortScanDetector,
+    key: &AddressPortPair,
+    traffic_direction: TrafficDirection,
+    tcp_segment: Option<TcpSegment>,
+    timestamp: i64,
+) {
+    // only the side opening the connection is counted: replies aren't probes
+    if tcp_segment.is_some_and(|segment| segment.ack.is_some())
+        || info_traffic.map.contains_key(&key.reversed())
+    {
+        return;
+    }
+    let scans = detector.new_connection(key, traffic_direction, timestamp);
+    info_traffic.port_scans_last_interval.extend(scans);
+}
+
+/// Checks a DNS message for signs of DNS tunnelling
+pub fn update_exfiltration_info(
+    info_traffic: &mut InfoTraffic,
+    detector: &mut ExfiltrationDetector,
+    key: &AddressPortPair,
+    dns_message: &DnsMessage,
+    timestamp: i64,
+) {
+    if key.port1 != Some(DNS_PORT) && key.port2 != Some(DNS_PORT) {
+        return;
+    }
+    let alerts = detector.dns_message(
+        key,
+        dns_message,
+        timestamp,
+        &info_traffic.exfiltration_heuristics,
+    );
+    info_traffic.exfiltration_last_interval.extend(alerts);
+}
+
+/// Checks the ratio between the bytes sent and received by an outgoing connection,
+/// where `key` is the direction of the last packet
+pub fn update_skewed_flow(
+    info_traffic: &mut InfoTraffic,
+    detector: &mut ExfiltrationDetector,
+    key: &AddressPortPair,
+    traffic_direction: TrafficDirection,
+    transmitted_bytes: u128,
+    timestamp: i64,
+) {
+    if traffic_direction != TrafficDirection::Outgoing {
+        return;
+    }
+    let received_bytes = || {
+        info_traffic
+            .map
+            .get(&key.reversed())
+            .map_or(0, |reverse_info| reverse_info.transmitted_bytes)
+    };
+    if let Some(alert) = detector.flow(
+        key,
+        transmitted_bytes,
+        received_bytes,
+        timestamp,
+        &info_traffic.exfiltration_heuristics,
+    ) {
+        info_traffic.exfiltration_last_interval.push(alert);
+    }
+}
+
+/// Evaluates the custom alert rules against a packet of the filtered traffic
+#[allow(clippy::too_many_arguments)]
+pub fn update_rule_matches(
+    info_traffic: &mut InfoTraffic,
+    alert_rules: &mut AlertRules,
+    key: &AddressPortPair,
+    packet_filters_fields: &PacketFiltersFields,
+    info: &InfoSnapshot,
+    exchanged_bytes: u128,
+    timestamp: i64,
+    country_db_reader: &MmdbReader,
+    asn_db_reader: &MmdbReader,
+) {
+    if alert_rules.is_empty() {
+        return;
+    }
+    let packet = RulePacket {
+        key,
+        fields: packet_filters_fields,
+        traffic_direction: info.traffic_direction,
+        service: info.service,
+        bytes: exchanged_bytes,
+        // the first packet of either direction
+        is_new_connection: info.transmitted_packets == 1
+            && !info_traffic.map.contains_key(&key.reversed()),
+        timestamp,
+    };
+    // the host may not be resolved yet: in this case use the names seen on the wire, if any
+    let address = get_address_to_lookup(key, info.traffic_direction);
+    let traffic = &*info_traffic;
+    let matches = alert_rules.packet(&packet, || {
+        let name = traffic
+            .server_names
+            .get(&address)
+            .or_else(|| traffic.dns_names.get(&address));
+        if let Some((r_dns, host)) = traffic.addresses_resolved.get(&address) {
+            return (name.unwrap_or(r_dns).clone(), host.clone());
+        }
+        let name = name.cloned().unwrap_or_default();
+        let host = Host {
+            domain: get_domain_from_r_dns(name.clone()),
+            asn: get_asn(&address, asn_db_reader),
+            country: get_country(&address, country_db_reader),
+        };
+        (name, host)
+    });
+    info_traffic.rule_matches_last_interval.extend(matches);
+}
+
+/// Feeds the beacon detector with a packet exchanged with the given remote address,
+/// updating the periodicity of the address when a new burst of traffic starts
+pub fn update_beacon_info(
+    info_traffic: &mut InfoTraffic,
+    detector: &mut BeaconDetector,
+    address: &str,
+    timestamp: i64,
+) {
+    let Some(beacon) = detector.packet(address, timestamp) else {
+        return;
+    };
+    let was_suspicious = info_traffic
+        .beacons
+        .get(address)
+        .is_some_and(Beacon::is_suspicious);
+    if beacon.is_suspicious() && !was_suspicious {
+        info_traffic
+            .beacons_last_interval
+            .push((address.to_string(), beacon));
+    }
+    info_traffic.beacons.insert(address.to_string(), beacon);
+}
+
+/// Looks for a known protocol signature in the first payloads sent by the source of the given key.
+/// If one is found, it's set as the service of the connection.
+pub fn detect_service_from_payload(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    app_payload: &[u8],
+) {
+    if app_payload.is_empty() {
+        return;
+    }
+    let Some(info) = info_traffic.map.get_mut(key) else {
+        return;
+    };
+    if info.service_detected || info.inspected_payloads >= MAX_INSPECTED_PAYLOADS {
+        return;
+    }
+    info.inspected_payloads += 1;
+
+    if let Some(mut service) = detect_service(key.protocol, app_payload) {
+        // TLS doesn't tell anything more than a port-based service running over it
+        if service == TLS_SERVICE && is_tls_based(info.service) {
+            service = info.service;
+        }
+        update_detected_service(info_traffic, key, service);
+    }
+}
+
+/// Sets the service identified from the payloads of the connection the given key belongs to,
+/// in the map entries of both its directions.
+///
+/// The data they exchanged so far is moved from the service guessed from the ports.
+pub fn update_detected_service(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    service: Service,
+) {
+    for key in [key.clone(), key.reversed()] {
+        let Some(info) = info_traffic.map.get_mut(&key) else {
+            continue;
+        };
+        info.service_detected = true;
+        let old_service = std::mem::replace(&mut info.service, service);
+        if old_service == service {
+            continue;
+        }
+        let data = DataInfo::new_with_packets(
+            info.transmitted_packets,
+            info.transmitted_bytes,
+            info.traffic_direction,
+        );
+        if let Some(old_data) = info_traffic.services.get_mut(&old_service) {
+            *old_data -= data;
+            if old_data.tot_packets() == 0 {
+                info_traffic.services.remove(&old_service);
+            }
+        }
+        *info_traffic.services.entry(service).or_default() += data;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn reverse_dns_lookup(
     info_traffic: &Arc<Mutex<InfoTraffic>>,
     key: &AddressPortPair,
@@ -304,6 +1211,8 @@ pub fn reverse_dns_lookup(
     my_device: &MyDevice,
     country_db_reader: &Arc<MmdbReader>,
     asn_db_reader: &Arc<MmdbReader>,
+    blocklists: &Arc<Mutex<Blocklists>>,
+    alert_rules: &Arc<Mutex<AlertRules>>,
 ) {
     let address_to_lookup = get_address_to_lookup(key, traffic_direction);
     let my_interface_addresses = my_device.addresses.lock().unwrap().clone();
@@ -330,22 +1239,54 @@ pub fn reverse_dns_lookup(
     } else {
         address_to_lookup.clone()
     };
+    let mut info_traffic_lock = info_traffic.lock().unwrap();
+    // prefer the server name requested to this address, the domain name that was queried for it,
+    // the host name declared by the DHCP client it was leased to, or the name announced by the device,
+    // if they were seen on the wire
+    let name = info_traffic_lock
+        .server_names
+        .get(&address_to_lookup)
+        .or_else(|| info_traffic_lock.dns_names.get(&address_to_lookup))
+        .or_else(|| info_traffic_lock.dhcp_names.get(&address_to_lookup))
+        .or_else(|| info_traffic_lock.local_names.name(&address_to_lookup))
+        .unwrap_or(&r_dns)
+        .clone();
+    let domain = get_domain_from_r_dns(name.clone());
     let new_host = Host {
-        domain: get_domain_from_r_dns(r_dns.clone()),
+        domain,
         asn,
         country,
     };
 
-    let mut info_traffic_lock = info_traffic.lock().unwrap();
     // collect the data exchanged from the same address so far and remove the address from the collection of addresses waiting a rDNS
     let other_data = info_traffic_lock
         .addresses_waiting_resolution
         .remove(&address_to_lookup)
         .unwrap_or_default();
+    // match the connections with this address again if the name resolved is in a domain blocklist
+    let blocklists = blocklists.lock().unwrap();
+    let r_dns_blocklisted = blocklists.matching_domain(&r_dns).is_some();
     // insert the newly resolved host in the collections, with the data it exchanged so far
     info_traffic_lock
         .addresses_resolved
-        .insert(address_to_lookup, (r_dns, new_host.clone()));
+        .insert(address_to_lookup.clone(), (r_dns, new_host.clone()));
+    if r_dns_blocklisted {
+        update_address_blocklist_info(
+            &mut info_traffic_lock,
+            &address_to_lookup,
+            &blocklists,
+            &my_interface_addresses,
+        );
+    }
+    drop(blocklists);
+    // evaluate the custom alert rules on the hosts seen for the first time
+    if !info_traffic_lock.hosts.contains_key(&new_host) {
+        let matches = alert_rules
+            .lock()
+            .unwrap()
+            .new_host(&address_to_lookup, &name, &new_host);
+        info_traffic_lock.rule_matches_last_interval.extend(matches);
+    }
     info_traffic_lock
         .hosts
         .entry(new_host.clone())
@@ -564,7 +1505,7 @@ pub fn is_my_address(local_address: &String, my_interface_addresses: &Vec<Addres
 }
 
 /// Converts a MAC address in its hexadecimal form
-fn mac_from_dec_to_hex(mac_dec: [u8; 6]) -> String {
+pub fn mac_from_dec_to_hex(mac_dec: [u8; 6]) -> String {
     let mut mac_hex = String::new();
     for n in &mac_dec {
         mac_hex.push_str(&format!("{n:02x}:"));
@@ -588,11 +1529,21 @@ mod tests {
     use pcap::Address;
 
     use crate::networking::manage_packets::{
-        get_service, get_traffic_direction, get_traffic_type, is_local_connection,
-        mac_from_dec_to_hex,
+        detect_service_from_payload, get_service, get_traffic_direction, get_traffic_type,
+        is_local_connection, mac_from_dec_to_hex, update_address_blocklist_info,
+        update_blocklist_info, update_blocklists_matches, update_port_scans, update_process_info,
     };
     use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::blocklist::{Blocklist, Blocklists};
+    use crate::networking::types::container_info::{ContainerInfo, ContainerRuntime};
+    use crate::networking::types::data_info::DataInfo;
+    use crate::networking::types::host::Host;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::networking::types::info_traffic::InfoTraffic;
+    use crate::networking::types::port_scan::{PortScanDetector, ScanThresholds};
+    use crate::networking::types::process_info::ProcessInfo;
     use crate::networking::types::service_query::ServiceQuery;
+    use crate::networking::types::tls_client_hello::TlsClientHello;
     use crate::networking::types::traffic_direction::TrafficDirection;
     use crate::networking::types::traffic_type::TrafficType;
     use crate::Protocol;
@@ -1231,7 +2182,7 @@ fn test_get_service_different_tcp_udp() {
                     Service::Name(match p {
                         Protocol::TCP => "mdns",
                         Protocol::UDP => "zeroconf",
-                        Protocol::ICMP => panic!(),
+                        Protocol::ICMP | Protocol::Other(_) | Protocol::AnyOther => panic!(),
                     })
                 );
 
@@ -1241,7 +2192,7 @@ fn test_get_service_different_tcp_udp() {
                     match p {
                         Protocol::TCP => Service::Name("netstat"),
                         Protocol::UDP => Service::Unknown,
-                        Protocol::ICMP => panic!(),
+                        Protocol::ICMP | Protocol::Other(_) | Protocol::AnyOther => panic!(),
                     }
                 );
 
@@ -1252,7 +2203,7 @@ fn test_get_service_different_tcp_udp() {
                     match p {
                         Protocol::TCP => Service::Unknown,
                         Protocol::UDP => Service::Name("murmur"),
-                        Protocol::ICMP => panic!(),
+                        Protocol::ICMP | Protocol::Other(_) | Protocol::AnyOther => panic!(),
                     }
                 );
 
@@ -1647,4 +2598,339 @@ fn test_other_service_names() {
             &Service::Name("murmur")
         );
     }
+
+    #[test]
+    fn test_detect_service_from_payload() {
+        let key = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(50000),
+            "10.0.0.1".to_string(),
+            Some(2222),
+            Protocol::TCP,
+        );
+        let guessed = Service::Name("EtherNetIP-1");
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.map.insert(
+            key.clone(),
+            InfoAddressPortPair {
+                transmitted_bytes: 120,
+                transmitted_packets: 2,
+                service: guessed,
+                traffic_direction: TrafficDirection::Outgoing,
+                ..Default::default()
+            },
+        );
+        info_traffic
+            .services
+            .insert(guessed, DataInfo::new_for_tests(0, 2, 0, 120));
+
+        // payloads not matching any signature leave the guessed service
+        detect_service_from_payload(&mut info_traffic, &key, b"");
+        detect_service_from_payload(&mut info_traffic, &key, b"\x00\x01\x02");
+        let info = info_traffic.map.get(&key).unwrap();
+        assert_eq!(info.service, guessed);
+        assert!(!info.service_detected);
+        assert_eq!(info.inspected_payloads, 1);
+
+        detect_service_from_payload(&mut info_traffic, &key, b"SSH-2.0-OpenSSH_9.6\r\n");
+        let info = info_traffic.map.get(&key).unwrap();
+        assert_eq!(info.service, Service::Name("ssh"));
+        assert!(info.service_detected);
+        // traffic exchanged so far is moved to the detected service
+        assert!(!info_traffic.services.contains_key(&guessed));
+        let ssh_data = info_traffic.services.get(&Service::Name("ssh")).unwrap();
+        assert_eq!(ssh_data.outgoing_packets(), 2);
+        assert_eq!(ssh_data.outgoing_bytes(), 120);
+
+        // once detected, the service doesn't change anymore
+        detect_service_from_payload(&mut info_traffic, &key, b"GET / HTTP/1.1\r\n");
+        assert_eq!(
+            info_traffic.map.get(&key).unwrap().service,
+            Service::Name("ssh")
+        );
+    }
+
+    #[test]
+    fn test_update_process_info() {
+        let key = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(50000),
+            "10.0.0.1".to_string(),
+            Some(443),
+            Protocol::TCP,
+        );
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.map.insert(
+            key.clone(),
+            InfoAddressPortPair {
+                transmitted_bytes: 300,
+                transmitted_packets: 3,
+                traffic_direction: TrafficDirection::Outgoing,
+                ..Default::default()
+            },
+        );
+        info_traffic
+            .apps
+            .insert("curl".to_string(), DataInfo::new_for_tests(4, 1, 800, 60));
+        let process = ProcessInfo {
+            pid: 1234,
+            name: "curl".to_string(),
+            executable: None,
+            netns: Some(4_026_532_561),
+            container: Some(ContainerInfo {
+                id: "3f4e5d6c7b8a99887766554433221100ffeeddccbbaa00112233445566778899".to_string(),
+                name: "web".to_string(),
+                runtime: ContainerRuntime::Docker,
+            }),
+        };
+
+        // unknown connections are ignored
+        update_process_info(&mut info_traffic, &key.reversed(), process.clone());
+        assert_eq!(info_traffic.apps.get("curl").unwrap().tot_packets(), 5);
+
+        update_process_info(&mut info_traffic, &key, process.clone());
+        assert_eq!(info_traffic.map.get(&key).unwrap().process, Some(process));
+        // traffic exchanged so far is added to the application
+        let curl_data = info_traffic.apps.get("curl").unwrap();
+        assert_eq!(curl_data.incoming_packets(), 4);
+        assert_eq!(curl_data.outgoing_packets(), 4);
+        assert_eq!(curl_data.outgoing_bytes(), 360);
+        // and to its container
+        let web_data = info_traffic.containers.get("web").unwrap();
+        assert_eq!(web_data.outgoing_packets(), 3);
+        assert_eq!(web_data.outgoing_bytes(), 300);
+    }
+
+    #[test]
+    fn test_update_blocklist_info() {
+        let outgoing = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(50000),
+            "185.220.101.7".to_string(),
+            Some(443),
+            Protocol::TCP,
+        );
+        let incoming = AddressPortPair::new(
+            "185.220.101.7".to_string(),
+            Some(40000),
+            "192.168.1.10".to_string(),
+            Some(22),
+            Protocol::TCP,
+        );
+        let clean = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(50001),
+            "9.9.9.9".to_string(),
+            Some(53),
+            Protocol::UDP,
+        );
+        let mut info_traffic = InfoTraffic::new();
+        for (key, traffic_direction) in [
+            (&outgoing, TrafficDirection::Outgoing),
+            (&incoming, TrafficDirection::Incoming),
+            (&clean, TrafficDirection::Outgoing),
+        ] {
+            info_traffic.map.insert(
+                key.clone(),
+                InfoAddressPortPair {
+                    traffic_direction,
+                    ..Default::default()
+                },
+            );
+        }
+        let mut blocklists = Blocklists::default();
+        blocklists.set(vec![Blocklist::parse("tor_exits", "185.220.101.0/24")]);
+
+        update_blocklist_info(&mut info_traffic, &outgoing, &blocklists, &vec![]);
+        update_blocklist_info(&mut info_traffic, &incoming, &blocklists, &vec![]);
+        update_blocklist_info(&mut info_traffic, &clean, &blocklists, &vec![]);
+        let blocklist = |key: &AddressPortPair, info_traffic: &InfoTraffic| {
+            info_traffic.map.get(key).unwrap().blocklist.clone()
+        };
+        assert_eq!(
+            blocklist(&outgoing, &info_traffic),
+            Some("tor_exits".to_string())
+        );
+        assert_eq!(
+            blocklist(&incoming, &info_traffic),
+            Some("tor_exits".to_string())
+        );
+        assert_eq!(blocklist(&clean, &info_traffic), None);
+        // the same remote address is reported only once
+        assert_eq!(
+            info_traffic.blocklist_matches_last_interval,
+            vec![("185.220.101.7".to_string(), "tor_exits".to_string())]
+        );
+
+        // after a reload, only the addresses matched by a different list are reported again
+        info_traffic.blocklist_matches_last_interval = Vec::new();
+        blocklists.set(vec![
+            Blocklist::parse("dns", "9.9.9.9"),
+            Blocklist::parse("tor_exits", "185.220.101.0/24"),
+        ]);
+        update_blocklists_matches(&mut info_traffic, &blocklists, &vec![]);
+        assert_eq!(blocklist(&clean, &info_traffic), Some("dns".to_string()));
+        assert_eq!(
+            info_traffic.blocklist_matches_last_interval,
+            vec![("9.9.9.9".to_string(), "dns".to_string())]
+        );
+
+        // addresses not listed anymore are unflagged
+        blocklists.set(Vec::new());
+        update_blocklists_matches(&mut info_traffic, &blocklists, &vec![]);
+        assert_eq!(blocklist(&outgoing, &info_traffic), None);
+        assert!(info_traffic.blocklisted_addresses.is_empty());
+    }
+
+    #[test]
+    fn test_update_blocklist_info_with_domains() {
+        let key = |address: &str, port: u16| {
+            AddressPortPair::new(
+                "192.168.1.10".to_string(),
+                Some(port),
+                address.to_string(),
+                Some(443),
+                Protocol::TCP,
+            )
+        };
+        let (sni_key, dns_key, r_dns_key) = (
+            key("203.0.113.1", 50000),
+            key("203.0.113.2", 50001),
+            key("203.0.113.3", 50002),
+        );
+        let mut info_traffic = InfoTraffic::new();
+        for key in [&sni_key, &dns_key, &r_dns_key] {
+            info_traffic.map.insert(
+                key.clone(),
+                InfoAddressPortPair {
+                    traffic_direction: TrafficDirection::Outgoing,
+                    ..Default::default()
+                },
+            );
+        }
+        let mut blocklists = Blocklists::default();
+        blocklists.set(vec![Blocklist::parse(
+            "hosts",
+            "0.0.0.0 evil.example\n0.0.0.0 tracker.example\n0.0.0.0 malware.example",
+        )]);
+
+        // server name of the connection
+        info_traffic.map.get_mut(&sni_key).unwrap().client_hello = Some(TlsClientHello {
+            server_name: Some("cdn.evil.example".to_string()),
+            alpn: Vec::new(),
+        });
+        update_blocklist_info(&mut info_traffic, &sni_key, &blocklists, &vec![]);
+        // domain name queried for the address
+        info_traffic
+            .dns_names
+            .insert("203.0.113.2".to_string(), "tracker.example".to_string());
+        update_blocklist_info(&mut info_traffic, &dns_key, &blocklists, &vec![]);
+        // reverse DNS, resolved after the first packet
+        update_blocklist_info(&mut info_traffic, &r_dns_key, &blocklists, &vec![]);
+        assert_eq!(info_traffic.map.get(&r_dns_key).unwrap().blocklist, None);
+        info_traffic.addresses_resolved.insert(
+            "203.0.113.3".to_string(),
+            ("a.b.malware.example".to_string(), Host::default()),
+        );
+        update_address_blocklist_info(&mut info_traffic, "203.0.113.3", &blocklists, &vec![]);
+
+        for key in [&sni_key, &dns_key, &r_dns_key] {
+            assert_eq!(
+                info_traffic.map.get(key).unwrap().blocklist,
+                Some("hosts".to_string())
+            );
+        }
+        assert_eq!(info_traffic.blocklist_matches_last_interval.len(), 3);
+    }
+
+    #[test]
+    fn test_update_blocklist_info_skips_local_addresses() {
+        let key = |address: &str, port: u16| {
+            AddressPortPair::new(
+                "203.0.113.10".to_string(),
+                Some(port),
+                address.to_string(),
+                Some(443),
+                Protocol::TCP,
+            )
+        };
+        // a peer in the same (public) subnet, a private address, and a multicast group
+        let (lan_key, private_key, multicast_key) = (
+            key("203.0.113.20", 50000),
+            key("10.1.1.1", 50001),
+            key("239.255.255.250", 50002),
+        );
+        let mut info_traffic = InfoTraffic::new();
+        for key in [&lan_key, &private_key, &multicast_key] {
+            info_traffic.map.insert(
+                key.clone(),
+                InfoAddressPortPair {
+                    traffic_direction: TrafficDirection::Outgoing,
+                    client_hello: Some(TlsClientHello {
+                        server_name: Some("evil.example".to_string()),
+                        alpn: Vec::new(),
+                    }),
+                    ..Default::default()
+                },
+            );
+        }
+        let my_interface_addresses = vec![Address {
+            addr: IpAddr::V4("203.0.113.10".parse().unwrap()),
+            netmask: Some(IpAddr::V4("255.255.255.0".parse().unwrap())),
+            broadcast_addr: Some(IpAddr::V4("203.0.113.255".parse().unwrap())),
+            dst_addr: None,
+        }];
+        let mut blocklists = Blocklists::default();
+        blocklists.set(vec![
+            Blocklist::parse("firehol_level1", "10.0.0.0/8\n203.0.113.0/24\n224.0.0.0/3"),
+            Blocklist::parse("domains", "evil.example"),
+        ]);
+
+        for key in [&lan_key, &private_key, &multicast_key] {
+            update_blocklist_info(&mut info_traffic, key, &blocklists, &my_interface_addresses);
+            assert_eq!(info_traffic.map.get(key).unwrap().blocklist, None);
+        }
+        update_blocklists_matches(&mut info_traffic, &blocklists, &my_interface_addresses);
+        assert!(info_traffic.blocklisted_addresses.is_empty());
+        assert!(info_traffic.blocklist_matches_last_interval.is_empty());
+
+        // the same peer is flagged when it isn't on the local network
+        update_blocklist_info(&mut info_traffic, &lan_key, &blocklists, &vec![]);
+        assert_eq!(
+            info_traffic.map.get(&lan_key).unwrap().blocklist,
+            Some("firehol_level1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_update_port_scans_skips_replies() {
+        let mut info_traffic = InfoTraffic::new();
+        let mut detector = PortScanDetector::new(ScanThresholds::default());
+        // a resolver answering many queries, each one from a different local port
+        for i in 0..50 {
+            let query = AddressPortPair::new(
+                "192.168.1.10".to_string(),
+                Some(50000 + i),
+                "192.168.1.1".to_string(),
+                Some(53),
+                Protocol::UDP,
+            );
+            let timestamp = i64::from(i) * 100_000;
+            for (key, traffic_direction) in [
+                (query.clone(), TrafficDirection::Outgoing),
+                (query.reversed(), TrafficDirection::Incoming),
+            ] {
+                info_traffic.map.entry(key.clone()).or_default();
+                update_port_scans(
+                    &mut info_traffic,
+                    &mut detector,
+                    &key,
+                    traffic_direction,
+                    None,
+                    timestamp,
+                );
+            }
+        }
+        assert!(info_traffic.port_scans_last_interval.is_empty());
+    }
 }
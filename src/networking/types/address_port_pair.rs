@@ -40,4 +40,15 @@ pub fn new(
             protocol,
         }
     }
+
+    /// Returns the `AddressPortPair` of the opposite direction.
+    pub fn reversed(&self) -> Self {
+        AddressPortPair {
+            address1: self.address2.clone(),
+            port1: self.port2,
+            address2: self.address1.clone(),
+            port2: self.port1,
+            protocol: self.protocol,
+        }
+    }
 }
@@ -0,0 +1,186 @@
+//! Module defining the `BeaconDetector` struct, which looks for remote hosts contacted
+//! at suspiciously regular intervals, as done by malware calling home.
+//!
+//! The traffic exchanged with each remote host is split in bursts, separated by idle gaps:
+//! a burst starts with a new connection or with a packet following a period of inactivity,
+//! and the intervals between the starts of consecutive bursts are analysed.
+
+use std::collections::{HashMap, VecDeque};
+
+/// Idle time (in microseconds) after which the traffic with a host starts a new burst
+const BURST_GAP: i64 = 1_000_000;
+
+/// Maximum number of bursts analysed for each host (the most recent ones)
+const MAX_BURSTS: usize = 64;
+
+/// Minimum number of intervals between bursts needed to estimate a period
+const MIN_INTERVALS: usize = 5;
+
+/// Score from which a host is considered to be beaconing
+pub const BEACON_SCORE_THRESHOLD: u8 = 75;
+
+/// Periodicity of the traffic with a remote host
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Beacon {
+    /// Median interval between the bursts, in microseconds
+    pub period: i64,
+    /// Median deviation of the intervals from the period, relative to the period
+    pub jitter: f64,
+    /// Likelihood of the traffic being periodic, from 0 to 100
+    pub score: u8,
+    /// Number of bursts analysed
+    pub bursts: usize,
+    /// Timestamp of the last burst, in microseconds
+    pub last_burst: i64,
+}
+
+impl Beacon {
+    /// Whether the traffic is regular enough to be reported
+    pub fn is_suspicious(&self) -> bool {
+        self.score >= BEACON_SCORE_THRESHOLD
+    }
+
+    /// Period expressed in seconds
+    pub fn period_secs(&self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let period = self.period as f64;
+        period / 1_000_000.0
+    }
+
+    /// Computes the periodicity of the given bursts starts, if there are enough of them
+    fn from_bursts(bursts: &VecDeque<i64>) -> Option<Self> {
+        let mut intervals: Vec<i64> = bursts
+            .iter()
+            .zip(bursts.iter().skip(1))
+            .map(|(prev, next)| next - prev)
+            .collect();
+        if intervals.len() < MIN_INTERVALS {
+            return None;
+        }
+        let period = median(&mut intervals);
+        let mut deviations: Vec<i64> = intervals.iter().map(|i| (i - period).abs()).collect();
+        #[allow(clippy::cast_precision_loss)]
+        let jitter = median(&mut deviations) as f64 / period as f64;
+
+        // regularity drops to 0 when the intervals deviate by 50% of the period,
+        // and the confidence grows with the number of intervals observed
+        #[allow(clippy::cast_precision_loss)]
+        let confidence = 1.0 - 1.0 / intervals.len() as f64;
+        let regularity = (1.0 - 2.0 * jitter).clamp(0.0, 1.0);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let score = (100.0 * regularity * confidence).round() as u8;
+
+        Some(Self {
+            period,
+            jitter,
+            score,
+            bursts: bursts.len(),
+            last_burst: bursts.back().copied().unwrap_or_default(),
+        })
+    }
+}
+
+/// Returns the median of the given values (the lower one, for an even number of values)
+fn median(values: &mut [i64]) -> i64 {
+    let middle = (values.len() - 1) / 2;
+    *values.select_nth_unstable(middle).1
+}
+
+/// Traffic bursts exchanged with a remote host
+#[derive(Default)]
+struct Bursts {
+    /// Starts of the most recent bursts
+    starts: VecDeque<i64>,
+    /// Timestamp of the last packet
+    last_packet: i64,
+}
+
+/// Detects the remote hosts contacted at regular intervals
+#[derive(Default)]
+pub struct BeaconDetector {
+    hosts: HashMap<String, Bursts>,
+}
+
+impl BeaconDetector {
+    /// Registers a packet exchanged with the given remote host at the given timestamp
+    /// (in microseconds), returning the updated periodicity of the host when a new burst starts
+    pub fn packet(&mut self, host: &str, timestamp: i64) -> Option<Beacon> {
+        let bursts = match self.hosts.get_mut(host) {
+            Some(bursts) => bursts,
+            None => self.hosts.entry(host.to_string()).or_default(),
+        };
+        let is_new_burst = bursts.starts.is_empty() || timestamp - bursts.last_packet >= BURST_GAP;
+        bursts.last_packet = bursts.last_packet.max(timestamp);
+        if !is_new_burst {
+            return None;
+        }
+        if bursts.starts.len() == MAX_BURSTS {
+            bursts.starts.pop_front();
+        }
+        bursts.starts.push_back(timestamp);
+        Beacon::from_bursts(&bursts.starts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::beaconing::{BeaconDetector, BEACON_SCORE_THRESHOLD};
+
+    const SECOND: i64 = 1_000_000;
+
+    #[test]
+    fn test_regular_bursts() {
+        let mut detector = BeaconDetector::default();
+        let mut beacon = None;
+        for i in 0..20 {
+            // a burst of a few packets every minute, with a jitter of up to a second
+            let start = i * 60 * SECOND + (i % 3 - 1) * SECOND;
+            for packet in 0..5 {
+                if let Some(b) = detector.packet("203.0.113.80", start + packet * 1000) {
+                    assert_eq!(packet, 0);
+                    beacon = Some(b);
+                }
+            }
+        }
+        let beacon = beacon.unwrap();
+        assert!((59 * SECOND..=61 * SECOND).contains(&beacon.period));
+        assert_eq!(beacon.bursts, 20);
+        assert!(beacon.jitter < 0.05);
+        assert!(beacon.score >= 85);
+        assert!(beacon.is_suspicious());
+    }
+
+    #[test]
+    fn test_irregular_bursts() {
+        let mut detector = BeaconDetector::default();
+        let mut beacon = None;
+        let mut start = 0;
+        for interval in [5, 120, 33, 7, 64, 300, 12, 45, 90, 3, 200, 18] {
+            start += interval * SECOND;
+            beacon = detector.packet("93.184.216.34", start).or(beacon);
+        }
+        let beacon = beacon.unwrap();
+        assert!(beacon.score < BEACON_SCORE_THRESHOLD);
+        assert!(!beacon.is_suspicious());
+    }
+
+    #[test]
+    fn test_continuous_traffic() {
+        let mut detector = BeaconDetector::default();
+        // a packet every 100 ms for ten minutes: a single burst
+        for i in 0..6000 {
+            assert!(detector.packet("198.51.100.99", i * SECOND / 10).is_none());
+        }
+    }
+
+    #[test]
+    fn test_too_few_bursts() {
+        let mut detector = BeaconDetector::default();
+        for i in 0..5 {
+            assert!(detector.packet("203.0.113.80", i * 30 * SECOND).is_none());
+        }
+        assert!(detector.packet("203.0.113.80", 150 * SECOND).is_some());
+        // hosts are tracked separately
+        assert!(detector.packet("203.0.113.81", 180 * SECOND).is_none());
+    }
+}
@@ -0,0 +1,528 @@
+//! Module defining the `Blocklists` struct, which holds the blocklists loaded from a local directory:
+//! IP lists (plain IP/CIDR lists, FireHOL netsets and ipsets, and Spamhaus DROP lists)
+//! and domain lists (hosts files and plain domain lists).
+
+use std::cmp::max;
+use std::collections::HashSet;
+use std::fs;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+/// Host names found in hosts files that don't identify a remote host
+const LOCAL_HOST_NAMES: [&str; 3] = [
+    "localhost.localdomain",
+    "ip6-localhost.localdomain",
+    "local",
+];
+
+/// Returns false for the addresses that can't belong to a remote host
+/// (private, loopback, link-local, multicast, broadcast, and unspecified addresses)
+pub fn is_global(address: &IpAddr) -> bool {
+    match address {
+        IpAddr::V4(ip) => {
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_multicast()
+                || ip.is_broadcast()
+                || ip.is_unspecified())
+        }
+        IpAddr::V6(ip) => {
+            !(ip.is_unique_local()
+                || ip.is_loopback()
+                || ip.is_unicast_link_local()
+                || ip.is_multicast()
+                || ip.is_unspecified())
+        }
+    }
+}
+
+/// A named list of IP ranges (sorted and without overlaps) and domains
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Blocklist {
+    /// Name of the list (the name of its file, without extension)
+    pub name: String,
+    /// IPv4 ranges, as inclusive bounds
+    ipv4_ranges: Vec<(u32, u32)>,
+    /// IPv6 ranges, as inclusive bounds
+    ipv6_ranges: Vec<(u128, u128)>,
+    /// Domains, also matching their subdomains
+    domains: HashSet<String>,
+}
+
+impl Blocklist {
+    /// Parses the content of a blocklist file.
+    ///
+    /// Each line can contain an IP address, a CIDR block, a range of addresses (`first-last`),
+    /// a domain, or a hosts file entry (an address followed by domains),
+    /// followed by a comment introduced by `#` or `;` (as in the Spamhaus DROP lists);
+    /// JSON lines with a `cidr` field (as in the Spamhaus DROP JSON lists) are supported too.
+    pub fn parse(name: &str, content: &str) -> Self {
+        let mut ipv4_ranges = Vec::new();
+        let mut ipv6_ranges = Vec::new();
+        let mut domains = HashSet::new();
+        for line in content.lines() {
+            let entry = if line.trim_start().starts_with('{') {
+                serde_json::from_str::<serde_json::Value>(line)
+                    .ok()
+                    .and_then(|json| Some(json.get("cidr")?.as_str()?.to_string()))
+            } else {
+                line.split(['#', ';'])
+                    .next()
+                    .map(|entry| entry.trim().to_string())
+            };
+            match entry.as_deref().and_then(parse_range) {
+                Some((IpAddr::V4(first), IpAddr::V4(last))) => {
+                    ipv4_ranges.push((u32::from(first), u32::from(last)));
+                }
+                Some((IpAddr::V6(first), IpAddr::V6(last))) => {
+                    ipv6_ranges.push((u128::from(first), u128::from(last)));
+                }
+                _ => domains.extend(entry.as_deref().map(parse_domains).unwrap_or_default()),
+            }
+        }
+        Self {
+            name: name.to_string(),
+            ipv4_ranges: merge_ranges(ipv4_ranges),
+            ipv6_ranges: merge_ranges(ipv6_ranges),
+            domains,
+        }
+    }
+
+    /// Returns true if the given address is in the list
+    pub fn contains(&self, address: &IpAddr) -> bool {
+        match address {
+            IpAddr::V4(address) => contains(&self.ipv4_ranges, u32::from(*address)),
+            IpAddr::V6(address) => contains(&self.ipv6_ranges, u128::from(*address)),
+        }
+    }
+
+    /// Returns true if the given domain is in the list (e.g., `evil.example`),
+    /// checking the domain itself first and then the domains it's a subdomain of
+    pub fn contains_domain(&self, domain: &str) -> bool {
+        let mut suffix = domain;
+        loop {
+            if self.domains.contains(suffix) {
+                return true;
+            }
+            match suffix.split_once('.') {
+                Some((_, parent)) => suffix = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Number of entries (ranges and domains) in the list
+    pub fn len(&self) -> usize {
+        self.ipv4_ranges.len() + self.ipv6_ranges.len() + self.domains.len()
+    }
+
+    /// Returns true if the list has no entries
+    pub fn is_empty(&self) -> bool {
+        self.ipv4_ranges.is_empty() && self.ipv6_ranges.is_empty() && self.domains.is_empty()
+    }
+}
+
+/// The blocklists currently loaded
+#[derive(Debug)]
+pub struct Blocklists {
+    /// Lists loaded, sorted by name
+    lists: Vec<Blocklist>,
+    /// Incremented at every (re)load, to let known connections be matched again
+    pub generation: usize,
+    /// Instant of the last (re)load
+    loaded_at: Instant,
+}
+
+impl Default for Blocklists {
+    fn default() -> Self {
+        Self {
+            lists: Vec::new(),
+            generation: 0,
+            loaded_at: Instant::now(),
+        }
+    }
+}
+
+impl Blocklists {
+    /// Reads all the files of the given directory as blocklists (this doesn't need any lock)
+    pub fn read_directory(directory: &str) -> Vec<Blocklist> {
+        if directory.is_empty() {
+            return Vec::new();
+        }
+        let Ok(entries) = fs::read_dir(directory) else {
+            return Vec::new();
+        };
+        let mut lists: Vec<Blocklist> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| {
+                let path = entry.path();
+                let name = path.file_stem()?.to_string_lossy().to_string();
+                // blocklists are plain text: skip hidden and unreadable files
+                if name.starts_with('.') {
+                    return None;
+                }
+                let content = fs::read_to_string(&path).ok()?;
+                Some(Blocklist::parse(&name, &content))
+            })
+            .filter(|list| !list.is_empty())
+            .collect();
+        lists.sort_by(|a, b| a.name.cmp(&b.name));
+        lists
+    }
+
+    /// Replaces the loaded lists
+    pub fn set(&mut self, lists: Vec<Blocklist>) {
+        self.lists = lists;
+        self.generation += 1;
+        self.loaded_at = Instant::now();
+    }
+
+    /// Returns true if the lists were loaded longer than the given interval ago,
+    /// and postpones the next check (the reload itself is performed in a separate thread)
+    pub fn reload_due(&mut self, interval: Duration) -> bool {
+        if self.loaded_at.elapsed() < interval {
+            return false;
+        }
+        self.loaded_at = Instant::now();
+        true
+    }
+
+    /// Returns the name of the first list containing the given address.
+    ///
+    /// Addresses that aren't global are never matched:
+    /// some lists (e.g. FireHOL level 1) include the reserved ranges, which aren't remote hosts.
+    pub fn matching(&self, address: &str) -> Option<&str> {
+        if self.lists.is_empty() {
+            return None;
+        }
+        let address: IpAddr = address.parse().ok()?;
+        if !is_global(&address) {
+            return None;
+        }
+        self.lists
+            .iter()
+            .find(|list| list.contains(&address))
+            .map(|list| list.name.as_str())
+    }
+
+    /// Returns the name of the first list containing the given domain or one of its parent domains
+    pub fn matching_domain(&self, domain: &str) -> Option<&str> {
+        if self.lists.is_empty() {
+            return None;
+        }
+        let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+        self.lists
+            .iter()
+            .find(|list| list.contains_domain(&domain))
+            .map(|list| list.name.as_str())
+    }
+
+    /// Lists loaded, sorted by name
+    pub fn lists(&self) -> &[Blocklist] {
+        &self.lists
+    }
+
+    /// Number of lists loaded, and total number of entries
+    pub fn summary(&self) -> (usize, usize) {
+        (
+            self.lists.len(),
+            self.lists.iter().map(Blocklist::len).sum(),
+        )
+    }
+}
+
+/// Parses an IP address, a CIDR block, or a range of addresses, returning its inclusive bounds
+pub(crate) fn parse_range(entry: &str) -> Option<(IpAddr, IpAddr)> {
+    if entry.is_empty() {
+        return None;
+    }
+    if let Some((first, last)) = entry.split_once('-') {
+        let first: IpAddr = first.trim().parse().ok()?;
+        let last: IpAddr = last.trim().parse().ok()?;
+        return (first.is_ipv4() == last.is_ipv4() && first <= last).then_some((first, last));
+    }
+    let Some((address, prefix)) = entry.split_once('/') else {
+        let address: IpAddr = entry.parse().ok()?;
+        return Some((address, address));
+    };
+    let address: IpAddr = address.parse().ok()?;
+    let prefix: u32 = prefix.parse().ok()?;
+    match address {
+        IpAddr::V4(address) => {
+            let host_bits = 32_u32.checked_sub(prefix)?;
+            let mask = u32::MAX.checked_shl(host_bits).unwrap_or(0);
+            let first = u32::from(address) & mask;
+            Some((
+                IpAddr::from(first.to_be_bytes()),
+                IpAddr::from((first | !mask).to_be_bytes()),
+            ))
+        }
+        IpAddr::V6(address) => {
+            let host_bits = 128_u32.checked_sub(prefix)?;
+            let mask = u128::MAX.checked_shl(host_bits).unwrap_or(0);
+            let first = u128::from(address) & mask;
+            Some((
+                IpAddr::from(first.to_be_bytes()),
+                IpAddr::from((first | !mask).to_be_bytes()),
+            ))
+        }
+    }
+}
+
+/// Parses a plain domain or a hosts file entry (e.g., `0.0.0.0 evil.example ads.example`),
+/// returning the remote domains found
+fn parse_domains(entry: &str) -> Vec<String> {
+    let mut tokens = entry.split_whitespace();
+    let Some(first) = tokens.next() else {
+        return Vec::new();
+    };
+    let names: Vec<&str> = if first.parse::<IpAddr>().is_ok() {
+        tokens.collect()
+    } else if tokens.next().is_none() {
+        vec![first]
+    } else {
+        return Vec::new();
+    };
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let name = name.trim_end_matches('.').to_ascii_lowercase();
+            let name = name.strip_prefix("*.").unwrap_or(&name);
+            let is_domain = name.contains('.')
+                && !name.starts_with('.')
+                && name.parse::<IpAddr>().is_err()
+                && name
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'.');
+            (is_domain && !LOCAL_HOST_NAMES.contains(&name)).then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Integer representation of IP addresses
+trait AddressBits: Ord + Copy {
+    fn successor(self) -> Option<Self>;
+}
+
+impl AddressBits for u32 {
+    fn successor(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+}
+
+impl AddressBits for u128 {
+    fn successor(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+}
+
+/// Sorts the ranges and merges the overlapping or adjacent ones
+fn merge_ranges<T: AddressBits>(mut ranges: Vec<(T, T)>) -> Vec<(T, T)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+    for (first, last) in ranges {
+        if let Some(previous) = merged.last_mut() {
+            if previous.1.successor().is_none_or(|next| first <= next) {
+                previous.1 = max(previous.1, last);
+                continue;
+            }
+        }
+        merged.push((first, last));
+    }
+    merged
+}
+
+/// Returns true if the value is in one of the sorted and disjoint ranges
+fn contains<T: AddressBits>(ranges: &[(T, T)], value: T) -> bool {
+    let index = ranges.partition_point(|&(first, _)| first <= value);
+    index > 0 && ranges[index - 1].1 >= value
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use crate::networking::types::blocklist::{parse_domains, parse_range, Blocklist, Blocklists};
+
+    const FIREHOL: &str = "#
+# firehol_level1
+#
+# ipv4 hash:net ipset
+#
+0.0.0.0/8
+10.0.0.0/8
+5.188.10.0/23
+5.188.11.0/24
+185.220.101.7
+";
+
+    const SPAMHAUS_DROP: &str =
+        "; Spamhaus DROP List 2024/09/10 - (c) 2024 The Spamhaus Project SLRL
+; Last-Modified: Tue, 10 Sep 2024 12:05:16 GMT
+1.10.16.0/20 ; SBL256894
+2a06:e480::/29 ; SBL301771
+";
+
+    const SPAMHAUS_DROP_JSON: &str = r#"{"cidr":"1.10.16.0/20","sblid":"SBL256894","rir":"apnic"}
+{"cidr":"2.57.122.0/24","sblid":"SBL636050","rir":"ripencc"}
+{"type":"metadata","timestamp":1725969916,"size":98,"records":2,"copyright":"(c) 2024 The Spamhaus Project SLRL"}
+"#;
+
+    const HOSTS: &str = "# Title: StevenBlack/hosts
+127.0.0.1 localhost
+127.0.0.1 localhost.localdomain
+255.255.255.255 broadcasthost
+::1 localhost ip6-localhost ip6-loopback
+0.0.0.0 0.0.0.0
+0.0.0.0 ads.tracker.example # tracking
+0.0.0.0 evil.example  Malware.Example.
+";
+
+    const DOMAINS: &str = "# domain list
+evil.example
+*.ads.example
+not a domain
+";
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(
+            parse_range("192.168.1.77/24"),
+            Some((ip("192.168.1.0"), ip("192.168.1.255")))
+        );
+        assert_eq!(
+            parse_range("0.0.0.0/0"),
+            Some((ip("0.0.0.0"), ip("255.255.255.255")))
+        );
+        assert_eq!(
+            parse_range("2001:db8::/32"),
+            Some((
+                ip("2001:db8::"),
+                ip("2001:db8:ffff:ffff:ffff:ffff:ffff:ffff")
+            ))
+        );
+        assert_eq!(
+            parse_range("10.0.0.5 - 10.0.0.9"),
+            Some((ip("10.0.0.5"), ip("10.0.0.9")))
+        );
+        assert_eq!(parse_range("8.8.8.8"), Some((ip("8.8.8.8"), ip("8.8.8.8"))));
+        assert_eq!(parse_range("10.0.0.9-10.0.0.5"), None);
+        assert_eq!(parse_range("10.0.0.0/33"), None);
+        assert_eq!(parse_range("example.com"), None);
+        assert_eq!(parse_range(""), None);
+    }
+
+    #[test]
+    fn test_parse_blocklists() {
+        let firehol = Blocklist::parse("firehol_level1", FIREHOL);
+        // the adjacent /23 and /24 are merged with each other
+        assert_eq!(firehol.len(), 4);
+        assert!(firehol.contains(&ip("10.20.30.40")));
+        assert!(firehol.contains(&ip("5.188.11.255")));
+        assert!(firehol.contains(&ip("185.220.101.7")));
+        assert!(!firehol.contains(&ip("185.220.101.8")));
+        assert!(!firehol.contains(&ip("11.0.0.0")));
+        assert!(!firehol.contains(&ip("::a00:1")));
+
+        let drop = Blocklist::parse("drop", SPAMHAUS_DROP);
+        assert_eq!(drop.len(), 2);
+        assert!(drop.contains(&ip("1.10.31.255")));
+        assert!(!drop.contains(&ip("1.10.32.0")));
+        assert!(drop.contains(&ip("2a06:e487::1")));
+
+        let drop_json = Blocklist::parse("drop_v4", SPAMHAUS_DROP_JSON);
+        assert_eq!(drop_json.len(), 2);
+        assert!(drop_json.contains(&ip("2.57.122.9")));
+    }
+
+    #[test]
+    fn test_parse_domains() {
+        assert_eq!(parse_domains("evil.example"), vec!["evil.example"]);
+        assert_eq!(parse_domains("*.Ads.Example."), vec!["ads.example"]);
+        assert_eq!(
+            parse_domains("0.0.0.0 evil.example my-ads.example"),
+            vec!["evil.example", "my-ads.example"]
+        );
+        assert!(parse_domains("127.0.0.1 localhost").is_empty());
+        assert!(parse_domains("0.0.0.0 0.0.0.0").is_empty());
+        assert!(parse_domains("localhost").is_empty());
+        assert!(parse_domains("evil.example ads.example").is_empty());
+        assert!(parse_domains("ev!l.example").is_empty());
+        assert!(parse_domains("").is_empty());
+
+        let hosts = Blocklist::parse("hosts", HOSTS);
+        assert_eq!(hosts.len(), 3);
+        // IP addresses of hosts files are not blocked
+        assert!(!hosts.contains(&ip("0.0.0.0")));
+        assert!(!hosts.contains(&ip("127.0.0.1")));
+        assert!(hosts.contains_domain("malware.example"));
+        assert!(hosts.contains_domain("ads.tracker.example"));
+        assert!(!hosts.contains_domain("tracker.example"));
+
+        let domains = Blocklist::parse("domains", DOMAINS);
+        assert_eq!(domains.len(), 2);
+        // subdomains are matched too
+        assert!(domains.contains_domain("evil.example"));
+        assert!(domains.contains_domain("cdn.evil.example"));
+        assert!(domains.contains_domain("a.b.ads.example"));
+        assert!(!domains.contains_domain("notevil.example"));
+        assert!(!domains.contains_domain("example"));
+    }
+
+    #[test]
+    fn test_blocklists_matching() {
+        let mut blocklists = Blocklists::default();
+        assert_eq!(blocklists.matching("1.10.16.1"), None);
+
+        blocklists.set(vec![
+            Blocklist::parse("drop", SPAMHAUS_DROP),
+            Blocklist::parse("firehol_level1", FIREHOL),
+        ]);
+        assert_eq!(blocklists.generation, 1);
+        assert_eq!(blocklists.summary(), (2, 6));
+        assert_eq!(blocklists.matching("1.10.16.1"), Some("drop"));
+        assert_eq!(blocklists.matching("185.220.101.7"), Some("firehol_level1"));
+        // the reserved ranges included in the lists are never matched
+        assert_eq!(blocklists.matching("10.1.1.1"), None);
+        assert_eq!(blocklists.matching("fd00::1"), None);
+        assert_eq!(blocklists.matching("9.9.9.9"), None);
+        assert_eq!(blocklists.matching("not an address"), None);
+        assert_eq!(blocklists.matching_domain("evil.example"), None);
+
+        blocklists.set(vec![
+            Blocklist::parse("domains", DOMAINS),
+            Blocklist::parse("hosts", HOSTS),
+        ]);
+        assert_eq!(blocklists.generation, 2);
+        assert_eq!(blocklists.summary(), (2, 5));
+        assert_eq!(
+            blocklists.matching_domain("CDN.Evil.Example."),
+            Some("domains")
+        );
+        assert_eq!(blocklists.matching_domain("malware.example"), Some("hosts"));
+        assert_eq!(blocklists.matching_domain("example"), None);
+        assert_eq!(blocklists.matching("0.0.0.0"), None);
+    }
+
+    #[test]
+    fn test_read_directory() {
+        let directory =
+            std::env::temp_dir().join(format!("sniffnet_blocklists_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("firehol_level1.netset"), FIREHOL).unwrap();
+        std::fs::write(directory.join("drop.txt"), SPAMHAUS_DROP).unwrap();
+        std::fs::write(directory.join("empty.txt"), "# nothing here\n").unwrap();
+
+        let lists = Blocklists::read_directory(&directory.to_string_lossy());
+        std::fs::remove_dir_all(&directory).unwrap();
+        let names: Vec<&str> = lists.iter().map(|list| list.name.as_str()).collect();
+        assert_eq!(names, vec!["drop", "firehol_level1"]);
+        assert!(Blocklists::read_directory("").is_empty());
+    }
+}
@@ -11,16 +11,29 @@ pub enum CaptureContext {
 
 impl CaptureContext {
     pub fn new(device: &MyDevice, pcap_path: &Option<String>) -> Self {
-        let cap_res = Capture::from_device(device.to_pcap_device())
-            .expect("Capture initialization error\n\r")
-            .promisc(true)
-            .snaplen(if pcap_path.is_some() {
-                i32::from(u16::MAX)
-            } else {
-                256 //limit stored packets slice dimension (to keep more in the buffer)
-            })
-            .immediate_mode(true) //parse packets ASAP!
-            .open();
+        let open = || {
+            Capture::from_device(device.to_pcap_device())
+                .expect("Capture initialization error\n\r")
+                .promisc(true)
+                .snaplen(if pcap_path.is_some() {
+                    i32::from(u16::MAX)
+                } else {
+                    // limit stored packets slice dimension (to keep more in the buffer):
+                    // 2048 bytes hold whole frames up to the usual 1500 bytes MTU, enough for
+                    // the payload parsers (which skip what is cut from larger frames)
+                    2048
+                })
+                .immediate_mode(true) //parse packets ASAP!
+                .open()
+        };
+        let cap_res = match &device.netns {
+            None => open(),
+            // the capture keeps working in the namespace it was opened in
+            Some(netns) => match netns.run_in(open) {
+                Ok(cap_res) => cap_res,
+                Err(e) => return Self::Error(e),
+            },
+        };
 
         if let Err(e) = &cap_res {
             return Self::Error(e.to_string());
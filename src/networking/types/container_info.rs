@@ -0,0 +1,286 @@
+//! Module defining the `ContainerInfo` struct, which identifies the container of a local process,
+//! found from its cgroup and named after the metadata files of the local container runtime.
+
+use std::fmt;
+
+/// Length of the container identifiers (hex SHA-256 digests)
+const CONTAINER_ID_LEN: usize = 64;
+
+/// Length of the short form of the container identifiers
+const SHORT_ID_LEN: usize = 12;
+
+/// Container of a local process
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ContainerInfo {
+    /// Container identifier
+    pub id: String,
+    /// Name of the container (this is the short identifier if the runtime metadata can't be read)
+    pub name: String,
+    /// Container runtime managing the container
+    pub runtime: ContainerRuntime,
+}
+
+impl ContainerInfo {
+    /// Returns the container of a process, given the content of its `/proc/<pid>/cgroup` file
+    pub fn from_cgroup(content: &str) -> Option<Self> {
+        let (runtime, id) = parse_cgroup(content)?;
+        let name = container_name(runtime, &id).unwrap_or_else(|| short_id(&id).to_string());
+        Some(Self { id, name, runtime })
+    }
+
+    /// Returns the short form of the container identifier
+    pub fn short_id(&self) -> &str {
+        short_id(&self.id)
+    }
+}
+
+impl fmt::Display for ContainerInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} {})", self.name, self.runtime, self.short_id())
+    }
+}
+
+/// Runtime managing a container
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerRuntime {
+    Docker,
+    Podman,
+    Containerd,
+    CriO,
+}
+
+impl ContainerRuntime {
+    /// Returns the runtime corresponding to the prefix of a cgroup scope (e.g., `docker-<id>.scope`)
+    /// or to the parent of a cgroup directory (e.g., `/docker/<id>`)
+    fn from_cgroup_component(component: &str) -> Option<Self> {
+        match component {
+            "docker" => Some(Self::Docker),
+            "libpod" => Some(Self::Podman),
+            "cri-containerd" | "containerd" => Some(Self::Containerd),
+            "crio" => Some(Self::CriO),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ContainerRuntime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let runtime = match self {
+            Self::Docker => "Docker",
+            Self::Podman => "Podman",
+            Self::Containerd => "containerd",
+            Self::CriO => "CRI-O",
+        };
+        write!(f, "{runtime}")
+    }
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..SHORT_ID_LEN).unwrap_or(id)
+}
+
+fn is_container_id(id: &str) -> bool {
+    id.len() == CONTAINER_ID_LEN && id.bytes().all(|byte| byte.is_ascii_hexdigit())
+}
+
+/// Parses the content of a `/proc/<pid>/cgroup` file, returning the runtime and the identifier
+/// of the container, if the process belongs to one.
+///
+/// Both the systemd layout (e.g., `/system.slice/docker-<id>.scope`) and the cgroupfs layout
+/// (e.g., `/docker/<id>` or `/kubepods/burstable/pod<uid>/<id>`) are supported.
+fn parse_cgroup(content: &str) -> Option<(ContainerRuntime, String)> {
+    content.lines().find_map(|line| {
+        // each line is made of hierarchy ID, controllers, and path
+        let path = line.splitn(3, ':').nth(2)?;
+        let components: Vec<&str> = path.split('/').collect();
+        components
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(i, component)| {
+                let component = component.strip_suffix(".scope").unwrap_or(component);
+                if let Some((prefix, id)) = component.rsplit_once('-') {
+                    let runtime = ContainerRuntime::from_cgroup_component(prefix)?;
+                    return is_container_id(id).then(|| (runtime, id.to_string()));
+                }
+                if !is_container_id(component) {
+                    return None;
+                }
+                // containers of Kubernetes pods are run by containerd, unless otherwise stated
+                let runtime = components[..i]
+                    .iter()
+                    .rev()
+                    .find_map(|parent| ContainerRuntime::from_cgroup_component(parent))
+                    .or_else(|| {
+                        components[..i]
+                            .iter()
+                            .any(|parent| parent.starts_with("kubepods"))
+                            .then_some(ContainerRuntime::Containerd)
+                    })?;
+                Some((runtime, component.to_string()))
+            })
+    })
+}
+
+/// Reads the name of a container from the metadata files of its runtime
+#[cfg(target_os = "linux")]
+fn container_name(runtime: ContainerRuntime, id: &str) -> Option<String> {
+    use std::fs;
+
+    match runtime {
+        ContainerRuntime::Docker => {
+            let config =
+                fs::read_to_string(format!("/var/lib/docker/containers/{id}/config.v2.json"));
+            parse_docker_config(&config.ok()?)
+        }
+        ContainerRuntime::Podman | ContainerRuntime::CriO => {
+            let storage = "/var/lib/containers/storage/overlay-containers";
+            fs::read_to_string(format!("{storage}/{id}/userdata/config.json"))
+                .ok()
+                .and_then(|config| parse_oci_config(&config))
+                .or_else(|| {
+                    let containers = fs::read_to_string(format!("{storage}/containers.json"));
+                    parse_storage_containers(&containers.ok()?, id)
+                })
+        }
+        ContainerRuntime::Containerd => ["k8s.io", "moby", "default"]
+            .into_iter()
+            .find_map(|namespace| {
+                fs::read_to_string(format!(
+                    "/run/containerd/io.containerd.runtime.v2.task/{namespace}/{id}/config.json"
+                ))
+                .ok()
+            })
+            .and_then(|config| parse_oci_config(&config)),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn container_name(_runtime: ContainerRuntime, _id: &str) -> Option<String> {
+    None
+}
+
+/// Parses the `config.v2.json` file of a Docker container, returning its name
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_docker_config(config: &str) -> Option<String> {
+    let config: serde_json::Value = serde_json::from_str(config).ok()?;
+    let name = config.get("Name")?.as_str()?;
+    Some(name.trim_start_matches('/').to_string()).filter(|name| !name.is_empty())
+}
+
+/// Parses the OCI runtime configuration of a container, returning its name
+/// (prefixed with the name of its pod, for Kubernetes containers) from the annotations
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_oci_config(config: &str) -> Option<String> {
+    let config: serde_json::Value = serde_json::from_str(config).ok()?;
+    let annotations = config.get("annotations")?;
+    let annotation = |keys: &[&str]| {
+        keys.iter()
+            .find_map(|key| annotations.get(key)?.as_str())
+            .filter(|value| !value.is_empty())
+    };
+    let container = annotation(&[
+        "io.kubernetes.cri.container-name",
+        "io.kubernetes.container.name",
+    ]);
+    let pod = annotation(&["io.kubernetes.cri.sandbox-name", "io.kubernetes.pod.name"]);
+    match (pod, container) {
+        (Some(pod), Some(container)) => Some(format!("{pod}/{container}")),
+        (pod, container) => container.or(pod).map(ToString::to_string),
+    }
+}
+
+/// Parses the `containers.json` file of the containers storage (used by Podman and CRI-O),
+/// returning the name of the container with the given identifier
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_storage_containers(containers: &str, id: &str) -> Option<String> {
+    let containers: serde_json::Value = serde_json::from_str(containers).ok()?;
+    let container = containers
+        .as_array()?
+        .iter()
+        .find(|container| container.get("id").and_then(|v| v.as_str()) == Some(id))?;
+    let name = container.get("names")?.as_array()?.first()?.as_str()?;
+    Some(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::container_info::{
+        parse_cgroup, parse_docker_config, parse_oci_config, parse_storage_containers,
+        ContainerInfo, ContainerRuntime,
+    };
+
+    const ID: &str = "3f4e5d6c7b8a99887766554433221100ffeeddccbbaa00112233445566778899";
+
+    #[test]
+    fn test_parse_cgroup() {
+        let systemd_docker = format!("0::/system.slice/docker-{ID}.scope\n");
+        let cgroupfs_docker = format!("12:pids:/docker/{ID}\n11:memory:/docker/{ID}\n");
+        let podman = format!("0::/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-{ID}.scope/container");
+        let kubepods_systemd = format!("0::/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b3c4d_5e6f.slice/cri-containerd-{ID}.scope");
+        let kubepods_cgroupfs = format!("0::/kubepods/besteffort/pod1a2b3c4d-5e6f/{ID}");
+        let crio = format!("0::/kubepods.slice/kubepods-pod1a2b.slice/crio-{ID}.scope");
+
+        for (content, runtime) in [
+            (systemd_docker, ContainerRuntime::Docker),
+            (cgroupfs_docker, ContainerRuntime::Docker),
+            (podman, ContainerRuntime::Podman),
+            (kubepods_systemd, ContainerRuntime::Containerd),
+            (kubepods_cgroupfs, ContainerRuntime::Containerd),
+            (crio, ContainerRuntime::CriO),
+        ] {
+            assert_eq!(parse_cgroup(&content), Some((runtime, ID.to_string())));
+        }
+
+        // processes of the host
+        assert_eq!(
+            parse_cgroup("0::/user.slice/user-1000.slice/session-2.scope"),
+            None
+        );
+        assert_eq!(parse_cgroup("0::/init.scope\n"), None);
+        assert_eq!(parse_cgroup("0::/docker-1234.scope"), None);
+        assert_eq!(parse_cgroup(""), None);
+    }
+
+    #[test]
+    fn test_parse_metadata() {
+        assert_eq!(
+            parse_docker_config(r#"{"ID":"3f4e","Name":"/web","State":{"Running":true}}"#),
+            Some("web".to_string())
+        );
+        assert_eq!(parse_docker_config(r#"{"Name":""}"#), None);
+        assert_eq!(parse_docker_config("not json"), None);
+
+        assert_eq!(
+            parse_oci_config(
+                r#"{"ociVersion":"1.0.2","annotations":{"io.kubernetes.cri.container-name":"nginx","io.kubernetes.cri.sandbox-name":"frontend-7d9f"}}"#
+            ),
+            Some("frontend-7d9f/nginx".to_string())
+        );
+        assert_eq!(
+            parse_oci_config(r#"{"annotations":{"io.kubernetes.container.name":"db"}}"#),
+            Some("db".to_string())
+        );
+        assert_eq!(parse_oci_config(r#"{"annotations":{}}"#), None);
+
+        let containers = format!(
+            r#"[{{"id":"0000","names":["other"]}},{{"id":"{ID}","names":["cache","alias"]}}]"#
+        );
+        assert_eq!(
+            parse_storage_containers(&containers, ID),
+            Some("cache".to_string())
+        );
+        assert_eq!(parse_storage_containers(&containers, "1111"), None);
+    }
+
+    #[test]
+    fn test_container_display() {
+        let container = ContainerInfo {
+            id: ID.to_string(),
+            name: "web".to_string(),
+            runtime: ContainerRuntime::Docker,
+        };
+        assert_eq!(container.short_id(), "3f4e5d6c7b8a");
+        assert_eq!(container.to_string(), "web (Docker 3f4e5d6c7b8a)");
+    }
+}
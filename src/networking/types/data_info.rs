@@ -1,7 +1,7 @@
 //! Module defining the `DataInfo` struct, which represents incoming and outgoing packets and bytes.
 
 use std::cmp::Ordering;
-use std::ops::AddAssign;
+use std::ops::{AddAssign, SubAssign};
 
 use chrono::{DateTime, Local};
 
@@ -62,17 +62,25 @@ pub fn add_packet(&mut self, bytes: u128, traffic_direction: TrafficDirection) {
     }
 
     pub fn new_with_first_packet(bytes: u128, traffic_direction: TrafficDirection) -> Self {
+        Self::new_with_packets(1, bytes, traffic_direction)
+    }
+
+    pub fn new_with_packets(
+        packets: u128,
+        bytes: u128,
+        traffic_direction: TrafficDirection,
+    ) -> Self {
         if traffic_direction.eq(&TrafficDirection::Outgoing) {
             Self {
                 incoming_packets: 0,
-                outgoing_packets: 1,
+                outgoing_packets: packets,
                 incoming_bytes: 0,
                 outgoing_bytes: bytes,
                 final_timestamp: Local::now(),
             }
         } else {
             Self {
-                incoming_packets: 1,
+                incoming_packets: packets,
                 outgoing_packets: 0,
                 incoming_bytes: bytes,
                 outgoing_bytes: 0,
@@ -122,3 +130,12 @@ fn add_assign(&mut self, rhs: Self) {
         self.final_timestamp = Local::now();
     }
 }
+
+impl SubAssign for DataInfo {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.incoming_packets = self.incoming_packets.saturating_sub(rhs.incoming_packets);
+        self.outgoing_packets = self.outgoing_packets.saturating_sub(rhs.outgoing_packets);
+        self.incoming_bytes = self.incoming_bytes.saturating_sub(rhs.incoming_bytes);
+        self.outgoing_bytes = self.outgoing_bytes.saturating_sub(rhs.outgoing_bytes);
+    }
+}
@@ -0,0 +1,201 @@
+//! Module defining the `DhcpAnalytics` struct, which collects the leases and the servers seen
+//! in the DHCP exchanges sniffed on the wire.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::dhcp_message::DhcpMessage;
+
+/// A DHCP client, with the details of the last lease it obtained
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DhcpClient {
+    /// Hardware address
+    pub mac_address: Option<String>,
+    /// Leased address (this is None if no lease was seen)
+    pub address: Option<String>,
+    /// Host name declared by the client
+    pub hostname: Option<String>,
+    /// Vendor class identifier declared by the client
+    pub vendor_class: Option<String>,
+    /// Lease time, in seconds
+    pub lease_time: Option<u32>,
+    /// Address of the server which granted the lease
+    pub server: Option<String>,
+}
+
+/// A DHCP server, with the number of messages it sent
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DhcpServer {
+    /// Address the server identifies itself with
+    pub address: String,
+    /// Hardware address the server messages were sent from
+    pub mac_address: Option<String>,
+    /// Number of messages sent (offers, acknowledgements, and replies)
+    pub messages: usize,
+}
+
+/// Leases and servers seen in the DHCP exchanges sniffed on the wire
+#[derive(Clone, Default, Debug)]
+pub struct DhcpAnalytics {
+    /// Clients, identified by their hardware address (DHCPv4) or DUID (DHCPv6)
+    pub clients: HashMap<String, DhcpClient>,
+    /// Servers, in the order they were first seen
+    pub servers: Vec<DhcpServer>,
+    /// Addresses of the servers seen for the first time in the last interval
+    pub servers_last_interval: Vec<String>,
+}
+
+impl DhcpAnalytics {
+    /// Updates the leases and the servers with a DHCP message sent by the source of `key`,
+    /// whose hardware address is `source_mac`.
+    ///
+    /// Returns the client the message refers to.
+    pub fn update(
+        &mut self,
+        key: &AddressPortPair,
+        dhcp_message: &DhcpMessage,
+        source_mac: Option<String>,
+    ) -> &DhcpClient {
+        let mut server = None;
+        if dhcp_message.from_server {
+            // relay agents forward the messages of DHCPv4 servers, which identify themselves in an option
+            let address = dhcp_message
+                .server_id
+                .map_or_else(|| key.address1.clone(), |address| address.to_string());
+            if let Some(known_server) = self.servers.iter_mut().find(|s| s.address == address) {
+                known_server.messages += 1;
+            } else {
+                self.servers_last_interval.push(address.clone());
+                self.servers.push(DhcpServer {
+                    address: address.clone(),
+                    mac_address: source_mac,
+                    messages: 1,
+                });
+            }
+            server = Some(address);
+        }
+
+        let client = self
+            .clients
+            .entry(dhcp_message.client_id.clone())
+            .or_default();
+        if dhcp_message.client_mac.is_some() {
+            client.mac_address.clone_from(&dhcp_message.client_mac);
+        }
+        if dhcp_message.hostname.is_some() {
+            client.hostname.clone_from(&dhcp_message.hostname);
+        }
+        if !dhcp_message.from_server && dhcp_message.vendor_class.is_some() {
+            client.vendor_class.clone_from(&dhcp_message.vendor_class);
+        }
+        if let Some(address) = dhcp_message.assigned_address {
+            client.address = Some(address.to_string());
+            client.lease_time = dhcp_message.lease_time;
+            client.server = server;
+        }
+        client
+    }
+
+    /// Returns true if more than one server of the same IP version was seen:
+    /// DHCPv4 and DHCPv6 servers are counted separately, since dual-stack networks have one of each
+    pub fn multiple_servers(&self) -> bool {
+        let ipv6_servers = self
+            .servers
+            .iter()
+            .filter(|server| server.address.contains(':'))
+            .count();
+        ipv6_servers > 1 || self.servers.len() - ipv6_servers > 1
+    }
+
+    /// Returns the clients sorted by leased address (clients without a lease come last)
+    pub fn sorted_clients(&self) -> Vec<&DhcpClient> {
+        let mut clients: Vec<&DhcpClient> = self.clients.values().collect();
+        clients.sort_by_cached_key(|client| {
+            let address = client
+                .address
+                .as_deref()
+                .and_then(|address| address.parse::<IpAddr>().ok());
+            (address.is_none(), address, client.mac_address.clone())
+        });
+        clients
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::dhcp_analytics::{DhcpAnalytics, DhcpClient};
+    use crate::networking::types::dhcp_message::DhcpMessage;
+    use crate::Protocol;
+
+    fn reply(
+        server: &str,
+        client_id: &str,
+        assigned_address: &str,
+    ) -> (AddressPortPair, DhcpMessage) {
+        let key = AddressPortPair::new(
+            server.to_string(),
+            Some(67),
+            "255.255.255.255".to_string(),
+            Some(68),
+            Protocol::UDP,
+        );
+        let dhcp_message = DhcpMessage {
+            client_id: client_id.to_string(),
+            from_server: true,
+            assigned_address: Some(assigned_address.parse::<IpAddr>().unwrap()),
+            ..DhcpMessage::default()
+        };
+        (key, dhcp_message)
+    }
+
+    #[test]
+    fn test_dual_stack_servers() {
+        let mut dhcp_analytics = DhcpAnalytics::default();
+        // the same router leasing both IPv4 and IPv6 addresses
+        for (server, client_id, assigned_address) in [
+            ("192.168.1.1", "3c:22:fb:01:02:03", "192.168.1.23"),
+            ("fe80::1", "000100012b3c4d5e3c22fb010203", "2001:db8::23"),
+        ] {
+            let (key, dhcp_message) = reply(server, client_id, assigned_address);
+            dhcp_analytics.update(&key, &dhcp_message, None);
+        }
+        assert_eq!(dhcp_analytics.servers.len(), 2);
+        assert!(!dhcp_analytics.multiple_servers());
+
+        // another DHCPv6 server on the same network
+        let (key, dhcp_message) = reply("fe80::2", "000100012b3c4d5e3c22fb010203", "2001:db8::99");
+        dhcp_analytics.update(&key, &dhcp_message, None);
+        assert!(dhcp_analytics.multiple_servers());
+    }
+
+    #[test]
+    fn test_sorted_clients() {
+        let mut dhcp_analytics = DhcpAnalytics::default();
+        for (client_id, address) in [
+            ("c", Some("192.168.1.10")),
+            ("d", None),
+            ("a", Some("2001:db8::1")),
+            ("b", Some("192.168.1.9")),
+        ] {
+            dhcp_analytics.clients.insert(
+                client_id.to_string(),
+                DhcpClient {
+                    mac_address: Some(client_id.to_string()),
+                    address: address.map(ToString::to_string),
+                    ..DhcpClient::default()
+                },
+            );
+        }
+        // addresses are sorted numerically, not as strings
+        let clients: Vec<&str> = dhcp_analytics
+            .sorted_clients()
+            .iter()
+            .map(|client| client.mac_address.as_deref().unwrap())
+            .collect();
+        assert_eq!(clients, vec!["b", "c", "a", "d"]);
+    }
+}
@@ -0,0 +1,369 @@
+//! Module defining the `DhcpMessage` struct, which contains the relevant content of a DHCPv4
+//! or DHCPv6 message sniffed on the wire.
+
+use std::fmt::Write;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::networking::manage_packets::mac_from_dec_to_hex;
+
+/// Port used by DHCPv4 servers
+pub const DHCP_SERVER_PORT: u16 = 67;
+/// Port used by DHCPv4 clients
+pub const DHCP_CLIENT_PORT: u16 = 68;
+/// Port used by DHCPv6 clients
+pub const DHCPV6_CLIENT_PORT: u16 = 546;
+/// Port used by DHCPv6 servers and relay agents
+pub const DHCPV6_SERVER_PORT: u16 = 547;
+
+/// Relevant content of a DHCPv4 or DHCPv6 message
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DhcpMessage {
+    /// Identifier of the client: its hardware address (DHCPv4) or its DUID (DHCPv6)
+    pub client_id: String,
+    /// Hardware address of the client (for DHCPv6, this is None if it can't be derived from the DUID)
+    pub client_mac: Option<String>,
+    /// True if the message was sent by a server
+    pub from_server: bool,
+    /// Address leased to the client (only set in acknowledgements and replies)
+    pub assigned_address: Option<IpAddr>,
+    /// Lease time of the assigned address, in seconds
+    pub lease_time: Option<u32>,
+    /// Host name declared by the client (or confirmed by the server)
+    pub hostname: Option<String>,
+    /// Vendor class identifier declared by the client (e.g., `MSFT 5.0`, `android-dhcp-14`)
+    pub vendor_class: Option<String>,
+    /// Address the server identifies itself with (DHCPv4 only)
+    pub server_id: Option<IpAddr>,
+}
+
+impl DhcpMessage {
+    const MAGIC_COOKIE: [u8; 4] = [99, 130, 83, 99];
+
+    const OPTION_PAD: u8 = 0;
+    const OPTION_HOSTNAME: u8 = 12;
+    const OPTION_LEASE_TIME: u8 = 51;
+    const OPTION_MESSAGE_TYPE: u8 = 53;
+    const OPTION_SERVER_ID: u8 = 54;
+    const OPTION_VENDOR_CLASS: u8 = 60;
+    const OPTION_CLIENT_FQDN: u8 = 81;
+    const OPTION_END: u8 = 255;
+
+    const MESSAGE_TYPE_OFFER: u8 = 2;
+    const MESSAGE_TYPE_ACK: u8 = 5;
+    const MESSAGE_TYPE_NAK: u8 = 6;
+
+    const V6_OPTION_CLIENT_ID: u16 = 1;
+    const V6_OPTION_IA_NA: u16 = 3;
+    const V6_OPTION_IA_ADDR: u16 = 5;
+    const V6_OPTION_VENDOR_CLASS: u16 = 16;
+    const V6_OPTION_CLIENT_FQDN: u16 = 39;
+
+    const V6_MESSAGE_TYPE_ADVERTISE: u8 = 2;
+    const V6_MESSAGE_TYPE_REPLY: u8 = 7;
+    const V6_MESSAGE_TYPE_RECONFIGURE: u8 = 10;
+    const V6_MESSAGE_TYPE_INFORMATION_REQUEST: u8 = 11;
+
+    /// Parses a DHCPv4 message.
+    ///
+    /// Since captured packets may be truncated, the options following a malformed one are ignored.
+    /// Returns None if the fixed part of the message can't be parsed.
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let fixed = bytes.get(..240)?;
+        // only Ethernet hardware addresses are supported
+        if fixed[1] != 1 || fixed[2] != 6 || fixed[236..240] != Self::MAGIC_COOKIE {
+            return None;
+        }
+        let yiaddr = Ipv4Addr::new(fixed[16], fixed[17], fixed[18], fixed[19]);
+        let chaddr: [u8; 6] = fixed[28..34].try_into().ok()?;
+        let client_mac = mac_from_dec_to_hex(chaddr);
+
+        let mut message = Self {
+            client_id: client_mac.clone(),
+            client_mac: Some(client_mac),
+            ..Self::default()
+        };
+        let mut message_type = None;
+        let mut offset = 240;
+        while let Some(&code) = bytes.get(offset) {
+            match code {
+                Self::OPTION_PAD => {
+                    offset += 1;
+                    continue;
+                }
+                Self::OPTION_END => break,
+                _ => {}
+            }
+            let Some(&len) = bytes.get(offset + 1) else {
+                break;
+            };
+            let Some(data) = bytes.get(offset + 2..offset + 2 + usize::from(len)) else {
+                break;
+            };
+            match code {
+                Self::OPTION_MESSAGE_TYPE => message_type = data.first().copied(),
+                Self::OPTION_HOSTNAME => message.hostname = read_text(data),
+                Self::OPTION_CLIENT_FQDN => {
+                    // flags (the E bit tells if the name is in wire format), and two deprecated fields
+                    let name = match data.split_first() {
+                        Some((flags, [_, _, name @ ..])) if flags & 0x04 != 0 => read_fqdn(name),
+                        Some((_, [_, _, name @ ..])) => read_text(name),
+                        _ => None,
+                    };
+                    message.hostname = message.hostname.or(name);
+                }
+                Self::OPTION_VENDOR_CLASS => message.vendor_class = read_text(data),
+                Self::OPTION_LEASE_TIME => message.lease_time = read_u32(data),
+                Self::OPTION_SERVER_ID => {
+                    message.server_id = <[u8; 4]>::try_from(data).ok().map(IpAddr::from);
+                }
+                _ => {}
+            }
+            offset += 2 + usize::from(len);
+        }
+
+        message.from_server = matches!(
+            message_type?,
+            Self::MESSAGE_TYPE_OFFER | Self::MESSAGE_TYPE_ACK | Self::MESSAGE_TYPE_NAK
+        );
+        if message_type == Some(Self::MESSAGE_TYPE_ACK) && !yiaddr.is_unspecified() {
+            message.assigned_address = Some(IpAddr::V4(yiaddr));
+        }
+        Some(message)
+    }
+
+    /// Parses a DHCPv6 message exchanged between a client and a server (relayed messages are ignored).
+    ///
+    /// Since captured packets may be truncated, the options following a malformed one are ignored.
+    /// Returns None if the client identifier can't be found.
+    pub fn parse_v6(bytes: &[u8]) -> Option<Self> {
+        let (&message_type, _transaction_id) = bytes.split_first()?;
+        if !(1..=Self::V6_MESSAGE_TYPE_INFORMATION_REQUEST).contains(&message_type) {
+            return None;
+        }
+
+        let mut message = Self {
+            from_server: matches!(
+                message_type,
+                Self::V6_MESSAGE_TYPE_ADVERTISE
+                    | Self::V6_MESSAGE_TYPE_REPLY
+                    | Self::V6_MESSAGE_TYPE_RECONFIGURE
+            ),
+            ..Self::default()
+        };
+        for (code, data) in read_v6_options(bytes.get(4..)?) {
+            match code {
+                Self::V6_OPTION_CLIENT_ID => {
+                    message.client_id = data.iter().fold(String::new(), |mut id, byte| {
+                        let _ = write!(id, "{byte:02x}");
+                        id
+                    });
+                    message.client_mac = mac_from_duid(data);
+                }
+                Self::V6_OPTION_IA_NA if message_type == Self::V6_MESSAGE_TYPE_REPLY => {
+                    // identity association ID and renewal times, followed by the options
+                    let addresses = read_v6_options(data.get(12..).unwrap_or_default())
+                        .filter(|(code, _)| *code == Self::V6_OPTION_IA_ADDR);
+                    for (_, address_data) in addresses {
+                        let Some(address) = address_data.get(..16) else {
+                            continue;
+                        };
+                        let address: [u8; 16] = address.try_into().ok()?;
+                        message.assigned_address = Some(IpAddr::V6(Ipv6Addr::from(address)));
+                        // valid lifetime follows the preferred one
+                        message.lease_time = address_data.get(20..24).and_then(read_u32);
+                    }
+                }
+                Self::V6_OPTION_VENDOR_CLASS => {
+                    // enterprise number, followed by the length of the first vendor class
+                    message.vendor_class = data.get(4..6).and_then(|len| {
+                        let len = usize::from(u16::from_be_bytes([len[0], len[1]]));
+                        read_text(data.get(6..6 + len)?)
+                    });
+                }
+                Self::V6_OPTION_CLIENT_FQDN => {
+                    message.hostname = data.get(1..).and_then(read_fqdn);
+                }
+                _ => {}
+            }
+        }
+
+        if message.client_id.is_empty() {
+            return None;
+        }
+        Some(message)
+    }
+}
+
+/// Iterates over the DHCPv6 options contained in `bytes`, stopping at the first truncated one
+fn read_v6_options(mut bytes: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+    std::iter::from_fn(move || {
+        let header = bytes.get(..4)?;
+        let code = u16::from_be_bytes([header[0], header[1]]);
+        let len = usize::from(u16::from_be_bytes([header[2], header[3]]));
+        let data = bytes.get(4..4 + len)?;
+        bytes = &bytes[4 + len..];
+        Some((code, data))
+    })
+}
+
+/// Returns the hardware address contained in a DUID based on an Ethernet link-layer address
+fn mac_from_duid(duid: &[u8]) -> Option<String> {
+    const DUID_LLT: u16 = 1;
+    const DUID_LL: u16 = 3;
+    const HARDWARE_TYPE_ETHERNET: u16 = 1;
+
+    let read_u16 = |offset: usize| {
+        duid.get(offset..offset + 2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+    };
+    let address = match read_u16(0)? {
+        DUID_LLT => duid.get(8..)?,
+        DUID_LL => duid.get(4..)?,
+        _ => return None,
+    };
+    if read_u16(2)? != HARDWARE_TYPE_ETHERNET {
+        return None;
+    }
+    Some(mac_from_dec_to_hex(address.try_into().ok()?))
+}
+
+/// Reads a domain name in DNS wire format, which may be partial (i.e., not terminated by the root label)
+fn read_fqdn(mut bytes: &[u8]) -> Option<String> {
+    let mut labels: Vec<String> = Vec::new();
+    while let Some((&len, rest)) = bytes.split_first() {
+        if len == 0 {
+            break;
+        }
+        let label = rest.get(..usize::from(len))?;
+        labels.push(label.escape_ascii().to_string());
+        bytes = &rest[usize::from(len)..];
+    }
+    (!labels.is_empty()).then(|| labels.join("."))
+}
+
+/// Reads an option containing text, ignoring the trailing zero bytes some clients include
+fn read_text(bytes: &[u8]) -> Option<String> {
+    let end = bytes.iter().rposition(|byte| *byte != 0)? + 1;
+    Some(bytes[..end].escape_ascii().to_string())
+}
+
+fn read_u32(bytes: &[u8]) -> Option<u32> {
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use crate::networking::types::dhcp_message::DhcpMessage;
+
+    fn dhcp_v4(message_type: u8, yiaddr: [u8; 4], options: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0u8; 240];
+        bytes[0] = if message_type == 1 || message_type == 3 {
+            1
+        } else {
+            2
+        };
+        bytes[1] = 1;
+        bytes[2] = 6;
+        bytes[4..8].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+        bytes[16..20].copy_from_slice(&yiaddr);
+        bytes[28..34].copy_from_slice(&[0x3c, 0x22, 0xfb, 0x01, 0x02, 0x03]);
+        bytes[236..240].copy_from_slice(&[99, 130, 83, 99]);
+        bytes.extend_from_slice(&[53, 1, message_type]);
+        bytes.extend_from_slice(options);
+        bytes.push(255);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_dhcp_request() {
+        let mut options = vec![12, 12];
+        options.extend_from_slice(b"alice-laptop");
+        options.extend_from_slice(&[60, 9]);
+        options.extend_from_slice(b"MSFT 5.0\0");
+        let message = DhcpMessage::parse(&dhcp_v4(3, [0; 4], &options)).unwrap();
+        assert_eq!(
+            message,
+            DhcpMessage {
+                client_id: "3c:22:fb:01:02:03".to_string(),
+                client_mac: Some("3c:22:fb:01:02:03".to_string()),
+                from_server: false,
+                hostname: Some("alice-laptop".to_string()),
+                vendor_class: Some("MSFT 5.0".to_string()),
+                ..DhcpMessage::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_dhcp_ack() {
+        let options = [
+            54, 4, 192, 168, 1, 1, // server identifier
+            0, 0, // padding
+            51, 4, 0, 0, 0x0e, 0x10, // lease time
+            81, 8, 0x05, 0, 0, 5, b'p', b'h', b'o',
+            b'n', // client FQDN, in wire format and truncated
+        ];
+        let message = DhcpMessage::parse(&dhcp_v4(5, [192, 168, 1, 23], &options)).unwrap();
+        assert!(message.from_server);
+        assert_eq!(
+            message.assigned_address,
+            Some(IpAddr::from([192, 168, 1, 23]))
+        );
+        assert_eq!(message.lease_time, Some(3600));
+        assert_eq!(message.server_id, Some(IpAddr::from([192, 168, 1, 1])));
+        assert_eq!(message.hostname, None);
+
+        // offers don't assign anything yet
+        let message = DhcpMessage::parse(&dhcp_v4(2, [192, 168, 1, 23], &options)).unwrap();
+        assert!(message.from_server);
+        assert_eq!(message.assigned_address, None);
+    }
+
+    #[test]
+    fn test_parse_not_dhcp() {
+        assert_eq!(DhcpMessage::parse(&[0; 100]), None);
+        let mut bytes = dhcp_v4(1, [0; 4], &[]);
+        bytes[236] = 0;
+        assert_eq!(DhcpMessage::parse(&bytes), None);
+        // message type is mandatory
+        let mut bytes = dhcp_v4(1, [0; 4], &[]);
+        bytes.truncate(240);
+        assert_eq!(DhcpMessage::parse(&bytes), None);
+    }
+
+    #[test]
+    fn test_parse_dhcpv6_reply() {
+        let mut bytes = vec![7, 0xaa, 0xbb, 0xcc];
+        // client identifier (DUID-LLT)
+        bytes.extend_from_slice(&[0, 1, 0, 14, 0, 1, 0, 1, 0x2c, 0x3e, 0x12, 0x00]);
+        bytes.extend_from_slice(&[0x3c, 0x22, 0xfb, 0x01, 0x02, 0x03]);
+        // IA_NA with an address valid for one day
+        bytes.extend_from_slice(&[0, 3, 0, 40, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0, 5, 0, 24]);
+        bytes.extend_from_slice(&[
+            0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x42,
+        ]);
+        bytes.extend_from_slice(&[0, 0, 0x0e, 0x10, 0, 1, 0x51, 0x80]);
+        // client FQDN
+        bytes.extend_from_slice(&[0, 39, 0, 11, 0x01]);
+        bytes.extend_from_slice(b"\x04nas1\x03lan\x00");
+        assert_eq!(
+            DhcpMessage::parse_v6(&bytes),
+            Some(DhcpMessage {
+                client_id: "000100012c3e12003c22fb010203".to_string(),
+                client_mac: Some("3c:22:fb:01:02:03".to_string()),
+                from_server: true,
+                assigned_address: Some("2001:db8::42".parse().unwrap()),
+                lease_time: Some(86400),
+                hostname: Some("nas1.lan".to_string()),
+                ..DhcpMessage::default()
+            })
+        );
+
+        // relayed messages are ignored
+        bytes[0] = 12;
+        assert_eq!(DhcpMessage::parse_v6(&bytes), None);
+    }
+}
@@ -0,0 +1,292 @@
+//! Module defining the `DnsAnalytics` struct, which collects statistics about the DNS transactions
+//! sniffed on the wire.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::dns_message::DnsMessage;
+
+/// A DNS query, with the details of its response (if any)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DnsTransaction {
+    /// Queried name
+    pub name: String,
+    /// Queried record type
+    pub record_type: u16,
+    /// Address of the DNS server
+    pub resolver: String,
+    /// Response code (this is None if no response was seen)
+    pub response_code: Option<u8>,
+    /// Time elapsed between the query and its response, in microseconds
+    /// (this is None if the query or the response wasn't seen)
+    pub latency: Option<i64>,
+}
+
+/// Statistics about the DNS transactions sniffed on the wire
+#[derive(Clone, Default, Debug)]
+pub struct DnsAnalytics {
+    /// Most recent transactions, in the order they were started
+    pub transactions: VecDeque<DnsTransaction>,
+    /// Number of queries
+    pub queries: usize,
+    /// Number of queries for each domain name (only the most queried ones are kept)
+    pub queried_domains: HashMap<String, usize>,
+    /// Number of `NXDOMAIN` responses
+    pub nxdomain: usize,
+    /// Number of `SERVFAIL` responses
+    pub servfail: usize,
+    /// Number of transactions discarded to make room for the most recent ones
+    discarded: usize,
+    /// Queries waiting for a response, identified by client address, resolver, client port,
+    /// and transaction ID, with their sequence number and their timestamp (µs)
+    pending: HashMap<(String, String, u16, u16), (usize, i64)>,
+    /// Timestamp (µs) of the last time the expired queries were discarded
+    last_expiration: i64,
+    /// Sum of the latencies of the answered queries (µs)
+    latency_sum: i64,
+    /// Number of the answered queries with a known latency
+    latency_count: i64,
+}
+
+impl DnsAnalytics {
+    const RESPONSE_CODE_SERVFAIL: u8 = 2;
+    const RESPONSE_CODE_NXDOMAIN: u8 = 3;
+    /// Maximum number of transactions kept
+    const MAX_TRANSACTIONS: usize = 10_000;
+    /// Maximum number of domain names whose queries are counted
+    const MAX_QUERIED_DOMAINS: usize = 10_000;
+    /// Time after which a query is considered unanswered (µs)
+    const PENDING_TIMEOUT: i64 = 30_000_000;
+
+    /// Updates the statistics with a DNS message exchanged between the endpoints of `key`.
+    ///
+    /// `timestamp` is the capture time of the message, expressed in microseconds.
+    pub fn update(&mut self, key: &AddressPortPair, dns_message: &DnsMessage, timestamp: i64) {
+        let Some(question) = dns_message.questions.first() else {
+            return;
+        };
+        let name = question.name.to_lowercase();
+        self.expire_pending(timestamp);
+
+        if dns_message.is_response {
+            let response_code = dns_message.response_code;
+            match response_code {
+                Self::RESPONSE_CODE_NXDOMAIN => self.nxdomain += 1,
+                Self::RESPONSE_CODE_SERVFAIL => self.servfail += 1,
+                _ => {}
+            }
+            let pending_key = (
+                key.address2.clone(),
+                key.address1.clone(),
+                key.port2.unwrap_or_default(),
+                dns_message.id,
+            );
+            let transaction =
+                self.pending
+                    .remove(&pending_key)
+                    .and_then(|(sequence_number, query_timestamp)| {
+                        let index = sequence_number.checked_sub(self.discarded)?;
+                        Some((self.transactions.get_mut(index)?, query_timestamp))
+                    });
+            if let Some((transaction, query_timestamp)) = transaction {
+                let latency = timestamp - query_timestamp;
+                transaction.response_code = Some(response_code);
+                transaction.latency = Some(latency);
+                self.latency_sum += latency;
+                self.latency_count += 1;
+            } else {
+                // the query was sent before the capture started
+                self.push(DnsTransaction {
+                    name,
+                    record_type: question.record_type,
+                    resolver: key.address1.clone(),
+                    response_code: Some(response_code),
+                    latency: None,
+                });
+            }
+        } else {
+            self.count_query(&name);
+            self.pending.insert(
+                (
+                    key.address1.clone(),
+                    key.address2.clone(),
+                    key.port1.unwrap_or_default(),
+                    dns_message.id,
+                ),
+                (self.discarded + self.transactions.len(), timestamp),
+            );
+            self.push(DnsTransaction {
+                name,
+                record_type: question.record_type,
+                resolver: key.address2.clone(),
+                response_code: None,
+                latency: None,
+            });
+        }
+    }
+
+    /// Appends a transaction, discarding the oldest one if the maximum is reached
+    fn push(&mut self, transaction: DnsTransaction) {
+        if self.transactions.len() >= Self::MAX_TRANSACTIONS {
+            self.transactions.pop_front();
+            self.discarded += 1;
+        }
+        self.transactions.push_back(transaction);
+    }
+
+    /// Counts a query for the given name; when the maximum number of names is reached,
+    /// only the most queried half of them is kept
+    fn count_query(&mut self, name: &str) {
+        self.queries += 1;
+        if let Some(count) = self.queried_domains.get_mut(name) {
+            *count += 1;
+            return;
+        }
+        if self.queried_domains.len() >= Self::MAX_QUERIED_DOMAINS {
+            let mut domains: Vec<(String, usize)> = self.queried_domains.drain().collect();
+            domains.sort_unstable_by(|(_, count_a), (_, count_b)| count_b.cmp(count_a));
+            domains.truncate(Self::MAX_QUERIED_DOMAINS / 2);
+            self.queried_domains = domains.into_iter().collect();
+        }
+        self.queried_domains.insert(name.to_string(), 1);
+    }
+
+    /// Forgets the queries which have been waiting for a response for too long
+    fn expire_pending(&mut self, timestamp: i64) {
+        if timestamp - self.last_expiration < Self::PENDING_TIMEOUT {
+            return;
+        }
+        self.last_expiration = timestamp;
+        self.pending
+            .retain(|_, (_, query_timestamp)| timestamp - *query_timestamp < Self::PENDING_TIMEOUT);
+    }
+
+    /// Returns the most queried domain names, with their number of queries
+    pub fn top_domains(&self, n: usize) -> Vec<(String, usize)> {
+        let mut domains: Vec<(String, usize)> = self
+            .queried_domains
+            .iter()
+            .map(|(name, count)| (name.clone(), *count))
+            .collect();
+        domains.sort_by(|(name_a, count_a), (name_b, count_b)| {
+            count_b.cmp(count_a).then_with(|| name_a.cmp(name_b))
+        });
+        domains.truncate(n);
+        domains
+    }
+
+    /// Average latency of the answered queries, in microseconds
+    pub fn average_latency(&self) -> Option<i64> {
+        (self.latency_count > 0).then(|| self.latency_sum / self.latency_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::dns_analytics::DnsAnalytics;
+    use crate::networking::types::dns_message::{DnsMessage, DnsQuestion};
+    use crate::Protocol;
+
+    fn message(id: u16, is_response: bool) -> DnsMessage {
+        DnsMessage {
+            id,
+            is_response,
+            response_code: 0,
+            questions: vec![DnsQuestion {
+                name: "www.example.com".to_string(),
+                record_type: 1,
+            }],
+            answers: Vec::new(),
+        }
+    }
+
+    fn query_key(client: &str) -> AddressPortPair {
+        AddressPortPair::new(
+            client.to_string(),
+            Some(50000),
+            "192.168.1.1".to_string(),
+            Some(53),
+            Protocol::UDP,
+        )
+    }
+
+    #[test]
+    fn test_responses_paired_with_their_client() {
+        let mut dns_analytics = DnsAnalytics::default();
+        // two clients behind a gateway, using the same port and transaction ID
+        dns_analytics.update(&query_key("10.0.0.2"), &message(7, false), 0);
+        dns_analytics.update(&query_key("10.0.0.3"), &message(7, false), 1_000);
+        dns_analytics.update(&query_key("10.0.0.3").reversed(), &message(7, true), 6_000);
+        assert_eq!(dns_analytics.transactions[0].latency, None);
+        assert_eq!(dns_analytics.transactions[1].latency, Some(5_000));
+        assert_eq!(dns_analytics.average_latency(), Some(5_000));
+    }
+
+    #[test]
+    fn test_unanswered_queries_expire() {
+        let mut dns_analytics = DnsAnalytics::default();
+        dns_analytics.update(&query_key("10.0.0.2"), &message(1, false), 0);
+        dns_analytics.update(
+            &query_key("10.0.0.2"),
+            &message(2, false),
+            DnsAnalytics::PENDING_TIMEOUT,
+        );
+        assert_eq!(dns_analytics.pending.len(), 1);
+        // the response to the expired query is considered unsolicited
+        dns_analytics.update(
+            &query_key("10.0.0.2").reversed(),
+            &message(1, true),
+            DnsAnalytics::PENDING_TIMEOUT + 1,
+        );
+        assert_eq!(dns_analytics.transactions.len(), 3);
+        assert_eq!(dns_analytics.average_latency(), None);
+    }
+
+    #[test]
+    fn test_transactions_bounded() {
+        let mut dns_analytics = DnsAnalytics::default();
+        for id in 0..=DnsAnalytics::MAX_TRANSACTIONS {
+            let id = u16::try_from(id).unwrap();
+            dns_analytics.update(&query_key("10.0.0.2"), &message(id, false), 0);
+        }
+        assert_eq!(
+            dns_analytics.transactions.len(),
+            DnsAnalytics::MAX_TRANSACTIONS
+        );
+        // the response to the most recent query still finds its transaction
+        let id = u16::try_from(DnsAnalytics::MAX_TRANSACTIONS).unwrap();
+        dns_analytics.update(&query_key("10.0.0.2").reversed(), &message(id, true), 2_000);
+        assert_eq!(
+            dns_analytics.transactions.back().unwrap().latency,
+            Some(2_000)
+        );
+        assert_eq!(dns_analytics.queried_domains["www.example.com"], 10_001);
+    }
+
+    #[test]
+    fn test_queried_domains_bounded() {
+        let mut dns_analytics = DnsAnalytics::default();
+        let mut query = |name: String| {
+            let mut dns_message = message(0, false);
+            dns_message.questions[0].name = name;
+            dns_analytics.update(&query_key("10.0.0.2"), &dns_message, 0);
+        };
+        for _ in 0..3 {
+            query("popular.example".to_string());
+        }
+        for i in 0..DnsAnalytics::MAX_QUERIED_DOMAINS {
+            query(format!("{i}.example"));
+        }
+        // the least queried half of the names is forgotten, but the queries are still counted
+        assert_eq!(
+            dns_analytics.queried_domains.len(),
+            DnsAnalytics::MAX_QUERIED_DOMAINS / 2 + 1
+        );
+        assert_eq!(
+            dns_analytics.top_domains(1),
+            vec![("popular.example".to_string(), 3)]
+        );
+        assert_eq!(dns_analytics.queries, DnsAnalytics::MAX_QUERIED_DOMAINS + 3);
+    }
+}
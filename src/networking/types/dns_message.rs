@@ -0,0 +1,404 @@
+//! Module defining the `DnsMessage` struct, which contains the relevant content of a DNS
+//! (or mDNS, or LLMNR) message sniffed on the wire.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Port used by DNS
+pub const DNS_PORT: u16 = 53;
+/// Port used by multicast DNS
+pub const MDNS_PORT: u16 = 5353;
+/// Port used by Link-Local Multicast Name Resolution
+pub const LLMNR_PORT: u16 = 5355;
+
+/// Maximum number of compression pointers to follow when reading a name
+const MAX_POINTERS: usize = 16;
+
+/// Entry of the question section of a DNS message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DnsQuestion {
+    /// Queried name
+    pub name: String,
+    /// Queried record type (e.g., 1 for A, 28 for AAAA)
+    pub record_type: u16,
+}
+
+/// Data of a DNS resource record
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DnsRecordData {
+    /// Address of an A or AAAA record
+    Address(IpAddr),
+    /// Canonical name of a CNAME record
+    Alias(String),
+    /// Any other record
+    Other,
+}
+
+/// DNS resource record
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DnsRecord {
+    /// Owner name of the record
+    pub name: String,
+    /// Record type
+    pub record_type: u16,
+    /// Time to live, in seconds
+    pub ttl: u32,
+    /// Content of the record
+    pub data: DnsRecordData,
+    /// Length of the record data, in bytes
+    pub data_len: usize,
+}
+
+/// Relevant content of a DNS message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DnsMessage {
+    /// Transaction identifier
+    pub id: u16,
+    /// True if the message is a response
+    pub is_response: bool,
+    /// Response code (e.g., 0 for `NOERROR`, 3 for `NXDOMAIN`)
+    pub response_code: u8,
+    /// Question section
+    pub questions: Vec<DnsQuestion>,
+    /// Resource records of the answer and additional sections
+    pub answers: Vec<DnsRecord>,
+}
+
+impl DnsMessage {
+    const RECORD_TYPE_A: u16 = 1;
+    const RECORD_TYPE_CNAME: u16 = 5;
+    const RECORD_TYPE_PTR: u16 = 12;
+    const RECORD_TYPE_AAAA: u16 = 28;
+
+    /// Parses a DNS message carried by UDP.
+    ///
+    /// Since captured packets may be truncated, the records following a malformed one are ignored.
+    /// Returns None if even the header or the question section can't be parsed.
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let read_u16 = |offset: usize| -> Option<u16> {
+            Some(u16::from_be_bytes(
+                bytes.get(offset..offset + 2)?.try_into().ok()?,
+            ))
+        };
+
+        if bytes.len() < 12 {
+            return None;
+        }
+        let id = read_u16(0)?;
+        let flags = read_u16(2)?;
+        let [questions_count, answers_count, authorities_count, additionals_count] =
+            [4, 6, 8, 10].map(|offset| usize::from(read_u16(offset).unwrap_or_default()));
+
+        let mut offset = 12;
+        let mut questions = Vec::new();
+        for _ in 0..questions_count {
+            let (name, next) = read_name(bytes, offset)?;
+            questions.push(DnsQuestion {
+                name,
+                record_type: read_u16(next)?,
+            });
+            offset = next + 4;
+        }
+
+        let mut answers = Vec::new();
+        for i in 0..answers_count + authorities_count + additionals_count {
+            let Some((record, next)) = read_record(bytes, offset) else {
+                break;
+            };
+            let is_authority = i >= answers_count && i < answers_count + authorities_count;
+            if !is_authority {
+                answers.push(record);
+            }
+            offset = next;
+        }
+
+        Some(Self {
+            id,
+            is_response: flags & 0x8000 != 0,
+            #[allow(clippy::cast_possible_truncation)]
+            response_code: (flags & 0x000f) as u8,
+            questions,
+            answers,
+        })
+    }
+
+    /// Parses a DNS message carried by TCP (i.e., preceded by its length)
+    pub fn parse_tcp(bytes: &[u8]) -> Option<Self> {
+        Self::parse(bytes.get(2..)?)
+    }
+
+    /// Returns the addresses resolved by this message, each with the name that was queried for it.
+    ///
+    /// CNAME chains are followed back to the queried name.
+    pub fn resolved_addresses(&self) -> Vec<(IpAddr, String)> {
+        if !self.is_response {
+            return Vec::new();
+        }
+        self.answers
+            .iter()
+            .filter_map(|record| match record.data {
+                DnsRecordData::Address(address) => Some((address, self.queried_name(record))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the service types advertised by this mDNS response (e.g., `_ipp._tcp`),
+    /// taken from the owner names of its PTR records.
+    ///
+    /// Subtypes and the enumeration of the available service types are ignored.
+    pub fn advertised_services(&self) -> Vec<String> {
+        if !self.is_response {
+            return Vec::new();
+        }
+        let mut services: Vec<String> = self
+            .answers
+            .iter()
+            .filter(|record| record.record_type == Self::RECORD_TYPE_PTR)
+            .filter_map(|record| {
+                let name = record.name.to_lowercase();
+                let service = name.strip_suffix(".local")?;
+                let mut labels = service.rsplit('.');
+                let (transport, service_name) = (labels.next()?, labels.next()?);
+                (matches!(transport, "_tcp" | "_udp")
+                    && service_name.starts_with('_')
+                    && labels.next().is_none())
+                .then(|| service.to_string())
+            })
+            .collect();
+        services.sort();
+        services.dedup();
+        services
+    }
+
+    /// Follows the CNAME chain leading to the given record, back to the name that was queried
+    fn queried_name(&self, record: &DnsRecord) -> String {
+        let mut name = &record.name;
+        for _ in 0..self.answers.len() {
+            let alias_of = self.answers.iter().find(|alias| {
+                matches!(&alias.data, DnsRecordData::Alias(target) if target.eq_ignore_ascii_case(name))
+            });
+            match alias_of {
+                Some(alias) => name = &alias.name,
+                None => break,
+            }
+        }
+        name.to_lowercase()
+    }
+}
+
+/// Returns the mnemonic of a DNS record type
+pub fn record_type_name(record_type: u16) -> String {
+    match record_type {
+        1 => "A",
+        2 => "NS",
+        5 => "CNAME",
+        6 => "SOA",
+        10 => "NULL",
+        12 => "PTR",
+        15 => "MX",
+        16 => "TXT",
+        28 => "AAAA",
+        33 => "SRV",
+        64 => "SVCB",
+        65 => "HTTPS",
+        255 => "ANY",
+        _ => return format!("TYPE{record_type}"),
+    }
+    .to_string()
+}
+
+/// Returns the mnemonic of a DNS response code
+pub fn response_code_name(response_code: u8) -> String {
+    match response_code {
+        0 => "NOERROR",
+        1 => "FORMERR",
+        2 => "SERVFAIL",
+        3 => "NXDOMAIN",
+        4 => "NOTIMP",
+        5 => "REFUSED",
+        _ => return format!("RCODE{response_code}"),
+    }
+    .to_string()
+}
+
+/// Reads the name starting at `offset`, following compression pointers.
+/// Returns the name and the offset of the first byte after it.
+fn read_name(bytes: &[u8], mut offset: usize) -> Option<(String, usize)> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut end = None;
+    let mut pointers = 0;
+    loop {
+        let len = *bytes.get(offset)?;
+        match len {
+            0 => break,
+            len if len & 0xc0 == 0xc0 => {
+                pointers += 1;
+                if pointers > MAX_POINTERS {
+                    return None;
+                }
+                end.get_or_insert(offset + 2);
+                offset = usize::from(u16::from_be_bytes([len & 0x3f, *bytes.get(offset + 1)?]));
+            }
+            len if len & 0xc0 == 0 => {
+                let label = bytes.get(offset + 1..offset + 1 + usize::from(len))?;
+                labels.push(String::from_utf8_lossy(label).to_string());
+                offset += 1 + usize::from(len);
+            }
+            _ => return None,
+        }
+    }
+    Some((labels.join("."), end.unwrap_or(offset + 1)))
+}
+
+/// Reads the resource record starting at `offset`.
+/// Returns the record and the offset of the first byte after it.
+fn read_record(bytes: &[u8], offset: usize) -> Option<(DnsRecord, usize)> {
+    let (name, offset) = read_name(bytes, offset)?;
+    let fixed = bytes.get(offset..offset + 10)?;
+    let record_type = u16::from_be_bytes([fixed[0], fixed[1]]);
+    let ttl = u32::from_be_bytes([fixed[4], fixed[5], fixed[6], fixed[7]]);
+    let data_len = usize::from(u16::from_be_bytes([fixed[8], fixed[9]]));
+    let data_offset = offset + 10;
+    let data_bytes = bytes.get(data_offset..data_offset + data_len)?;
+
+    let data = match record_type {
+        DnsMessage::RECORD_TYPE_A => {
+            let octets: [u8; 4] = data_bytes.try_into().ok()?;
+            DnsRecordData::Address(IpAddr::V4(Ipv4Addr::from(octets)))
+        }
+        DnsMessage::RECORD_TYPE_AAAA => {
+            let octets: [u8; 16] = data_bytes.try_into().ok()?;
+            DnsRecordData::Address(IpAddr::V6(Ipv6Addr::from(octets)))
+        }
+        DnsMessage::RECORD_TYPE_CNAME => DnsRecordData::Alias(read_name(bytes, data_offset)?.0),
+        _ => DnsRecordData::Other,
+    };
+
+    Some((
+        DnsRecord {
+            name,
+            record_type,
+            ttl,
+            data,
+            data_len,
+        },
+        data_offset + data_len,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use crate::networking::types::dns_message::{
+        record_type_name, response_code_name, DnsMessage, DnsQuestion,
+    };
+
+    // response to a query for www.example.com (A), answered with a CNAME and an address
+    const RESPONSE: [u8; 67] = [
+        0x12, 0x34, 0x81, 0x80, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, // header
+        3, b'w', b'w', b'w', 7, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 3, b'c', b'o', b'm', 0,
+        0x00, 0x01, 0x00, 0x01, // question
+        0xc0, 0x0c, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x06, // CNAME
+        3, b'c', b'd', b'n', 0xc0, 0x10, // cdn.example.com
+        0xc0, 0x2d, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x04, // A
+        93, 184, 216, 34,
+    ];
+
+    #[test]
+    fn test_parse_dns_response() {
+        let message = DnsMessage::parse(&RESPONSE).unwrap();
+        assert_eq!(message.id, 0x1234);
+        assert!(message.is_response);
+        assert_eq!(message.response_code, 0);
+        assert_eq!(
+            message.questions,
+            vec![DnsQuestion {
+                name: "www.example.com".to_string(),
+                record_type: 1
+            }]
+        );
+        assert_eq!(message.answers.len(), 2);
+        assert_eq!(message.answers[1].name, "cdn.example.com");
+        assert_eq!(
+            message.resolved_addresses(),
+            vec![(
+                IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34)),
+                "www.example.com".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_truncated_dns_response() {
+        // the address record is cut off: the other records are still available
+        let message = DnsMessage::parse(&RESPONSE[..65]).unwrap();
+        assert_eq!(message.answers.len(), 1);
+        assert!(message.resolved_addresses().is_empty());
+
+        assert!(DnsMessage::parse(&RESPONSE[..20]).is_none());
+        assert!(DnsMessage::parse(&[]).is_none());
+    }
+
+    #[test]
+    fn test_parse_dns_pointer_loop() {
+        let mut bytes = RESPONSE;
+        // the CNAME owner points to itself
+        bytes[33..35].copy_from_slice(&[0xc0, 0x21]);
+        let message = DnsMessage::parse(&bytes).unwrap();
+        assert!(message.answers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_dns_huge_counts() {
+        let mut bytes = RESPONSE;
+        // the record counts would overflow if summed as 16-bit integers
+        bytes[6..12].copy_from_slice(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        let message = DnsMessage::parse(&bytes).unwrap();
+        assert_eq!(message.answers.len(), 2);
+    }
+
+    #[test]
+    fn test_dns_query_resolves_nothing() {
+        let mut bytes = RESPONSE;
+        bytes[2] = 0x01;
+        let message = DnsMessage::parse(&bytes).unwrap();
+        assert!(!message.is_response);
+        assert!(message.resolved_addresses().is_empty());
+    }
+
+    #[test]
+    fn test_mdns_announcement() {
+        let bytes = [
+            0x00, 0x00, 0x84, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, // header
+            4, b'_', b'i', b'p', b'p', 4, b'_', b't', b'c', b'p', 5, b'l', b'o', b'c', b'a', b'l',
+            0, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x11, 0x94, 0x00, 0x0a, // PTR
+            7, b'P', b'r', b'i', b'n', b't', b'e', b'r', 0xc0,
+            0x0c, // Printer._ipp._tcp.local
+            7, b'p', b'r', b'i', b'n', b't', b'e', b'r', 0xc0, 0x16, // printer.local
+            0x00, 0x01, 0x80, 0x01, 0x00, 0x00, 0x00, 0x78, 0x00, 0x04, 192, 168, 1, 50, // A
+        ];
+        let message = DnsMessage::parse(&bytes).unwrap();
+        assert_eq!(message.advertised_services(), vec!["_ipp._tcp".to_string()]);
+        assert_eq!(
+            message.resolved_addresses(),
+            vec![(
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
+                "printer.local".to_string()
+            )]
+        );
+        // responses to regular queries don't advertise anything
+        assert!(DnsMessage::parse(&RESPONSE)
+            .unwrap()
+            .advertised_services()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_dns_mnemonics() {
+        assert_eq!(record_type_name(28), "AAAA");
+        assert_eq!(record_type_name(99), "TYPE99");
+        assert_eq!(response_code_name(3), "NXDOMAIN");
+        assert_eq!(response_code_name(9), "RCODE9");
+    }
+}
@@ -0,0 +1,74 @@
+//! Module defining the `DnsNames` struct, which keeps the domain names queried for the addresses
+//! seen in DNS responses on the wire.
+
+use std::collections::{HashMap, VecDeque};
+
+/// Maximum number of addresses whose name is kept
+const MAX_DNS_NAMES: usize = 10_000;
+
+/// Map of the addresses with the domain name queried for them, keeping the most recent ones
+#[derive(Clone, Debug, Default)]
+pub struct DnsNames {
+    names: HashMap<String, String>,
+    /// Addresses in the order they were first resolved, to forget the oldest ones
+    order: VecDeque<String>,
+}
+
+impl DnsNames {
+    /// Returns the name queried for the given address, if any
+    pub fn get(&self, address: &str) -> Option<&String> {
+        self.names.get(address)
+    }
+
+    /// Returns true if a name is known for the given address
+    pub fn contains_key(&self, address: &str) -> bool {
+        self.names.contains_key(address)
+    }
+
+    /// Number of addresses whose name is known
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Sets the name queried for an address, forgetting the oldest address if the limit is reached
+    pub fn insert(&mut self, address: String, name: String) {
+        if let Some(known_name) = self.names.get_mut(&address) {
+            *known_name = name;
+            return;
+        }
+        if self.order.len() >= MAX_DNS_NAMES {
+            if let Some(oldest) = self.order.pop_front() {
+                self.names.remove(&oldest);
+            }
+        }
+        self.order.push_back(address.clone());
+        self.names.insert(address, name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::dns_names::{DnsNames, MAX_DNS_NAMES};
+
+    #[test]
+    fn test_dns_names_limit() {
+        let mut dns_names = DnsNames::default();
+        for i in 0..MAX_DNS_NAMES {
+            dns_names.insert(
+                format!("10.0.{}.{}", i / 256, i % 256),
+                format!("{i}.example"),
+            );
+        }
+        // a new name for a known address doesn't take room
+        dns_names.insert("10.0.0.0".to_string(), "new.example".to_string());
+        assert_eq!(dns_names.len(), MAX_DNS_NAMES);
+        assert_eq!(dns_names.get("10.0.0.0").unwrap(), "new.example");
+
+        // the oldest address is forgotten
+        dns_names.insert("203.0.113.1".to_string(), "last.example".to_string());
+        assert_eq!(dns_names.len(), MAX_DNS_NAMES);
+        assert!(!dns_names.contains_key("10.0.0.0"));
+        assert_eq!(dns_names.get("10.0.0.1").unwrap(), "1.example");
+        assert_eq!(dns_names.get("203.0.113.1").unwrap(), "last.example");
+    }
+}
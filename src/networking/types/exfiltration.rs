@@ -0,0 +1,532 @@
+//! Module defining the `ExfiltrationDetector` struct, which looks for DNS tunnelling
+//! and data exfiltration by means of simple heuristics.
+//!
+//! The DNS queries and responses are checked for labels looking like encoded data,
+//! for many unique subdomains of the same domain, and for large TXT and NULL records;
+//! the connections are checked for sending much more than they receive.
+
+use std::collections::{HashMap, HashSet};
+
+use once_cell::sync::Lazy;
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::dns_message::{DnsMessage, DNS_PORT};
+use crate::translations::translations_4::{
+    large_records_translation, skewed_flows_translation, suspicious_labels_translation,
+    unique_subdomains_translation,
+};
+use crate::Language;
+
+/// Bundled public suffixes made of more than one label (see the file header for a description)
+const PUBLIC_SUFFIXES: &str = include_str!("../../../resources/DB/public_suffixes.txt");
+
+static SUFFIXES: Lazy<HashSet<&str>> = Lazy::new(|| {
+    PUBLIC_SUFFIXES
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+});
+
+/// Top-level domains of the names that aren't looked up on the Internet:
+/// reverse lookups (also done by Sniffnet itself) and multicast DNS
+const LOCAL_TLDS: [&str; 2] = ["arpa", "local"];
+
+/// Labels at least this long are always suspicious (labels can't exceed 63 characters)
+const LONG_LABEL_LEN: usize = 50;
+
+/// Labels at least this long are suspicious if their entropy is high
+const RANDOM_LABEL_LEN: usize = 24;
+
+/// Entropy (in bits per character) from which a label looks like encoded data
+const RANDOM_LABEL_ENTROPY: f64 = 4.0;
+
+/// Number of unique subdomains of the same domain queried by a host to be suspicious
+const UNIQUE_SUBDOMAINS: usize = 50;
+
+/// Time window in which the unique subdomains are counted (µs)
+const UNIQUE_SUBDOMAINS_WINDOW: i64 = 600_000_000;
+
+/// Time after which the DNS activity of a host towards a domain,
+/// or a connection reported for its byte ratio, is forgotten if idle (µs)
+const IDLE_TIMEOUT: i64 = 3_600_000_000;
+
+/// Total size (in bytes) of the TXT and NULL records of a response to be suspicious
+/// (this is the maximum size of a classic DNS message)
+const LARGE_RECORDS_SIZE: usize = 512;
+
+/// Total volume (in bytes) exchanged by a connection for its byte ratio to be checked
+const SKEWED_FLOW_VOLUME: u128 = 10_000_000;
+
+/// Ratio between the outgoing and the incoming bytes of a connection to be suspicious
+const SKEWED_FLOW_RATIO: u128 = 20;
+
+const RECORD_TYPE_NULL: u16 = 10;
+const RECORD_TYPE_TXT: u16 = 16;
+
+/// Heuristic used to detect DNS tunnelling or data exfiltration
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExfiltrationHeuristic {
+    /// Long or high-entropy labels in the DNS queries
+    SuspiciousLabels,
+    /// Many unique subdomains queried under the same domain
+    UniqueSubdomains,
+    /// Large TXT and NULL records in the DNS responses
+    LargeRecords,
+    /// Connections sending much more than they receive, over a large volume
+    SkewedFlows,
+}
+
+impl ExfiltrationHeuristic {
+    pub const ALL: [ExfiltrationHeuristic; 4] = [
+        ExfiltrationHeuristic::SuspiciousLabels,
+        ExfiltrationHeuristic::UniqueSubdomains,
+        ExfiltrationHeuristic::LargeRecords,
+        ExfiltrationHeuristic::SkewedFlows,
+    ];
+
+    pub fn get_label(self, language: Language) -> &'static str {
+        match self {
+            ExfiltrationHeuristic::SuspiciousLabels => suspicious_labels_translation(language),
+            ExfiltrationHeuristic::UniqueSubdomains => unique_subdomains_translation(language),
+            ExfiltrationHeuristic::LargeRecords => large_records_translation(language),
+            ExfiltrationHeuristic::SkewedFlows => skewed_flows_translation(language),
+        }
+    }
+}
+
+/// Evidence of a possible DNS tunnel or data exfiltration
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExfiltrationEvidence {
+    /// A queried name containing a label looking like encoded data
+    SuspiciousLabel {
+        name: String,
+        /// Length of the longest label
+        label_len: usize,
+        /// Entropy of the longest label, in bits per character
+        entropy: f64,
+    },
+    /// Many unique subdomains of the given domain
+    UniqueSubdomains { parent: String, count: usize },
+    /// A response carrying large TXT or NULL records
+    LargeRecords {
+        name: String,
+        /// Type of the TXT or NULL records
+        record_type: u16,
+        /// Total size of the TXT and NULL records, in bytes
+        size: usize,
+    },
+    /// A connection sending much more than it receives
+    SkewedFlow {
+        remote_address: String,
+        remote_port: Option<u16>,
+        outgoing_bytes: u128,
+        incoming_bytes: u128,
+    },
+}
+
+impl ExfiltrationEvidence {
+    pub fn heuristic(&self) -> ExfiltrationHeuristic {
+        match self {
+            ExfiltrationEvidence::SuspiciousLabel { .. } => ExfiltrationHeuristic::SuspiciousLabels,
+            ExfiltrationEvidence::UniqueSubdomains { .. } => {
+                ExfiltrationHeuristic::UniqueSubdomains
+            }
+            ExfiltrationEvidence::LargeRecords { .. } => ExfiltrationHeuristic::LargeRecords,
+            ExfiltrationEvidence::SkewedFlow { .. } => ExfiltrationHeuristic::SkewedFlows,
+        }
+    }
+}
+
+/// A host suspected of DNS tunnelling or data exfiltration
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExfiltrationAlert {
+    /// Address of the suspected host
+    pub host: String,
+    pub evidence: ExfiltrationEvidence,
+}
+
+/// DNS activity of a host towards a domain
+#[derive(Default)]
+struct DomainActivity {
+    /// Unique subdomains queried in the last `UNIQUE_SUBDOMAINS_WINDOW`,
+    /// with the last time they were queried (cleared once reported)
+    subdomains: HashMap<String, i64>,
+    /// Heuristics already reported for this host and domain
+    reported: HashSet<ExfiltrationHeuristic>,
+    /// Last time this activity was updated (µs)
+    last_seen: i64,
+}
+
+/// Detects DNS tunnelling and data exfiltration.
+///
+/// Only the enabled heuristics are evaluated; each one is reported at most once
+/// for each host and domain (or for each connection).
+#[derive(Default)]
+pub struct ExfiltrationDetector {
+    /// DNS activity of each host towards each domain
+    domains: HashMap<(String, String), DomainActivity>,
+    /// Last time the idle activities were forgotten (µs)
+    last_eviction: i64,
+    /// Connections already reported for their byte ratio, with the last time they were checked
+    skewed_flows: HashMap<AddressPortPair, i64>,
+}
+
+impl ExfiltrationDetector {
+    /// Checks a DNS message exchanged between the endpoints of `key`,
+    /// captured at the given time (µs)
+    pub fn dns_message(
+        &mut self,
+        key: &AddressPortPair,
+        dns_message: &DnsMessage,
+        timestamp: i64,
+        enabled: &HashSet<ExfiltrationHeuristic>,
+    ) -> Vec<ExfiltrationAlert> {
+        let Some(question) = dns_message.questions.first() else {
+            return Vec::new();
+        };
+        self.evict_idle(timestamp);
+        let name = question.name.to_lowercase();
+        let tld = name
+            .trim_end_matches('.')
+            .rsplit('.')
+            .next()
+            .unwrap_or_default();
+        if LOCAL_TLDS.contains(&tld) {
+            return Vec::new();
+        }
+        let parent = parent_domain(&name).to_string();
+        let host = if dns_message.is_response {
+            &key.address2
+        } else {
+            &key.address1
+        };
+
+        let mut evidences = Vec::new();
+        if dns_message.is_response {
+            if key.port1 == Some(DNS_PORT) && enabled.contains(&ExfiltrationHeuristic::LargeRecords)
+            {
+                let records: Vec<_> = dns_message
+                    .answers
+                    .iter()
+                    .filter(|record| {
+                        matches!(record.record_type, RECORD_TYPE_TXT | RECORD_TYPE_NULL)
+                    })
+                    .collect();
+                let size: usize = records.iter().map(|record| record.data_len).sum();
+                if size >= LARGE_RECORDS_SIZE {
+                    evidences.push(ExfiltrationEvidence::LargeRecords {
+                        name,
+                        record_type: records[0].record_type,
+                        size,
+                    });
+                }
+            }
+        } else if let Some(subdomain) = name.strip_suffix(&parent).filter(|s| !s.is_empty()) {
+            if enabled.contains(&ExfiltrationHeuristic::SuspiciousLabels) {
+                if let Some((label_len, entropy)) = suspicious_label(subdomain) {
+                    evidences.push(ExfiltrationEvidence::SuspiciousLabel {
+                        name: name.clone(),
+                        label_len,
+                        entropy,
+                    });
+                }
+            }
+            if enabled.contains(&ExfiltrationHeuristic::UniqueSubdomains) {
+                let activity = self
+                    .domains
+                    .entry((host.clone(), parent.clone()))
+                    .or_default();
+                activity.last_seen = timestamp;
+                if !activity
+                    .reported
+                    .contains(&ExfiltrationHeuristic::UniqueSubdomains)
+                {
+                    activity.subdomains.retain(|_, last_queried| {
+                        timestamp - *last_queried < UNIQUE_SUBDOMAINS_WINDOW
+                    });
+                    activity.subdomains.insert(name, timestamp);
+                    if activity.subdomains.len() >= UNIQUE_SUBDOMAINS {
+                        evidences.push(ExfiltrationEvidence::UniqueSubdomains {
+                            parent: parent.clone(),
+                            count: activity.subdomains.len(),
+                        });
+                        activity.subdomains = HashMap::new();
+                    }
+                }
+            }
+        }
+        if evidences.is_empty() {
+            return Vec::new();
+        }
+
+        let activity = self.domains.entry((host.clone(), parent)).or_default();
+        activity.last_seen = timestamp;
+        evidences
+            .into_iter()
+            .filter(|evidence| activity.reported.insert(evidence.heuristic()))
+            .map(|evidence| ExfiltrationAlert {
+                host: host.clone(),
+                evidence,
+            })
+            .collect()
+    }
+
+    /// Forgets the DNS activities and the reported connections idle for too long,
+    /// at most once per `UNIQUE_SUBDOMAINS_WINDOW`
+    fn evict_idle(&mut self, timestamp: i64) {
+        if timestamp - self.last_eviction < UNIQUE_SUBDOMAINS_WINDOW {
+            return;
+        }
+        self.last_eviction = timestamp;
+        self.domains
+            .retain(|_, activity| timestamp - activity.last_seen < IDLE_TIMEOUT);
+        self.skewed_flows
+            .retain(|_, last_seen| timestamp - *last_seen < IDLE_TIMEOUT);
+    }
+
+    /// Checks the bytes exchanged by an outgoing connection, where `key` is the local
+    /// to remote direction, returning an alert if it sends much more than it receives.
+    ///
+    /// The incoming bytes are only computed if the outgoing ones are enough to be suspicious.
+    pub fn flow(
+        &mut self,
+        key: &AddressPortPair,
+        outgoing_bytes: u128,
+        incoming_bytes: impl FnOnce() -> u128,
+        timestamp: i64,
+        enabled: &HashSet<ExfiltrationHeuristic>,
+    ) -> Option<ExfiltrationAlert> {
+        // the volume can't be reached with a skewed ratio if too few bytes were sent
+        if !enabled.contains(&ExfiltrationHeuristic::SkewedFlows)
+            || outgoing_bytes * (SKEWED_FLOW_RATIO + 1) < SKEWED_FLOW_VOLUME * SKEWED_FLOW_RATIO
+        {
+            return None;
+        }
+        self.evict_idle(timestamp);
+        if let Some(last_seen) = self.skewed_flows.get_mut(key) {
+            *last_seen = timestamp;
+            return None;
+        }
+        let incoming_bytes = incoming_bytes();
+        if outgoing_bytes + incoming_bytes < SKEWED_FLOW_VOLUME
+            || outgoing_bytes < SKEWED_FLOW_RATIO * incoming_bytes
+        {
+            return None;
+        }
+        self.skewed_flows.insert(key.clone(), timestamp);
+        Some(ExfiltrationAlert {
+            host: key.address1.clone(),
+            evidence: ExfiltrationEvidence::SkewedFlow {
+                remote_address: key.address2.clone(),
+                remote_port: key.port2,
+                outgoing_bytes,
+                incoming_bytes,
+            },
+        })
+    }
+}
+
+/// Returns the domain owning the given name, made of its public suffix and of one more label
+///
+/// The public suffix is the longest one bundled, or the top-level domain if none matches.
+fn parent_domain(name: &str) -> &str {
+    let name = name.trim_end_matches('.');
+    let starts: Vec<usize> = std::iter::once(0)
+        .chain(name.match_indices('.').map(|(index, _)| index + 1))
+        .collect();
+    let suffix = starts
+        .iter()
+        .position(|start| SUFFIXES.contains(&name[*start..]))
+        .unwrap_or(starts.len() - 1);
+    match suffix.checked_sub(1) {
+        Some(label) => &name[starts[label]..],
+        None => name,
+    }
+}
+
+/// Returns the length and the entropy of the longest label of the given subdomain,
+/// if it looks like encoded data
+fn suspicious_label(subdomain: &str) -> Option<(usize, f64)> {
+    let label = subdomain.split('.').max_by_key(|label| label.len())?;
+    let entropy = entropy(label);
+    (label.len() >= LONG_LABEL_LEN
+        || (label.len() >= RANDOM_LABEL_LEN && entropy >= RANDOM_LABEL_ENTROPY))
+        .then_some((label.len(), entropy))
+}
+
+/// Shannon entropy of the characters of a label, in bits per character
+#[allow(clippy::cast_precision_loss)]
+fn entropy(label: &str) -> f64 {
+    let mut counts = [0_usize; 256];
+    for byte in label.bytes() {
+        counts[usize::from(byte)] += 1;
+    }
+    let len = label.len() as f64;
+    counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let p = *count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::dns_message::{DnsMessage, DnsQuestion};
+    use crate::networking::types::exfiltration::{
+        entropy, parent_domain, suspicious_label, ExfiltrationDetector, ExfiltrationEvidence,
+        ExfiltrationHeuristic, IDLE_TIMEOUT, UNIQUE_SUBDOMAINS, UNIQUE_SUBDOMAINS_WINDOW,
+    };
+    use crate::Protocol;
+
+    fn key(source: &str, destination: &str) -> AddressPortPair {
+        AddressPortPair::new(
+            source.to_string(),
+            Some(50000),
+            destination.to_string(),
+            Some(443),
+            Protocol::TCP,
+        )
+    }
+
+    #[test]
+    fn test_parent_domain() {
+        assert_eq!(parent_domain("www.example.com"), "example.com");
+        assert_eq!(parent_domain("a.b.c.example.com."), "example.com");
+        assert_eq!(parent_domain("example.com"), "example.com");
+        assert_eq!(parent_domain("localhost"), "localhost");
+        // public suffixes made of more than one label
+        assert_eq!(parent_domain("www.bbc.co.uk"), "bbc.co.uk");
+        assert_eq!(parent_domain("shop.example.com.au"), "example.com.au");
+        assert_eq!(parent_domain("co.uk"), "co.uk");
+        assert_eq!(
+            parent_domain("d111111abcdef8.cloudfront.net"),
+            "d111111abcdef8.cloudfront.net"
+        );
+    }
+
+    #[test]
+    fn test_unique_subdomains() {
+        let mut detector = ExfiltrationDetector::default();
+        let enabled = HashSet::from([ExfiltrationHeuristic::UniqueSubdomains]);
+        let key = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(50000),
+            "192.168.1.1".to_string(),
+            Some(53),
+            Protocol::UDP,
+        );
+        let mut query = |name: String, timestamp: i64, enabled: &HashSet<_>| {
+            let dns_message = DnsMessage {
+                id: 1,
+                is_response: false,
+                response_code: 0,
+                questions: vec![DnsQuestion {
+                    name,
+                    record_type: 1,
+                }],
+                answers: Vec::new(),
+            };
+            detector.dns_message(&key, &dns_message, timestamp, enabled)
+        };
+        // unrelated sites under the same public suffix
+        for i in 0..UNIQUE_SUBDOMAINS {
+            assert!(query(format!("www.site{i}.co.uk"), 0, &enabled).is_empty());
+        }
+        // subdomains spread over a time longer than the window
+        for i in 0..UNIQUE_SUBDOMAINS {
+            let timestamp = i64::try_from(i).unwrap() * UNIQUE_SUBDOMAINS_WINDOW / 10;
+            assert!(query(format!("n{i}.cdn.example.com"), timestamp, &enabled).is_empty());
+        }
+        // subdomains queried while the heuristic is disabled aren't reported later
+        for i in 0..UNIQUE_SUBDOMAINS {
+            assert!(query(format!("a{i}.tunnel.example.net"), 0, &HashSet::new()).is_empty());
+        }
+        for i in 1..UNIQUE_SUBDOMAINS {
+            assert!(query(format!("b{i}.tunnel.example.net"), 1, &enabled).is_empty());
+        }
+        let alerts = query("b0.tunnel.example.net".to_string(), 1, &enabled);
+        assert_eq!(
+            alerts[0].evidence,
+            ExfiltrationEvidence::UniqueSubdomains {
+                parent: "example.net".to_string(),
+                count: UNIQUE_SUBDOMAINS
+            }
+        );
+        // reported once for each host and domain
+        assert!(query("b50.tunnel.example.net".to_string(), 2, &enabled).is_empty());
+        // reverse lookups and mDNS names aren't counted
+        for i in 0..UNIQUE_SUBDOMAINS {
+            let name = format!("{i}.113.0.203.in-addr.arpa");
+            assert!(query(name, 3, &enabled).is_empty());
+            assert!(query(format!("device{i}.local"), 3, &enabled).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_suspicious_labels() {
+        assert!(entropy("aaaa") < 0.1);
+        assert!((entropy("abcd") - 2.0).abs() < 1e-9);
+        // ordinary names
+        for subdomain in [
+            "www",
+            "googleusercontent",
+            "accounts.login",
+            "thisisaverylongsubdomain",
+        ] {
+            assert!(suspicious_label(subdomain).is_none());
+        }
+        // base32 encoded data
+        let (len, entropy) = suspicious_label("mzxw6ytboi2dcnzvgu3tqojq.t").unwrap();
+        assert_eq!(len, 24);
+        assert!(entropy >= 4.0);
+        // very long labels are always suspicious
+        assert_eq!(suspicious_label(&"ab".repeat(26)).unwrap().0, 52);
+    }
+
+    #[test]
+    fn test_skewed_flows() {
+        let mut detector = ExfiltrationDetector::default();
+        let enabled = HashSet::from([ExfiltrationHeuristic::SkewedFlows]);
+        let key = key("192.168.1.10", "203.0.113.5");
+        // not enough volume, or not skewed enough
+        assert!(detector.flow(&key, 5_000_000, || 0, 0, &enabled).is_none());
+        assert!(detector
+            .flow(&key, 19_000_000, || 1_000_000, 0, &enabled)
+            .is_none());
+        // not enabled
+        assert!(detector
+            .flow(&key, 21_000_000, || 1_000_000, 0, &HashSet::new())
+            .is_none());
+
+        let alert = detector
+            .flow(&key, 21_000_000, || 1_000_000, 0, &enabled)
+            .unwrap();
+        assert_eq!(alert.host, "192.168.1.10");
+        assert_eq!(
+            alert.evidence,
+            ExfiltrationEvidence::SkewedFlow {
+                remote_address: "203.0.113.5".to_string(),
+                remote_port: Some(443),
+                outgoing_bytes: 21_000_000,
+                incoming_bytes: 1_000_000,
+            }
+        );
+        // reported once for each connection
+        assert!(detector
+            .flow(&key, 50_000_000, || 1_000_000, 1, &enabled)
+            .is_none());
+        // reported connections are forgotten when idle
+        assert!(detector
+            .flow(&key, 60_000_000, || 1_000_000, IDLE_TIMEOUT - 1, &enabled)
+            .is_none());
+        assert!(detector
+            .flow(&key, 70_000_000, || 1_000_000, 3 * IDLE_TIMEOUT, &enabled)
+            .is_some());
+    }
+}
@@ -41,7 +41,9 @@ impl Filters {
     /// Checks whether the filters match the current packet's protocols
     pub fn matches(&self, packet_filters_fields: &PacketFiltersFields) -> bool {
         self.ip_versions.contains(&packet_filters_fields.ip_version)
-            && self.protocols.contains(&packet_filters_fields.protocol)
+            && self
+                .protocols
+                .contains(&packet_filters_fields.protocol.filter_class())
             && (self
                 .address_collection
                 .contains(&packet_filters_fields.source)
@@ -105,8 +107,10 @@ pub fn pretty_print_ip(&self) -> String {
     }
 
     pub fn pretty_print_protocol(&self) -> String {
-        format!("{:?}", self.protocols)
-            .replace('{', "")
-            .replace('}', "")
+        self.protocols
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
     }
 }
@@ -4,7 +4,7 @@
 /// Struct to represent a network host
 #[derive(Default, PartialEq, Eq, Hash, Clone, Debug)]
 pub struct Host {
-    /// Hostname (domain). Obtained from the reverse DNS.
+    /// Hostname (domain). Obtained from the TLS server names, DNS responses, DHCP leases, or local name announcements seen on the wire or, if none, from the reverse DNS.
     pub domain: String,
     /// Autonomous System which operates the host
     pub asn: Asn,
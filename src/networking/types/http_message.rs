@@ -0,0 +1,187 @@
+//! Module defining the `HttpMessage` struct, used to extract the metadata of plain HTTP/1.x
+//! requests and responses from the TCP payloads.
+
+use std::collections::VecDeque;
+
+/// Maximum number of requests remembered for each connection (the oldest are discarded first)
+pub const MAX_HTTP_REQUESTS: usize = 10;
+
+const METHODS: [&str; 9] = [
+    "GET", "POST", "HEAD", "PUT", "DELETE", "OPTIONS", "PATCH", "CONNECT", "TRACE",
+];
+
+/// Metadata of a plain HTTP request, completed by the status code of its response
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// Request method (e.g., GET)
+    pub method: String,
+    /// Value of the `Host` header
+    pub host: Option<String>,
+    /// Request target
+    pub path: String,
+    /// Value of the `User-Agent` header
+    pub user_agent: Option<String>,
+    /// Status code of the response (this is None until the response is seen)
+    pub status: Option<u16>,
+}
+
+impl HttpRequest {
+    /// Lists the given requests from the most recent, each followed by its user agent
+    pub fn pretty_print_requests(requests: &VecDeque<HttpRequest>) -> String {
+        let mut ret_val = String::new();
+
+        for request in requests.iter().rev() {
+            let status = request
+                .status
+                .map_or_else(|| "-".to_string(), |status| status.to_string());
+            ret_val.push_str(&format!(
+                "   {} {}{} ({status})\n",
+                request.method,
+                request.host.as_deref().unwrap_or_default(),
+                request.path
+            ));
+            if let Some(user_agent) = &request.user_agent {
+                ret_val.push_str(&format!("      {user_agent}\n"));
+            }
+        }
+        ret_val
+    }
+}
+
+/// Start of a plain HTTP/1.x message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HttpMessage {
+    Request(HttpRequest),
+    Response {
+        /// Status code
+        status: u16,
+    },
+}
+
+impl HttpMessage {
+    /// Parses the start line and headers of an HTTP/1.x message at the beginning of a TCP payload.
+    ///
+    /// Headers truncated or carried by the following segments are ignored.
+    pub fn parse(payload: &[u8]) -> Option<Self> {
+        // cheap check to skip the payloads that can't be HTTP messages
+        if !payload.starts_with(b"HTTP/1.")
+            && !METHODS.iter().any(|method| {
+                payload.starts_with(method.as_bytes()) && payload.get(method.len()) == Some(&b' ')
+            })
+        {
+            return None;
+        }
+
+        let mut lines = payload.split(|byte| *byte == b'\n').map(|line| {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            line.escape_ascii().to_string()
+        });
+        let start_line = lines.next()?;
+        let mut parts = start_line.split(' ');
+
+        let first = parts.next()?;
+        if first.starts_with("HTTP/1.") {
+            let status = parts.next()?.parse().ok()?;
+            return Some(Self::Response { status });
+        }
+
+        if !METHODS.contains(&first) {
+            return None;
+        }
+        let path = parts.next()?;
+        if !parts.next()?.starts_with("HTTP/1.") {
+            return None;
+        }
+        let mut request = HttpRequest {
+            method: first.to_string(),
+            path: path.to_string(),
+            ..HttpRequest::default()
+        };
+        for line in lines.take_while(|line| !line.is_empty()) {
+            let Some((name, value)) = line.split_once(':') else {
+                continue;
+            };
+            if name.eq_ignore_ascii_case("host") {
+                request.host = Some(value.trim().to_string());
+            } else if name.eq_ignore_ascii_case("user-agent") {
+                request.user_agent = Some(value.trim().to_string());
+            }
+        }
+        Some(Self::Request(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::networking::types::http_message::{HttpMessage, HttpRequest};
+
+    #[test]
+    fn test_parse_request() {
+        let payload = b"GET /index.html?q=1 HTTP/1.1\r\nhost: example.com\r\nUser-Agent: curl/8.5.0\r\nAccept: */*\r\n\r\n";
+        assert_eq!(
+            HttpMessage::parse(payload),
+            Some(HttpMessage::Request(HttpRequest {
+                method: "GET".to_string(),
+                host: Some("example.com".to_string()),
+                path: "/index.html?q=1".to_string(),
+                user_agent: Some("curl/8.5.0".to_string()),
+                status: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_truncated_request() {
+        let payload = b"POST /upload HTTP/1.0\r\nContent-Length: 3\r\nHo";
+        assert_eq!(
+            HttpMessage::parse(payload),
+            Some(HttpMessage::Request(HttpRequest {
+                method: "POST".to_string(),
+                path: "/upload".to_string(),
+                ..HttpRequest::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            HttpMessage::parse(b"HTTP/1.1 404 Not Found\r\nServer: nginx\r\n\r\n"),
+            Some(HttpMessage::Response { status: 404 })
+        );
+        assert_eq!(HttpMessage::parse(b"HTTP/1.1 OK\r\n"), None);
+    }
+
+    #[test]
+    fn test_pretty_print_requests() {
+        let requests = VecDeque::from([
+            HttpRequest {
+                method: "GET".to_string(),
+                host: Some("example.com".to_string()),
+                path: "/".to_string(),
+                user_agent: Some("curl/8.5.0".to_string()),
+                status: Some(301),
+            },
+            HttpRequest {
+                method: "POST".to_string(),
+                path: "/form".to_string(),
+                ..HttpRequest::default()
+            },
+        ]);
+        assert_eq!(
+            HttpRequest::pretty_print_requests(&requests),
+            "   POST /form (-)\n   GET example.com/ (301)\n      curl/8.5.0\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_not_http() {
+        assert_eq!(HttpMessage::parse(b""), None);
+        assert_eq!(HttpMessage::parse(b"SSH-2.0-OpenSSH_9.6\r\n"), None);
+        assert_eq!(HttpMessage::parse(b"GET / SIP/2.0\r\n"), None);
+        assert_eq!(HttpMessage::parse(b"FETCH / HTTP/1.1\r\n"), None);
+        assert_eq!(HttpMessage::parse(b"GET /"), None);
+    }
+}
@@ -1,12 +1,18 @@
 //! Module defining the `InfoAddressPortPair` struct, useful to format the output report file and
 //! to keep track of statistics about the sniffed traffic.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 use chrono::{DateTime, Local};
 
+use crate::networking::types::http_message::HttpRequest;
 use crate::networking::types::icmp_type::IcmpType;
+use crate::networking::types::os_fingerprint::OsGuess;
+use crate::networking::types::process_info::ProcessInfo;
+use crate::networking::types::tcp_state::{TcpFlagsSeen, TcpMetrics, TcpState};
+use crate::networking::types::tls_client_hello::TlsClientHello;
 use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::networking::types::tunnel::Tunnel;
 use crate::Service;
 
 /// Struct useful to format the output report file and to keep track of statistics about the sniffed traffic.
@@ -28,8 +34,93 @@ pub struct InfoAddressPortPair {
     pub final_timestamp: DateTime<Local>,
     /// Upper layer service carried by the associated address:port pair.
     pub service: Service,
+    /// True if the service was identified from the payloads, false if it was guessed from the ports
+    pub service_detected: bool,
+    /// Number of payloads inspected so far to identify the service
+    pub inspected_payloads: u8,
     /// Determines if the connection is incoming or outgoing
     pub traffic_direction: TrafficDirection,
     /// Types of the ICMP messages exchanged, with the relative count (this is empty if not ICMP)
     pub icmp_types: HashMap<IcmpType, usize>,
+    /// Outer encapsulation of the connection (this is None if not tunnelled or if decapsulation is disabled)
+    pub tunnel: Option<Tunnel>,
+    /// Handshake and teardown flags exchanged (this is None if not TCP)
+    pub tcp_flags: Option<TcpFlagsSeen>,
+    /// Round-trip time and retransmission metrics of the segments sent by the source (this is None if not TCP)
+    pub tcp_metrics: Option<TcpMetrics>,
+    /// Server name and application protocols requested in the TLS ClientHello (this is None if not seen)
+    pub client_hello: Option<TlsClientHello>,
+    /// Last plain HTTP requests sent by the source, from the oldest to the most recent (this is empty if not HTTP)
+    pub http_requests: VecDeque<HttpRequest>,
+    /// Operating system guessed for the source (this is None if not guessed yet)
+    pub source_os: Option<OsGuess>,
+    /// Operating system guessed for the destination (this is None if not guessed yet)
+    pub destination_os: Option<OsGuess>,
+    /// Local process owning the socket of the connection (this is None if unknown or not on Linux)
+    pub process: Option<ProcessInfo>,
+    /// Name of the blocklist containing the remote address (this is None if not blocklisted)
+    pub blocklist: Option<String>,
+}
+
+impl InfoAddressPortPair {
+    /// Copies the fields needed while parsing each packet
+    pub fn snapshot(&self) -> InfoSnapshot {
+        InfoSnapshot {
+            transmitted_bytes: self.transmitted_bytes,
+            transmitted_packets: self.transmitted_packets,
+            service: self.service,
+            traffic_direction: self.traffic_direction,
+            has_process: self.process.is_some(),
+        }
+    }
+
+    /// State of the connection (this is None if not TCP)
+    pub fn tcp_state(&self) -> Option<TcpState> {
+        self.tcp_flags.as_ref().map(TcpFlagsSeen::state)
+    }
+
+    /// Average round-trip time in microseconds (this is None if not TCP or if no sample was measured)
+    pub fn average_rtt(&self) -> Option<i64> {
+        self.tcp_metrics
+            .as_ref()
+            .and_then(|metrics| metrics.rtt.average())
+    }
+
+    /// Server name requested in the TLS ClientHello (this is None if not seen)
+    pub fn server_name(&self) -> Option<&str> {
+        self.client_hello
+            .as_ref()
+            .and_then(|client_hello| client_hello.server_name.as_deref())
+    }
+
+    /// `Host` header of the most recent plain HTTP request (this is None if not seen)
+    pub fn http_host(&self) -> Option<&str> {
+        self.http_requests
+            .iter()
+            .rev()
+            .find_map(|request| request.host.as_deref())
+    }
+
+    /// Fraction of retransmitted segments (this is None if not TCP)
+    pub fn retransmission_rate(&self) -> Option<f32> {
+        self.tcp_metrics
+            .as_ref()
+            .map(TcpMetrics::retransmission_rate)
+    }
+}
+
+/// Copy of the `InfoAddressPortPair` fields read while parsing each packet,
+/// so that the whole entry doesn't need to be cloned.
+#[derive(Clone, Copy, Default)]
+pub struct InfoSnapshot {
+    /// Amount of bytes transmitted between the pair.
+    pub transmitted_bytes: u128,
+    /// Amount of packets transmitted between the pair.
+    pub transmitted_packets: u128,
+    /// Upper layer service carried by the associated address:port pair.
+    pub service: Service,
+    /// Determines if the connection is incoming or outgoing
+    pub traffic_direction: TrafficDirection,
+    /// True if the local process owning the socket is known
+    pub has_process: bool,
 }
@@ -4,11 +4,21 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::beaconing::Beacon;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dhcp_analytics::DhcpAnalytics;
+use crate::networking::types::dns_analytics::DnsAnalytics;
+use crate::networking::types::dns_names::DnsNames;
+use crate::networking::types::exfiltration::{ExfiltrationAlert, ExfiltrationHeuristic};
 use crate::networking::types::host::Host;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::networking::types::local_names::LocalNames;
+use crate::networking::types::os_fingerprint::OsGuess;
+use crate::networking::types::packet_samples::PacketSamples;
+use crate::networking::types::port_scan::PortScan;
 use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::notifications::types::alert_rules::RuleMatch;
 use crate::Service;
 
 /// Struct to be shared between the threads in charge of parsing packets and update reports.
@@ -35,10 +45,46 @@ pub struct InfoTraffic {
     pub favorites_last_interval: HashSet<Host>,
     /// Map of the upper layer services with their data info
     pub services: HashMap<Service, DataInfo>,
+    /// Map of the local applications (identified by process name) with their data info
+    pub apps: HashMap<String, DataInfo>,
+    /// Map of the local containers (identified by name) with their data info
+    pub containers: HashMap<String, DataInfo>,
     /// Map of the addresses waiting for a rDNS resolution; used to NOT send multiple rDNS for the same address
     pub addresses_waiting_resolution: HashMap<String, DataInfo>,
     /// Map of the resolved addresses with their full rDNS value and the corresponding host
     pub addresses_resolved: HashMap<String, (String, Host)>,
+    /// Map of the addresses with the domain name queried for them, as seen in DNS responses on the wire
+    pub dns_names: DnsNames,
+    /// Map of the addresses with the server name requested to them in TLS ClientHello messages
+    pub server_names: HashMap<String, String>,
+    /// Statistics about the DNS transactions seen on the wire
+    pub dns_analytics: DnsAnalytics,
+    /// Map of the addresses with the host name declared by the DHCP client they were leased to
+    pub dhcp_names: HashMap<String, String>,
+    /// Leases and servers seen in the DHCP exchanges on the wire
+    pub dhcp_analytics: DhcpAnalytics,
+    /// Names and services announced by local devices over mDNS, LLMNR, and NetBIOS
+    pub local_names: LocalNames,
+    /// Map of the addresses with the operating system guessed from their TCP handshake segments
+    pub os_guesses: HashMap<String, OsGuess>,
+    /// First packets of the filtered connections, kept to inspect their bytes
+    pub packet_samples: PacketSamples,
+    /// Map of the remote addresses matched by a blocklist, with the name of the list
+    pub blocklisted_addresses: HashMap<String, String>,
+    /// Remote addresses matched by a blocklist in the last interval, with the name of the list
+    pub blocklist_matches_last_interval: Vec<(String, String)>,
+    /// Port scans detected in the last interval
+    pub port_scans_last_interval: Vec<PortScan>,
+    /// Map of the remote addresses with their traffic periodicity
+    pub beacons: HashMap<String, Beacon>,
+    /// Remote addresses found to be contacted at regular intervals in the last interval
+    pub beacons_last_interval: Vec<(String, Beacon)>,
+    /// Heuristics of DNS tunnelling and data exfiltration enabled in the notification settings
+    pub exfiltration_heuristics: HashSet<ExfiltrationHeuristic>,
+    /// Hosts suspected of DNS tunnelling or data exfiltration in the last interval
+    pub exfiltration_last_interval: Vec<ExfiltrationAlert>,
+    /// Custom alert rules matched in the last interval
+    pub rule_matches_last_interval: Vec<RuleMatch>,
     /// Map of the hosts with their data info
     pub hosts: HashMap<Host, DataInfoHost>,
 }
@@ -58,8 +104,26 @@ pub fn new() -> Self {
             favorite_hosts: HashSet::new(),
             favorites_last_interval: HashSet::new(),
             services: HashMap::new(),
+            apps: HashMap::new(),
+            containers: HashMap::new(),
             addresses_waiting_resolution: HashMap::new(),
             addresses_resolved: HashMap::new(),
+            dns_names: DnsNames::default(),
+            server_names: HashMap::new(),
+            dns_analytics: DnsAnalytics::default(),
+            dhcp_names: HashMap::new(),
+            dhcp_analytics: DhcpAnalytics::default(),
+            local_names: LocalNames::default(),
+            os_guesses: HashMap::new(),
+            packet_samples: PacketSamples::default(),
+            blocklisted_addresses: HashMap::new(),
+            blocklist_matches_last_interval: Vec::new(),
+            port_scans_last_interval: Vec::new(),
+            beacons: HashMap::new(),
+            beacons_last_interval: Vec::new(),
+            exfiltration_heuristics: HashSet::new(),
+            exfiltration_last_interval: Vec::new(),
+            rule_matches_last_interval: Vec::new(),
             hosts: HashMap::new(),
         }
     }
@@ -0,0 +1,118 @@
+//! Module defining the `LocalNames` struct, which collects the names and the services announced
+//! by local devices over mDNS, LLMNR, and the NetBIOS Name Service.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// Protocol a local device announced its name with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NameProtocol {
+    /// NetBIOS Name Service (names are uppercase and truncated to 15 characters)
+    Nbns,
+    /// Link-Local Multicast Name Resolution
+    Llmnr,
+    /// Multicast DNS
+    Mdns,
+}
+
+impl fmt::Display for NameProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let protocol = match self {
+            NameProtocol::Nbns => "NetBIOS",
+            NameProtocol::Llmnr => "LLMNR",
+            NameProtocol::Mdns => "mDNS",
+        };
+        write!(f, "{protocol}")
+    }
+}
+
+/// A local device, with the name and the services it announced
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocalDevice {
+    /// Announced name, with the protocol it was announced with
+    pub name: Option<(String, NameProtocol)>,
+    /// Advertised mDNS service types (e.g., `_ipp._tcp`)
+    pub services: BTreeSet<String>,
+}
+
+/// Names and services announced by local devices
+#[derive(Clone, Default, Debug)]
+pub struct LocalNames {
+    /// Devices, identified by their address
+    pub devices: HashMap<String, LocalDevice>,
+}
+
+impl LocalNames {
+    /// Remembers the name announced for an address.
+    ///
+    /// Names announced over a protocol with lower precedence (NetBIOS, then LLMNR, then mDNS)
+    /// don't replace the existing ones.
+    /// Returns true if the name of the device changed.
+    pub fn add_name(&mut self, address: &str, name: &str, protocol: NameProtocol) -> bool {
+        let device = self.devices.entry(address.to_string()).or_default();
+        match &device.name {
+            Some((current_name, current_protocol))
+                if current_name == name || *current_protocol > protocol =>
+            {
+                false
+            }
+            _ => {
+                device.name = Some((name.to_string(), protocol));
+                true
+            }
+        }
+    }
+
+    /// Remembers the mDNS service types advertised by an address
+    pub fn add_services(&mut self, address: &str, services: Vec<String>) {
+        if services.is_empty() {
+            return;
+        }
+        self.devices
+            .entry(address.to_string())
+            .or_default()
+            .services
+            .extend(services);
+    }
+
+    /// Returns the name announced for an address, if any
+    pub fn name(&self, address: &str) -> Option<&String> {
+        self.devices
+            .get(address)
+            .and_then(|device| device.name.as_ref().map(|(name, _)| name))
+    }
+
+    /// Returns the devices sorted by address
+    pub fn sorted_devices(&self) -> Vec<(&String, &LocalDevice)> {
+        let mut devices: Vec<(&String, &LocalDevice)> = self.devices.iter().collect();
+        devices.sort_by_key(|(address, _)| *address);
+        devices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::local_names::{LocalNames, NameProtocol};
+
+    #[test]
+    fn test_name_precedence() {
+        let mut local_names = LocalNames::default();
+        assert!(local_names.add_name("192.168.1.42", "DESKTOP-7QK2M", NameProtocol::Nbns));
+        assert!(!local_names.add_name("192.168.1.42", "DESKTOP-7QK2M", NameProtocol::Nbns));
+        assert!(local_names.add_name("192.168.1.42", "desktop-7qk2m", NameProtocol::Llmnr));
+        assert!(!local_names.add_name("192.168.1.42", "DESKTOP-7QK2M", NameProtocol::Nbns));
+        assert!(local_names.add_name("192.168.1.42", "alice-pc.local", NameProtocol::Mdns));
+        assert_eq!(
+            local_names.name("192.168.1.42"),
+            Some(&"alice-pc.local".to_string())
+        );
+        assert_eq!(local_names.name("192.168.1.43"), None);
+
+        local_names.add_services("192.168.1.43", vec!["_ipp._tcp".to_string()]);
+        local_names.add_services("192.168.1.43", vec!["_ipp._tcp".to_string()]);
+        assert_eq!(local_names.name("192.168.1.43"), None);
+        let devices = local_names.sorted_devices();
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[1].1.services.len(), 1);
+    }
+}
@@ -1,22 +1,44 @@
 pub mod address_port_pair;
 pub mod asn;
+pub mod beaconing;
+pub mod blocklist;
 pub mod byte_multiple;
 pub mod capture_context;
+pub mod container_info;
 pub mod data_info;
 pub mod data_info_host;
+pub mod dhcp_analytics;
+pub mod dhcp_message;
+pub mod dns_analytics;
+pub mod dns_message;
+pub mod dns_names;
+pub mod exfiltration;
 pub mod filters;
 pub mod host;
+pub mod http_message;
 pub mod icmp_type;
 pub mod info_address_port_pair;
 pub mod info_traffic;
 pub mod ip_collection;
 pub mod ip_version;
+pub mod local_names;
 pub mod my_device;
 pub mod my_link_type;
+pub mod nbns_message;
+pub mod network_namespace;
+pub mod os_fingerprint;
 pub mod packet_filters_fields;
+pub mod packet_samples;
+pub mod payload_signature;
 pub mod port_collection;
+pub mod port_scan;
+pub mod process_info;
 pub mod protocol;
+pub mod quic_initial;
 pub mod service;
 pub mod service_query;
+pub mod tcp_state;
+pub mod tls_client_hello;
 pub mod traffic_direction;
 pub mod traffic_type;
+pub mod tunnel;
@@ -3,10 +3,12 @@
 use pcap::{Address, Device, DeviceFlags};
 
 use crate::networking::types::my_link_type::MyLinkType;
+use crate::networking::types::network_namespace::{list_devices, NetworkNamespace};
 
 /// Represents the current inspected device.
 /// Used to keep in sync the device addresses in case of changes
-/// (e.g., device not connected to the internet acquires new IP address)
+/// (e.g., device not connected to the internet acquires new IP address);
+/// they are read again when the adapter is selected and when a capture starts
 #[derive(Clone)]
 pub struct MyDevice {
     pub name: String,
@@ -14,11 +16,25 @@ pub struct MyDevice {
     pub desc: Option<String>,
     pub addresses: Arc<Mutex<Vec<Address>>>,
     pub link_type: MyLinkType,
+    /// Network namespace of the device (this is None for the namespace of Sniffnet itself)
+    pub netns: Option<NetworkNamespace>,
 }
 
 impl MyDevice {
+    /// Reads again the addresses of the device, and returns them
+    pub fn refresh_addresses(&self) -> Vec<Address> {
+        let mut addresses = self.addresses.lock().unwrap();
+        if let Some(device) = list_devices(self.netns.as_ref())
+            .into_iter()
+            .find(|device| device.name.eq(&self.name))
+        {
+            *addresses = device.addresses;
+        }
+        addresses.clone()
+    }
+
     pub fn to_pcap_device(&self) -> Device {
-        for device in Device::list().unwrap_or_default() {
+        for device in list_devices(self.netns.as_ref()) {
             if device.name.eq(&self.name) {
                 return device;
             }
@@ -16,6 +16,9 @@ pub enum MyLinkType {
     Loop(Linktype),
     IPv4(Linktype),
     IPv6(Linktype),
+    LinuxSll(Linktype),
+    LinuxSll2(Linktype),
+    Ieee80211Radiotap(Linktype),
     Unsupported(Linktype),
     #[default]
     NotYetAssigned,
@@ -34,6 +37,9 @@ pub fn from_pcap_link_type(link_type: Linktype) -> Self {
             Linktype::LOOP => Self::Loop(link_type),
             Linktype::IPV4 => Self::IPv4(link_type),
             Linktype::IPV6 => Self::IPv6(link_type),
+            Linktype::LINUX_SLL => Self::LinuxSll(link_type),
+            Linktype::LINUX_SLL2 => Self::LinuxSll2(link_type),
+            Linktype::IEEE802_11_RADIOTAP => Self::Ieee80211Radiotap(link_type),
             _ => Self::Unsupported(link_type),
         }
     }
@@ -46,6 +52,9 @@ pub fn full_print_on_one_line(self, language: Language) -> String {
             | Self::Loop(l)
             | Self::IPv4(l)
             | Self::IPv6(l)
+            | Self::LinuxSll(l)
+            | Self::LinuxSll2(l)
+            | Self::Ieee80211Radiotap(l)
             | Self::Unsupported(l) => {
                 format!(
                     "{}: {} ({})",
@@ -70,6 +79,9 @@ pub fn link_type_col(
             | Self::Loop(l)
             | Self::IPv4(l)
             | Self::IPv6(l)
+            | Self::LinuxSll(l)
+            | Self::LinuxSll2(l)
+            | Self::Ieee80211Radiotap(l)
             | Self::Unsupported(l) => {
                 let link_info = format!(
                     "{} ({})",
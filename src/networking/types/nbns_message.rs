@@ -0,0 +1,237 @@
+//! Module defining the `NbnsMessage` struct, which contains the names bound to addresses
+//! by a NetBIOS Name Service message sniffed on the wire.
+
+use std::net::{IpAddr, Ipv4Addr};
+
+/// Port used by the NetBIOS Name Service
+pub const NBNS_PORT: u16 = 137;
+
+/// Names bound to addresses by a NetBIOS Name Service message
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NbnsMessage {
+    /// Unique names of workstations and servers, each with the address it's bound to
+    pub names: Vec<(IpAddr, String)>,
+}
+
+impl NbnsMessage {
+    const OPCODE_QUERY: u16 = 0;
+    const OPCODE_REGISTRATION: u16 = 5;
+    const OPCODE_REFRESH: u16 = 8;
+    const OPCODE_REFRESH_ALT: u16 = 9;
+
+    const RECORD_TYPE_NB: u16 = 0x20;
+    const GROUP_FLAG: u16 = 0x8000;
+
+    const SUFFIX_WORKSTATION: u8 = 0x00;
+    const SUFFIX_SERVER: u8 = 0x20;
+
+    /// Parses a NetBIOS Name Service message carried by UDP.
+    ///
+    /// Only registrations, refreshes, and positive query responses bind names to addresses;
+    /// the records following a malformed one are ignored.
+    /// Returns None if even the header or the question section can't be parsed.
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let read_u16 = |offset: usize| -> Option<u16> {
+            Some(u16::from_be_bytes(
+                bytes.get(offset..offset + 2)?.try_into().ok()?,
+            ))
+        };
+
+        if bytes.len() < 12 {
+            return None;
+        }
+        let flags = read_u16(2)?;
+        let [questions_count, answers_count, authorities_count, additionals_count] =
+            [4, 6, 8, 10].map(|offset| usize::from(read_u16(offset).unwrap_or_default()));
+        let is_response = flags & 0x8000 != 0;
+        let opcode = (flags >> 11) & 0x0f;
+        let response_code = flags & 0x0f;
+        let binds_names = match opcode {
+            Self::OPCODE_QUERY => is_response,
+            Self::OPCODE_REGISTRATION | Self::OPCODE_REFRESH | Self::OPCODE_REFRESH_ALT => true,
+            _ => false,
+        };
+
+        let mut offset = 12;
+        for _ in 0..questions_count {
+            offset = read_name(bytes, offset)?.1 + 4;
+        }
+
+        let mut message = Self::default();
+        if !binds_names || response_code != 0 {
+            return Some(message);
+        }
+        for _ in 0..answers_count + authorities_count + additionals_count {
+            let Some((name, next)) = read_name(bytes, offset) else {
+                break;
+            };
+            let Some(fixed) = bytes.get(next..next + 10) else {
+                break;
+            };
+            let record_type = u16::from_be_bytes([fixed[0], fixed[1]]);
+            let data_len = usize::from(u16::from_be_bytes([fixed[8], fixed[9]]));
+            let Some(data) = bytes.get(next + 10..next + 10 + data_len) else {
+                break;
+            };
+            offset = next + 10 + data_len;
+
+            let Some(name) = name.filter(|_| record_type == Self::RECORD_TYPE_NB) else {
+                continue;
+            };
+            // each entry is made of the name flags and of an IPv4 address
+            for entry in data.chunks_exact(6) {
+                let nb_flags = u16::from_be_bytes([entry[0], entry[1]]);
+                let address = Ipv4Addr::new(entry[2], entry[3], entry[4], entry[5]);
+                if nb_flags & Self::GROUP_FLAG == 0 && !address.is_unspecified() {
+                    message.names.push((IpAddr::V4(address), name.clone()));
+                }
+            }
+        }
+        Some(message)
+    }
+}
+
+/// Reads the NetBIOS name starting at `offset`, following at most a compression pointer.
+///
+/// Returns the name (only for workstations and servers) and the offset of the first byte after it.
+fn read_name(bytes: &[u8], offset: usize) -> Option<(Option<String>, usize)> {
+    let first = *bytes.get(offset)?;
+    if first & 0xc0 == 0xc0 {
+        let target = usize::from(u16::from_be_bytes([first & 0x3f, *bytes.get(offset + 1)?]));
+        let (name, _) = read_encoded_name(bytes, target)?;
+        return Some((name, offset + 2));
+    }
+    read_encoded_name(bytes, offset)
+}
+
+/// Decodes a name in first-level encoding (each half-byte is mapped to a letter from `A` to `P`),
+/// skipping the scope identifier that may follow it
+fn read_encoded_name(bytes: &[u8], offset: usize) -> Option<(Option<String>, usize)> {
+    if bytes.get(offset) != Some(&32) {
+        return None;
+    }
+    let encoded = bytes.get(offset + 1..offset + 33)?;
+    let mut decoded = [0u8; 16];
+    for (byte, pair) in decoded.iter_mut().zip(encoded.chunks_exact(2)) {
+        let [high, low] = [pair[0], pair[1]].map(|c| c.wrapping_sub(b'A'));
+        if high > 0x0f || low > 0x0f {
+            return None;
+        }
+        *byte = (high << 4) | low;
+    }
+
+    let mut end = offset + 33;
+    loop {
+        match *bytes.get(end)? {
+            0 => {
+                end += 1;
+                break;
+            }
+            len if len & 0xc0 == 0xc0 => {
+                end += 2;
+                break;
+            }
+            len => end += 1 + usize::from(len),
+        }
+    }
+
+    let (name, suffix) = (&decoded[..15], decoded[15]);
+    let name = String::from_utf8_lossy(name).trim_end().to_string();
+    let is_host = matches!(
+        suffix,
+        NbnsMessage::SUFFIX_WORKSTATION | NbnsMessage::SUFFIX_SERVER
+    );
+    let is_valid = !name.is_empty() && name.chars().all(|c| c.is_ascii_graphic());
+    Some(((is_host && is_valid && name != "*").then_some(name), end))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use crate::networking::types::nbns_message::NbnsMessage;
+
+    fn encode(name: &str, suffix: u8) -> Vec<u8> {
+        let mut padded = format!("{name:<15}").into_bytes();
+        padded.push(suffix);
+        let mut bytes = vec![32];
+        for byte in padded {
+            bytes.extend_from_slice(&[b'A' + (byte >> 4), b'A' + (byte & 0x0f)]);
+        }
+        bytes.push(0);
+        bytes
+    }
+
+    fn registration(name: &str, suffix: u8, nb_flags: [u8; 2]) -> Vec<u8> {
+        let mut bytes = vec![0x80, 0x01, 0x29, 0x10, 0, 1, 0, 0, 0, 0, 0, 1];
+        bytes.extend(encode(name, suffix));
+        bytes.extend_from_slice(&[0, 0x20, 0, 1]);
+        bytes.extend_from_slice(&[0xc0, 0x0c, 0, 0x20, 0, 1, 0, 0x04, 0x93, 0xe0, 0, 6]);
+        bytes.extend_from_slice(&nb_flags);
+        bytes.extend_from_slice(&[192, 168, 1, 42]);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_nbns_registration() {
+        assert_eq!(
+            NbnsMessage::parse(&registration("DESKTOP-7QK2M", 0x00, [0x60, 0])),
+            Some(NbnsMessage {
+                names: vec![(IpAddr::from([192, 168, 1, 42]), "DESKTOP-7QK2M".to_string())]
+            })
+        );
+        // group names and other suffixes (e.g., domain controllers) don't identify hosts
+        assert_eq!(
+            NbnsMessage::parse(&registration("WORKGROUP", 0x00, [0xe0, 0])),
+            Some(NbnsMessage::default())
+        );
+        assert_eq!(
+            NbnsMessage::parse(&registration("DESKTOP-7QK2M", 0x1c, [0x60, 0])),
+            Some(NbnsMessage::default())
+        );
+    }
+
+    #[test]
+    fn test_parse_nbns_huge_counts() {
+        let mut bytes = registration("DESKTOP-7QK2M", 0x00, [0x60, 0]);
+        // the record counts would overflow if summed as 16-bit integers
+        bytes[6..12].copy_from_slice(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(
+            NbnsMessage::parse(&bytes),
+            Some(NbnsMessage {
+                names: vec![(IpAddr::from([192, 168, 1, 42]), "DESKTOP-7QK2M".to_string())]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_nbns_query() {
+        let mut query = vec![0x12, 0x34, 0x01, 0x10, 0, 1, 0, 0, 0, 0, 0, 0];
+        query.extend(encode("NAS", 0x20));
+        query.extend_from_slice(&[0, 0x20, 0, 1]);
+        assert_eq!(NbnsMessage::parse(&query), Some(NbnsMessage::default()));
+
+        // positive response, with the full name in the answer
+        let mut response = vec![0x12, 0x34, 0x85, 0x00, 0, 0, 0, 1, 0, 0, 0, 0];
+        response.extend(encode("NAS", 0x20));
+        response.extend_from_slice(&[0, 0x20, 0, 1, 0, 0x04, 0x93, 0xe0, 0, 12]);
+        response.extend_from_slice(&[0, 0, 192, 168, 1, 5, 0, 0, 10, 0, 0, 5]);
+        assert_eq!(
+            NbnsMessage::parse(&response),
+            Some(NbnsMessage {
+                names: vec![
+                    (IpAddr::from([192, 168, 1, 5]), "NAS".to_string()),
+                    (IpAddr::from([10, 0, 0, 5]), "NAS".to_string())
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_not_nbns() {
+        assert_eq!(NbnsMessage::parse(&[0; 5]), None);
+        let mut bytes = registration("DESKTOP-7QK2M", 0x00, [0x60, 0]);
+        bytes[13] = b'Z';
+        assert_eq!(NbnsMessage::parse(&bytes), None);
+    }
+}
@@ -0,0 +1,146 @@
+//! Module defining the `NetworkNamespace` struct, used to capture in the network namespace
+//! of another process (e.g., a container) on Linux.
+
+use std::fmt;
+
+use pcap::Device;
+
+/// A network namespace, entered through one of the processes living in it
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NetworkNamespace {
+    /// Inode identifying the namespace
+    pub inode: u64,
+    /// Process living in the namespace
+    pub pid: u32,
+    /// Name of the namespace (the container name, or the process name)
+    pub name: String,
+}
+
+impl fmt::Display for NetworkNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (net:[{}])", self.name, self.inode)
+    }
+}
+
+impl NetworkNamespace {
+    /// Returns the network namespaces other than the one of this process, sorted by name
+    #[cfg(target_os = "linux")]
+    pub fn list() -> Vec<Self> {
+        use std::collections::HashMap;
+        use std::fs;
+
+        use crate::networking::types::container_info::ContainerInfo;
+
+        let own_inode = namespace_inode("/proc/self/ns/net");
+        let mut namespaces: HashMap<u64, Self> = HashMap::new();
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return Vec::new();
+        };
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|pid| pid.parse().ok()) else {
+                continue;
+            };
+            let Some(inode) = namespace_inode(&format!("/proc/{pid}/ns/net")) else {
+                continue;
+            };
+            if Some(inode) == own_inode || namespaces.contains_key(&inode) {
+                continue;
+            }
+            let name = fs::read_to_string(entry.path().join("cgroup"))
+                .ok()
+                .and_then(|cgroup| ContainerInfo::from_cgroup(&cgroup))
+                .map(|container| container.name)
+                .or_else(|| {
+                    fs::read_to_string(entry.path().join("comm"))
+                        .ok()
+                        .map(|name| name.trim_end().to_string())
+                })
+                .unwrap_or_default();
+            namespaces.insert(inode, Self { inode, pid, name });
+        }
+        let mut namespaces: Vec<Self> = namespaces.into_values().collect();
+        namespaces.sort_by(|a, b| a.name.cmp(&b.name).then(a.inode.cmp(&b.inode)));
+        namespaces
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn list() -> Vec<Self> {
+        Vec::new()
+    }
+
+    /// Runs the given function in a thread that joined this network namespace
+    /// (this requires the `CAP_SYS_ADMIN` capability)
+    #[cfg(target_os = "linux")]
+    pub fn run_in<T: Send>(&self, f: impl FnOnce() -> T + Send) -> Result<T, String> {
+        use std::fs::File;
+        use std::os::fd::AsRawFd;
+
+        let path = format!("/proc/{}/ns/net", self.pid);
+        if namespace_inode(&path) != Some(self.inode) {
+            return Err(format!("network namespace {} doesn't exist anymore", self));
+        }
+        let file = File::open(&path).map_err(|e| e.to_string())?;
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    // SAFETY: the file descriptor is valid, and only this thread joins the namespace
+                    if unsafe { libc::setns(file.as_raw_fd(), libc::CLONE_NEWNET) } != 0 {
+                        return Err(std::io::Error::last_os_error().to_string());
+                    }
+                    Ok(f())
+                })
+                .join()
+                .map_err(|_| "network namespace thread panicked".to_string())?
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn run_in<T: Send>(&self, _f: impl FnOnce() -> T + Send) -> Result<T, String> {
+        Err("network namespaces are only supported on Linux".to_string())
+    }
+}
+
+/// Returns the network devices of the given namespace (or of the current one, if None)
+pub fn list_devices(netns: Option<&NetworkNamespace>) -> Vec<Device> {
+    match netns {
+        None => Device::list().unwrap_or_default(),
+        Some(netns) => netns
+            .run_in(|| Device::list().unwrap_or_default())
+            .unwrap_or_default(),
+    }
+}
+
+/// Returns the inode of the namespace linked by the given path (e.g., `net:[4026531840]`)
+#[cfg(target_os = "linux")]
+pub fn namespace_inode(path: &str) -> Option<u64> {
+    let link = std::fs::read_link(path).ok()?;
+    link.to_str()?
+        .strip_prefix("net:[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::network_namespace::NetworkNamespace;
+
+    #[test]
+    fn test_network_namespace_display() {
+        let netns = NetworkNamespace {
+            inode: 4_026_532_561,
+            pid: 4242,
+            name: "web".to_string(),
+        };
+        assert_eq!(netns.to_string(), "web (net:[4026532561])");
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_own_namespace_not_listed() {
+        let own_inode = super::namespace_inode("/proc/self/ns/net").unwrap();
+        assert!(NetworkNamespace::list()
+            .iter()
+            .all(|netns| netns.inode != own_inode));
+    }
+}
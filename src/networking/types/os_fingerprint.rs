@@ -0,0 +1,376 @@
+//! Module implementing the passive OS fingerprinting of hosts from the TCP handshake segments they send,
+//! matched against the bundled signatures (inspired by the p0f v3 database).
+
+use etherparse::TcpHeader;
+use once_cell::sync::Lazy;
+
+/// Bundled OS signatures (see the file header for a description of the format)
+const OS_SIGNATURES: &str = include_str!("../../../resources/DB/os_signatures.txt");
+
+static SIGNATURES: Lazy<Vec<OsSignature>> = Lazy::new(|| {
+    OS_SIGNATURES
+        .lines()
+        .filter_map(OsSignature::parse)
+        .collect()
+});
+
+/// Guesses with a lower confidence are discarded
+const MIN_CONFIDENCE: u8 = 50;
+
+/// Maximum number of TCP options taken into account
+const MAX_OPTIONS: usize = 20;
+
+/// Values of the IP header and of the TCP handshake segment relevant to fingerprint the sender
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct TcpSignature {
+    /// True if the segment is a SYN+ACK (i.e., the sender accepted a connection)
+    pub syn_ack: bool,
+    /// Observed TTL (or hop limit)
+    pub ttl: u8,
+    /// Window size
+    pub window: u16,
+    /// Maximum segment size option
+    pub mss: Option<u16>,
+    /// Window scale option
+    pub window_scale: Option<u8>,
+    /// Kinds of the options, in the order they appear (only the first `options_len` are valid)
+    options: [u8; MAX_OPTIONS],
+    options_len: usize,
+}
+
+impl TcpSignature {
+    const OPTION_EOL: u8 = 0;
+    const OPTION_NOP: u8 = 1;
+    const OPTION_MSS: u8 = 2;
+    const OPTION_WS: u8 = 3;
+
+    /// Returns the signature of a SYN or SYN+ACK segment (None for other segments)
+    pub fn from_etherparse(tcp_header: &TcpHeader, ttl: u8) -> Option<Self> {
+        if !tcp_header.syn {
+            return None;
+        }
+        let mut signature = Self {
+            syn_ack: tcp_header.ack,
+            ttl,
+            window: tcp_header.window_size,
+            ..Self::default()
+        };
+        let mut bytes = tcp_header.options.as_slice();
+        while let Some((&kind, rest)) = bytes.split_first() {
+            if signature.options_len == MAX_OPTIONS {
+                break;
+            }
+            signature.options[signature.options_len] = kind;
+            signature.options_len += 1;
+            match kind {
+                Self::OPTION_EOL => break,
+                Self::OPTION_NOP => {
+                    bytes = rest;
+                    continue;
+                }
+                _ => {}
+            }
+            let Some(len) = rest
+                .first()
+                .map(|len| usize::from(*len))
+                .filter(|len| *len >= 2)
+            else {
+                break;
+            };
+            let Some(data) = bytes.get(2..len) else {
+                break;
+            };
+            match (kind, data) {
+                (Self::OPTION_MSS, [high, low]) => {
+                    signature.mss = Some(u16::from_be_bytes([*high, *low]));
+                }
+                (Self::OPTION_WS, [scale]) => signature.window_scale = Some(*scale),
+                _ => {}
+            }
+            bytes = &bytes[len..];
+        }
+        Some(signature)
+    }
+
+    /// Returns the TCP options, in the order they appear and named as in the signatures file
+    pub fn options_layout(&self) -> String {
+        self.options[..self.options_len]
+            .iter()
+            .map(|kind| match kind {
+                0 => "eol".to_string(),
+                1 => "nop".to_string(),
+                2 => "mss".to_string(),
+                3 => "ws".to_string(),
+                4 => "sok".to_string(),
+                5 => "sack".to_string(),
+                8 => "ts".to_string(),
+                other => format!("?{other}"),
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    /// Returns the initial TTL, assuming the sender used one of the common defaults
+    pub fn initial_ttl(&self) -> u8 {
+        match self.ttl {
+            0..=32 => 32,
+            33..=64 => 64,
+            65..=128 => 128,
+            _ => 255,
+        }
+    }
+
+    /// Returns the estimated number of hops from the sender
+    pub fn hop_distance(&self) -> u8 {
+        self.initial_ttl() - self.ttl
+    }
+
+    /// Guesses the operating system of the sender, returning None if no signature is close enough
+    pub fn guess_os(&self) -> Option<OsGuess> {
+        let options_layout = self.options_layout();
+        SIGNATURES
+            .iter()
+            .filter(|signature| {
+                signature.syn_ack == self.syn_ack
+                    && signature.initial_ttl == self.initial_ttl()
+                    && signature.options_layout == options_layout
+            })
+            .map(|signature| (signature.label, signature.confidence(self)))
+            // the first signature wins among the ones with the same confidence
+            .rev()
+            .max_by_key(|(_, confidence)| *confidence)
+            .filter(|(_, confidence)| *confidence >= MIN_CONFIDENCE)
+            .map(|(os, confidence)| OsGuess {
+                os,
+                confidence,
+                hop_distance: self.hop_distance(),
+            })
+    }
+}
+
+/// Operating system guessed for a host
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OsGuess {
+    /// Operating system, as labelled in the signatures file
+    pub os: &'static str,
+    /// Confidence of the guess, in percent
+    pub confidence: u8,
+    /// Estimated number of hops from the host
+    pub hop_distance: u8,
+}
+
+/// Value of a signature field
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Field<T> {
+    Any,
+    Absent,
+    Exactly(T),
+    /// Multiple of the maximum segment size (window size only)
+    MssMultiple(u16),
+}
+
+impl<T: PartialEq> Field<T> {
+    /// Returns the penalty of the given value for this field, whose maximum is `weight`
+    fn penalty(&self, value: Option<T>, weight: u8) -> u8 {
+        match (self, value) {
+            (Field::Any, _) => weight / 3,
+            (Field::Absent, None) => 0,
+            (Field::Exactly(expected), Some(value)) if *expected == value => 0,
+            _ => weight,
+        }
+    }
+}
+
+/// Entry of the signatures file
+#[derive(Clone, Debug)]
+struct OsSignature {
+    syn_ack: bool,
+    initial_ttl: u8,
+    mss: Field<u16>,
+    window: Field<u16>,
+    window_scale: Field<u8>,
+    options_layout: &'static str,
+    label: &'static str,
+}
+
+impl OsSignature {
+    const WINDOW_WEIGHT: u8 = 30;
+    const SCALE_WEIGHT: u8 = 15;
+    const MSS_WEIGHT: u8 = 15;
+
+    fn parse(line: &'static str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let mut fields = line.splitn(6, ':');
+        let syn_ack = match fields.next()? {
+            "syn" => false,
+            "syn+ack" => true,
+            _ => return None,
+        };
+        let initial_ttl = fields.next()?.parse().ok()?;
+        let mss = parse_field(fields.next()?)?;
+        let (window, window_scale) = fields.next()?.split_once(',')?;
+        let window = match window.strip_prefix("mss*") {
+            Some(multiple) => Field::MssMultiple(multiple.parse().ok()?),
+            None => parse_field(window)?,
+        };
+        let window_scale = parse_field(window_scale)?;
+        let options_layout = fields.next()?;
+        let label = fields.next()?.trim();
+        Some(Self {
+            syn_ack,
+            initial_ttl,
+            mss,
+            window,
+            window_scale,
+            options_layout,
+            label,
+        })
+    }
+
+    /// Returns how much the given handshake segment matches this signature, in percent
+    fn confidence(&self, tcp_signature: &TcpSignature) -> u8 {
+        let window_penalty = match self.window {
+            Field::MssMultiple(multiple) => {
+                let expected = tcp_signature.mss.and_then(|mss| mss.checked_mul(multiple));
+                if expected.is_some() && expected == Some(tcp_signature.window) {
+                    0
+                } else {
+                    Self::WINDOW_WEIGHT
+                }
+            }
+            window => window.penalty(Some(tcp_signature.window), Self::WINDOW_WEIGHT),
+        };
+        let scale_penalty = self
+            .window_scale
+            .penalty(tcp_signature.window_scale, Self::SCALE_WEIGHT);
+        let mss_penalty = self.mss.penalty(tcp_signature.mss, Self::MSS_WEIGHT);
+        100 - window_penalty - scale_penalty - mss_penalty
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(value: &str) -> Option<Field<T>> {
+    match value {
+        "*" => Some(Field::Any),
+        "-" => Some(Field::Absent),
+        value => value.parse().ok().map(Field::Exactly),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use etherparse::{TcpHeader, TcpOptionElement};
+
+    use crate::networking::types::os_fingerprint::{
+        OsGuess, TcpSignature, OS_SIGNATURES, SIGNATURES,
+    };
+
+    fn syn(window: u16, options: &[TcpOptionElement], ack: bool) -> TcpHeader {
+        let mut tcp_header = TcpHeader::new(50000, 443, 1, window);
+        tcp_header.syn = true;
+        tcp_header.ack = ack;
+        tcp_header.set_options(options).unwrap();
+        tcp_header
+    }
+
+    #[test]
+    fn test_signatures_file() {
+        // every signature must be valid
+        let lines = OS_SIGNATURES
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .count();
+        assert_eq!(SIGNATURES.len(), lines);
+        assert!(SIGNATURES
+            .iter()
+            .all(|signature| !signature.label.is_empty() && !signature.options_layout.is_empty()));
+    }
+
+    #[test]
+    fn test_linux_syn() {
+        let tcp_header = syn(
+            64240,
+            &[
+                TcpOptionElement::MaximumSegmentSize(1460),
+                TcpOptionElement::SelectiveAcknowledgementPermitted,
+                TcpOptionElement::Timestamp(1, 0),
+                TcpOptionElement::Noop,
+                TcpOptionElement::WindowScale(7),
+            ],
+            false,
+        );
+        let signature = TcpSignature::from_etherparse(&tcp_header, 61).unwrap();
+        assert_eq!(signature.options_layout(), "mss,sok,ts,nop,ws");
+        assert_eq!(signature.mss, Some(1460));
+        assert_eq!(signature.window_scale, Some(7));
+        assert_eq!(signature.initial_ttl(), 64);
+        assert_eq!(
+            signature.guess_os(),
+            Some(OsGuess {
+                os: "Linux 4.x-6.x",
+                confidence: 95,
+                hop_distance: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_windows_syn_and_syn_ack() {
+        let options = [
+            TcpOptionElement::MaximumSegmentSize(1460),
+            TcpOptionElement::Noop,
+            TcpOptionElement::WindowScale(8),
+            TcpOptionElement::Noop,
+            TcpOptionElement::Noop,
+            TcpOptionElement::SelectiveAcknowledgementPermitted,
+        ];
+        let signature = TcpSignature::from_etherparse(&syn(64240, &options, false), 128).unwrap();
+        assert_eq!(
+            signature.guess_os(),
+            Some(OsGuess {
+                os: "Windows 10/11",
+                confidence: 95,
+                hop_distance: 0
+            })
+        );
+        // a different window size lowers the confidence
+        let signature = TcpSignature::from_etherparse(&syn(29200, &options, false), 120).unwrap();
+        assert_eq!(signature.guess_os().unwrap().confidence, 65);
+
+        let signature = TcpSignature::from_etherparse(&syn(65535, &options, true), 117).unwrap();
+        assert!(signature.syn_ack);
+        assert_eq!(
+            signature.guess_os(),
+            Some(OsGuess {
+                os: "Windows",
+                confidence: 80,
+                hop_distance: 11
+            })
+        );
+    }
+
+    #[test]
+    fn test_unknown_signature() {
+        // TTL doesn't match the one of the known systems with this options layout
+        let tcp_header = syn(
+            64240,
+            &[
+                TcpOptionElement::MaximumSegmentSize(1460),
+                TcpOptionElement::SelectiveAcknowledgementPermitted,
+                TcpOptionElement::Timestamp(1, 0),
+                TcpOptionElement::Noop,
+                TcpOptionElement::WindowScale(7),
+            ],
+            false,
+        );
+        let signature = TcpSignature::from_etherparse(&tcp_header, 125).unwrap();
+        assert_eq!(signature.guess_os(), None);
+
+        // not a handshake segment
+        let mut tcp_header = syn(64240, &[], true);
+        tcp_header.syn = false;
+        assert_eq!(TcpSignature::from_etherparse(&tcp_header, 64), None);
+    }
+}
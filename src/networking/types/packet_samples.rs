@@ -0,0 +1,209 @@
+//! Module defining the `PacketSamples` struct, which keeps the first packets of each connection
+//! to let users inspect their bytes.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use chrono::{DateTime, Local};
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::tcp_state::TcpFlags;
+
+/// Maximum number of packets kept for each direction of a connection
+pub const MAX_SAMPLES_PER_DIRECTION: usize = 16;
+
+/// Maximum amount of bytes kept for all the connections
+const MAX_SAMPLES_BYTES: usize = 32 * 1024 * 1024;
+
+/// Number of bytes in each line of the hex dump
+const BYTES_PER_LINE: usize = 16;
+
+/// A packet kept to be inspected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketSample {
+    /// Capture time of the packet, in microseconds
+    pub timestamp: i64,
+    /// Length of the packet on the wire
+    pub original_len: u32,
+    /// TCP flags of the packet (this is None if not TCP)
+    pub tcp_flags: Option<TcpFlags>,
+    /// Captured bytes of the packet (these are less than its length if truncated by the snaplen)
+    pub data: Vec<u8>,
+}
+
+impl PacketSample {
+    /// Returns true if the packet was truncated by the snaplen
+    pub fn is_truncated(&self) -> bool {
+        self.data.len() < self.original_len as usize
+    }
+
+    /// Capture time of the packet, formatted as local time with microseconds
+    pub fn formatted_timestamp(&self) -> String {
+        DateTime::from_timestamp_micros(self.timestamp).map_or_else(
+            || "-".to_string(),
+            |timestamp| {
+                timestamp
+                    .with_timezone(&Local)
+                    .format("%H:%M:%S%.6f")
+                    .to_string()
+            },
+        )
+    }
+
+    /// Returns the captured bytes as lines made of the offset, the hex values, and the ASCII characters
+    pub fn hex_dump(&self) -> String {
+        let mut dump = String::new();
+        for (i, line) in self.data.chunks(BYTES_PER_LINE).enumerate() {
+            let _ = write!(dump, "{:04x}  ", i * BYTES_PER_LINE);
+            for position in 0..BYTES_PER_LINE {
+                match line.get(position) {
+                    Some(byte) => {
+                        let _ = write!(dump, "{byte:02x} ");
+                    }
+                    None => dump.push_str("   "),
+                }
+                if position == BYTES_PER_LINE / 2 - 1 {
+                    dump.push(' ');
+                }
+            }
+            dump.push(' ');
+            dump.extend(line.iter().map(|byte| {
+                if byte.is_ascii_graphic() || *byte == b' ' {
+                    char::from(*byte)
+                } else {
+                    '.'
+                }
+            }));
+            dump.push('\n');
+        }
+        dump.pop();
+        dump
+    }
+
+    /// Returns the captured bytes as a stream of hex values
+    pub fn hex_stream(&self) -> String {
+        self.data.iter().fold(String::new(), |mut stream, byte| {
+            let _ = write!(stream, "{byte:02x}");
+            stream
+        })
+    }
+}
+
+/// The first packets of each connection, bounded in number and in total size
+#[derive(Clone, Default, Debug)]
+pub struct PacketSamples {
+    /// Packets kept, for each direction of the connections
+    samples: HashMap<AddressPortPair, Vec<PacketSample>>,
+    /// Total amount of bytes kept
+    bytes: usize,
+}
+
+impl PacketSamples {
+    /// Keeps a packet sent by the source of the given key, unless enough packets were already kept
+    /// for this direction of the connection or the memory budget is exhausted.
+    ///
+    /// The captured bytes are only copied if the packet is kept, in which case true is returned.
+    pub fn add(
+        &mut self,
+        key: &AddressPortPair,
+        timestamp: i64,
+        original_len: u32,
+        tcp_flags: Option<TcpFlags>,
+        data: &[u8],
+    ) -> bool {
+        if self.bytes + data.len() > MAX_SAMPLES_BYTES
+            || self
+                .samples
+                .get(key)
+                .is_some_and(|samples| samples.len() >= MAX_SAMPLES_PER_DIRECTION)
+        {
+            return false;
+        }
+        let sample = PacketSample {
+            timestamp,
+            original_len,
+            tcp_flags,
+            data: data.to_vec(),
+        };
+        self.bytes += data.len();
+        if let Some(samples) = self.samples.get_mut(key) {
+            samples.push(sample);
+        } else {
+            self.samples.insert(key.clone(), vec![sample]);
+        }
+        true
+    }
+
+    /// Returns the packets kept for both the directions of a connection, sorted by capture time.
+    ///
+    /// Each packet is paired with true if it was sent by the source of the given key.
+    pub fn connection(&self, key: &AddressPortPair) -> Vec<(bool, &PacketSample)> {
+        let forward = self.samples.get(key).into_iter().flatten();
+        let backward = self.samples.get(&key.reversed()).into_iter().flatten();
+        let mut packets: Vec<(bool, &PacketSample)> = forward
+            .map(|sample| (true, sample))
+            .chain(backward.map(|sample| (false, sample)))
+            .collect();
+        packets.sort_by_key(|(_, sample)| sample.timestamp);
+        packets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::packet_samples::{
+        PacketSample, PacketSamples, MAX_SAMPLES_PER_DIRECTION,
+    };
+    use crate::Protocol;
+
+    fn sample(timestamp: i64, data: &[u8]) -> PacketSample {
+        PacketSample {
+            timestamp,
+            original_len: 100,
+            tcp_flags: None,
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_hex_dump() {
+        let packet = sample(0, b"GET / HTTP/1.1\r\nHost: a\r\n");
+        assert!(packet.is_truncated());
+        assert_eq!(
+            packet.hex_dump(),
+            "0000  47 45 54 20 2f 20 48 54  54 50 2f 31 2e 31 0d 0a  GET / HTTP/1.1..\n\
+             0010  48 6f 73 74 3a 20 61 0d  0a                       Host: a.."
+        );
+        assert_eq!(
+            packet.hex_stream(),
+            "474554202f20485454502f312e310d0a486f73743a20610d0a"
+        );
+        assert_eq!(sample(0, &[]).hex_dump(), "");
+    }
+
+    #[test]
+    fn test_samples_bounded_per_direction() {
+        let key = AddressPortPair::new(
+            "10.0.0.1".to_string(),
+            Some(50000),
+            "10.0.0.2".to_string(),
+            Some(80),
+            Protocol::TCP,
+        );
+        let mut samples = PacketSamples::default();
+        for i in 0..MAX_SAMPLES_PER_DIRECTION {
+            assert!(samples.add(&key, 2 * i as i64, 100, None, &[1]));
+        }
+        assert!(!samples.add(&key, 100, 100, None, &[1]));
+        // the other direction has its own budget
+        assert!(samples.add(&key.reversed(), 1, 100, None, &[2]));
+
+        let packets = samples.connection(&key);
+        assert_eq!(packets.len(), MAX_SAMPLES_PER_DIRECTION + 1);
+        assert_eq!(packets[0], (true, &sample(0, &[1])));
+        assert_eq!(packets[1], (false, &sample(1, &[2])));
+        assert!(samples.connection(&key.reversed())[1].0);
+        assert_eq!(samples.bytes, MAX_SAMPLES_PER_DIRECTION + 1);
+    }
+}
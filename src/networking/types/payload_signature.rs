@@ -0,0 +1,272 @@
+//! Module in charge of identifying the application protocol of a connection from the first bytes
+//! of its payloads, regardless of the ports in use.
+
+use crate::networking::types::dns_message::DnsMessage;
+use crate::networking::types::service::Service;
+use crate::Protocol;
+
+/// Service associated to the connections identified as TLS, when the port doesn't tell anything more specific
+pub const TLS_SERVICE: Service = Service::Name("tls");
+
+/// Port-based services known to run over TLS: if a connection is identified as TLS, their label is kept
+const TLS_BASED_SERVICES: [Service; 9] = [
+    Service::Name("https"),
+    Service::Name("smtps"),
+    Service::Name("imaps"),
+    Service::Name("pop3s"),
+    Service::Name("ftps"),
+    Service::Name("ldapssl"),
+    Service::Name("snews"),
+    Service::Name("domain-s"),
+    Service::Name("ircs-u"),
+];
+
+const HTTP_METHODS: [&[u8]; 9] = [
+    b"GET ",
+    b"POST ",
+    b"HEAD ",
+    b"PUT ",
+    b"DELETE ",
+    b"OPTIONS ",
+    b"PATCH ",
+    b"CONNECT ",
+    b"TRACE ",
+];
+
+/// Returns the service whose signature matches the given TCP or UDP payload, if any
+pub fn detect_service(protocol: Protocol, payload: &[u8]) -> Option<Service> {
+    let name = match protocol {
+        Protocol::TCP => detect_tcp(payload)?,
+        Protocol::UDP => detect_udp(payload)?,
+        _ => return None,
+    };
+    Some(Service::Name(name))
+}
+
+/// Returns true if the given port-based service is known to run over TLS
+pub fn is_tls_based(service: Service) -> bool {
+    TLS_BASED_SERVICES.contains(&service)
+}
+
+fn detect_tcp(payload: &[u8]) -> Option<&'static str> {
+    if HTTP_METHODS
+        .iter()
+        .any(|method| payload.starts_with(method))
+        || payload.starts_with(b"HTTP/1.")
+    {
+        Some("http")
+    } else if is_tls(payload) {
+        Some("tls")
+    } else if payload.starts_with(b"SSH-") {
+        Some("ssh")
+    } else if is_smtp(payload) {
+        Some("smtp")
+    } else if is_ftp(payload) {
+        Some("ftp")
+    } else if is_rdp(payload) {
+        Some("ms-wbt-server")
+    } else if payload.starts_with(b"\x13BitTorrent protocol") {
+        Some("bittorrent")
+    } else if is_stun(payload) {
+        Some("stun")
+    } else if length_prefixed(payload).is_some_and(is_openvpn) {
+        Some("openvpn")
+    } else if length_prefixed(payload).is_some_and(is_dns) {
+        Some("domain")
+    } else {
+        None
+    }
+}
+
+fn detect_udp(payload: &[u8]) -> Option<&'static str> {
+    if is_wireguard(payload) {
+        Some("wireguard")
+    } else if is_stun(payload) {
+        Some("stun")
+    } else if payload.starts_with(b"d1:ad2:id20:") || payload.starts_with(b"d1:rd2:id20:") {
+        // DHT queries and responses
+        Some("bittorrent")
+    } else if is_dns(payload) {
+        Some("domain")
+    } else if is_openvpn(payload) {
+        Some("openvpn")
+    } else {
+        None
+    }
+}
+
+/// TLS handshake record (ClientHello or ServerHello)
+fn is_tls(payload: &[u8]) -> bool {
+    matches!(payload, [0x16, 0x03, 0x00..=0x04, _, _, 0x01 | 0x02, ..])
+}
+
+/// SMTP server greeting or client greeting
+fn is_smtp(payload: &[u8]) -> bool {
+    payload.starts_with(b"EHLO ")
+        || payload.starts_with(b"HELO ")
+        || (payload.starts_with(b"220") && first_line_contains(payload, b"SMTP"))
+}
+
+/// FTP server greeting or client login
+fn is_ftp(payload: &[u8]) -> bool {
+    payload.starts_with(b"USER ")
+        || (payload.starts_with(b"220") && first_line_contains(payload, b"FTP"))
+}
+
+/// TPKT header followed by an X.224 Connection Request or Connection Confirm
+fn is_rdp(payload: &[u8]) -> bool {
+    matches!(payload, [0x03, 0x00, len_hi, len_lo, _, 0xe0 | 0xd0, ..]
+        if usize::from(u16::from_be_bytes([*len_hi, *len_lo])) == payload.len())
+}
+
+/// STUN message (first two bits set to zero, magic cookie, and consistent length)
+fn is_stun(payload: &[u8]) -> bool {
+    payload.len() >= 20
+        && payload[0] & 0xc0 == 0
+        && payload[4..8] == [0x21, 0x12, 0xa4, 0x42]
+        && usize::from(u16::from_be_bytes([payload[2], payload[3]])) == payload.len() - 20
+}
+
+/// WireGuard message (type followed by reserved zero bytes, with the length expected for that type)
+fn is_wireguard(payload: &[u8]) -> bool {
+    match payload {
+        [message_type, 0, 0, 0, ..] => match message_type {
+            // handshake initiation, handshake response, cookie reply
+            1 => payload.len() == 148,
+            2 => payload.len() == 92,
+            3 => payload.len() == 64,
+            // transport data (padded to a multiple of 16 bytes, plus header and tag)
+            4 => payload.len() >= 32 && payload.len().is_multiple_of(16),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// OpenVPN hard reset packet (the first packet sent by each peer)
+fn is_openvpn(payload: &[u8]) -> bool {
+    // opcode in the 5 most significant bits, key ID (zero for the first session) in the others
+    let Some(first_byte) = payload.first() else {
+        return false;
+    };
+    matches!(first_byte >> 3, 7 | 8 | 10) && first_byte & 0x07 == 0 && payload.len() >= 14
+}
+
+/// DNS message with a single question and no unknown opcode
+fn is_dns(payload: &[u8]) -> bool {
+    // standard query, inverse query, status, notify, update
+    let opcode = payload.get(2).map(|flags| (flags >> 3) & 0x0f);
+    matches!(opcode, Some(0 | 1 | 2 | 4 | 5))
+        && payload.get(4..6) == Some(&[0, 1])
+        && DnsMessage::parse(payload).is_some_and(|message| message.questions.len() == 1)
+}
+
+/// Returns the message following the 2-byte length prefix used by DNS and OpenVPN over TCP,
+/// if the prefix matches the payload length
+fn length_prefixed(payload: &[u8]) -> Option<&[u8]> {
+    let (len, message) = payload.split_first_chunk::<2>()?;
+    (usize::from(u16::from_be_bytes(*len)) == message.len()).then_some(message)
+}
+
+fn first_line_contains(payload: &[u8], needle: &[u8]) -> bool {
+    payload
+        .split(|byte| *byte == b'\n')
+        .next()
+        .is_some_and(|line| line.windows(needle.len()).any(|window| window == needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::payload_signature::{detect_service, is_tls_based};
+    use crate::networking::types::service::Service;
+    use crate::Protocol;
+
+    fn tcp(payload: &[u8]) -> Option<Service> {
+        detect_service(Protocol::TCP, payload)
+    }
+
+    fn udp(payload: &[u8]) -> Option<Service> {
+        detect_service(Protocol::UDP, payload)
+    }
+
+    #[test]
+    fn test_detect_tcp_services() {
+        assert_eq!(
+            tcp(b"GET /index.html HTTP/1.1\r\nHost: example.com\r\n\r\n"),
+            Some(Service::Name("http"))
+        );
+        assert_eq!(tcp(b"HTTP/1.1 200 OK\r\n"), Some(Service::Name("http")));
+        assert_eq!(
+            tcp(&[0x16, 0x03, 0x01, 0x02, 0x00, 0x01, 0x00, 0x01, 0xfc]),
+            Some(Service::Name("tls"))
+        );
+        assert_eq!(tcp(b"SSH-2.0-OpenSSH_9.6\r\n"), Some(Service::Name("ssh")));
+        assert_eq!(
+            tcp(b"220 mail.example.com ESMTP Postfix\r\n"),
+            Some(Service::Name("smtp"))
+        );
+        assert_eq!(tcp(b"EHLO client\r\n"), Some(Service::Name("smtp")));
+        assert_eq!(tcp(b"220 (vsFTPd 3.0.5)\r\n"), Some(Service::Name("ftp")));
+        assert_eq!(
+            tcp(&[0x03, 0x00, 0x00, 0x0b, 0x06, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            Some(Service::Name("ms-wbt-server"))
+        );
+        assert_eq!(
+            tcp(b"\x13BitTorrent protocol\x00\x00\x00\x00\x00\x10\x00\x05"),
+            Some(Service::Name("bittorrent"))
+        );
+        let mut openvpn = vec![0x00, 0x0e, 0x38];
+        openvpn.extend_from_slice(&[0xaa; 13]);
+        assert_eq!(tcp(&openvpn), Some(Service::Name("openvpn")));
+        // DNS over TCP (preceded by its length)
+        let mut dns = vec![0x00, 0x1d];
+        dns.extend_from_slice(&DNS_QUERY);
+        assert_eq!(tcp(&dns), Some(Service::Name("domain")));
+    }
+
+    #[test]
+    fn test_detect_udp_services() {
+        let mut wireguard = vec![0x01, 0x00, 0x00, 0x00];
+        wireguard.extend_from_slice(&[0x5a; 144]);
+        assert_eq!(udp(&wireguard), Some(Service::Name("wireguard")));
+        let mut stun = vec![
+            0x00, 0x01, 0x00, 0x00, 0x21, 0x12, 0xa4, 0x42, // binding request, no attributes
+        ];
+        stun.extend_from_slice(&[0x01; 12]);
+        assert_eq!(udp(&stun), Some(Service::Name("stun")));
+        assert_eq!(
+            udp(b"d1:ad2:id20:abcdefghij0123456789e1:q4:pinge"),
+            Some(Service::Name("bittorrent"))
+        );
+        assert_eq!(udp(&DNS_QUERY), Some(Service::Name("domain")));
+        let mut openvpn = vec![0x38];
+        openvpn.extend_from_slice(&[0xaa; 13]);
+        assert_eq!(udp(&openvpn), Some(Service::Name("openvpn")));
+    }
+
+    #[test]
+    fn test_detect_nothing() {
+        assert_eq!(tcp(b""), None);
+        assert_eq!(tcp(b"hello world"), None);
+        assert_eq!(tcp(b"GET"), None);
+        assert_eq!(udp(b"GET / HTTP/1.1\r\n"), None);
+        assert_eq!(udp(&[0x01, 0x00, 0x00, 0x00, 0x5a]), None);
+        assert_eq!(udp(&[0u8; 40]), None);
+        assert_eq!(detect_service(Protocol::ICMP, b"SSH-2.0-OpenSSH_9.6"), None);
+    }
+
+    #[test]
+    fn test_tls_based_services() {
+        assert!(is_tls_based(Service::Name("https")));
+        assert!(is_tls_based(Service::Name("imaps")));
+        assert!(!is_tls_based(Service::Name("http")));
+        assert!(!is_tls_based(Service::Unknown));
+    }
+
+    // standard query for example.com (A)
+    const DNS_QUERY: [u8; 29] = [
+        0xab, 0xcd, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // header
+        7, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 3, b'c', b'o', b'm', 0, 0x00, 0x01, 0x00,
+        0x01,
+    ];
+}
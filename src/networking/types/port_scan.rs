@@ -0,0 +1,343 @@
+//! Module defining the `PortScanDetector` struct, which detects horizontal and vertical port scans
+//! from the new connections seen in sliding time windows.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::Protocol;
+
+/// Ports of the services that local clients normally contact on many hosts
+/// (DNS, HTTP, NTP, HTTPS and QUIC, DNS over TLS, IMAPS, and alternative HTTP(S) ports):
+/// outgoing connections to them aren't counted towards horizontal scans
+const CLIENT_SERVICE_PORTS: [u16; 8] = [53, 80, 123, 443, 853, 993, 8080, 8443];
+
+/// Thresholds used to detect port scans
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct ScanThresholds {
+    /// Length of the sliding window, in seconds
+    pub window: u32,
+    /// Distinct hosts probed on the same port to detect a horizontal scan
+    pub hosts: u32,
+    /// Distinct ports probed on the same host to detect a vertical scan
+    pub ports: u32,
+}
+
+impl Default for ScanThresholds {
+    fn default() -> Self {
+        Self {
+            window: 60,
+            hosts: 20,
+            ports: 20,
+        }
+    }
+}
+
+impl ScanThresholds {
+    pub const WINDOW_OPTIONS: [u32; 4] = [10, 60, 300, 900];
+    pub const TARGETS_OPTIONS: [u32; 4] = [10, 20, 50, 100];
+}
+
+/// Kind of port scan
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScanKind {
+    /// One source probing the same port on many hosts
+    Horizontal,
+    /// One source probing many ports on the given host
+    Vertical(String),
+}
+
+/// A port scan detected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PortScan {
+    pub kind: ScanKind,
+    /// Address of the host performing the scan
+    pub scanner: String,
+    pub protocol: Protocol,
+    /// Number of hosts (horizontal scans) or ports (vertical scans) probed in the window
+    pub targets: usize,
+    /// Lowest and highest port probed in the window
+    pub ports: (u16, u16),
+}
+
+/// Distinct targets seen in a sliding window
+#[derive(Default)]
+struct Window<T> {
+    /// Targets, with the timestamp of their connection, from the oldest
+    events: VecDeque<(i64, T)>,
+    /// Number of events in the window for each target
+    counts: HashMap<T, usize>,
+    /// Whether a scan was already reported for the targets currently in the window
+    reported: bool,
+}
+
+impl<T: Clone + Eq + Hash> Window<T> {
+    /// Adds a target, returning the number of distinct targets in the window
+    fn add(&mut self, timestamp: i64, target: T, window: i64) -> usize {
+        self.expire(timestamp - window);
+        if self.events.is_empty() {
+            self.reported = false;
+        }
+        *self.counts.entry(target.clone()).or_default() += 1;
+        self.events.push_back((timestamp, target));
+        self.counts.len()
+    }
+
+    /// Removes the targets older than the given timestamp
+    fn expire(&mut self, oldest: i64) {
+        while self.events.front().is_some_and(|(ts, _)| *ts < oldest) {
+            let Some((_, target)) = self.events.pop_front() else {
+                break;
+            };
+            if let Entry::Occupied(mut count) = self.counts.entry(target) {
+                *count.get_mut() -= 1;
+                if *count.get() == 0 {
+                    count.remove();
+                }
+            }
+        }
+    }
+}
+
+/// Detects port scans from the new connections, in both directions
+pub struct PortScanDetector {
+    thresholds: ScanThresholds,
+    /// Hosts probed by each source on the same port
+    horizontal: HashMap<(String, Protocol, u16), Window<String>>,
+    /// Ports probed by each source on the same host
+    vertical: HashMap<(String, String, Protocol), Window<u16>>,
+    /// Timestamp of the last removal of the expired windows
+    last_cleanup: i64,
+}
+
+impl PortScanDetector {
+    pub fn new(thresholds: ScanThresholds) -> Self {
+        Self {
+            thresholds,
+            horizontal: HashMap::new(),
+            vertical: HashMap::new(),
+            last_cleanup: 0,
+        }
+    }
+
+    /// Registers a new connection, opened at the given timestamp (in microseconds),
+    /// returning the scans detected because of it.
+    ///
+    /// Each scan is reported once, when the distinct targets in the window reach the threshold;
+    /// it can be reported again only after its window has been emptied.
+    pub fn new_connection(
+        &mut self,
+        key: &AddressPortPair,
+        traffic_direction: TrafficDirection,
+        timestamp: i64,
+    ) -> Vec<PortScan> {
+        let Some(port) = key.port2 else {
+            return Vec::new();
+        };
+        let window = i64::from(self.thresholds.window) * 1_000_000;
+        self.cleanup(timestamp, window);
+        let mut scans = Vec::new();
+
+        // browsing and other ordinary client activity reaches many hosts on the same ports
+        let is_client_activity =
+            traffic_direction == TrafficDirection::Outgoing && CLIENT_SERVICE_PORTS.contains(&port);
+        if !is_client_activity {
+            let horizontal = self
+                .horizontal
+                .entry((key.address1.clone(), key.protocol, port))
+                .or_default();
+            let hosts = horizontal.add(timestamp, key.address2.clone(), window);
+            if let Some(targets) = check_threshold(horizontal, hosts, self.thresholds.hosts) {
+                scans.push(PortScan {
+                    kind: ScanKind::Horizontal,
+                    scanner: key.address1.clone(),
+                    protocol: key.protocol,
+                    targets,
+                    ports: (port, port),
+                });
+            }
+        }
+
+        let vertical = self
+            .vertical
+            .entry((key.address1.clone(), key.address2.clone(), key.protocol))
+            .or_default();
+        let ports = vertical.add(timestamp, port, window);
+        if let Some(targets) = check_threshold(vertical, ports, self.thresholds.ports) {
+            let first_port = vertical.counts.keys().min().copied().unwrap_or(port);
+            let last_port = vertical.counts.keys().max().copied().unwrap_or(port);
+            scans.push(PortScan {
+                kind: ScanKind::Vertical(key.address2.clone()),
+                scanner: key.address1.clone(),
+                protocol: key.protocol,
+                targets,
+                ports: (first_port, last_port),
+            });
+        }
+
+        scans
+    }
+
+    /// Removes the expired windows, at most once per window length
+    fn cleanup(&mut self, timestamp: i64, window: i64) {
+        if timestamp - self.last_cleanup < window {
+            return;
+        }
+        self.last_cleanup = timestamp;
+        let oldest = timestamp - window;
+        self.horizontal.retain(|_, w| {
+            w.expire(oldest);
+            !w.events.is_empty()
+        });
+        self.vertical.retain(|_, w| {
+            w.expire(oldest);
+            !w.events.is_empty()
+        });
+    }
+}
+
+/// Returns the number of targets if a scan has to be reported for the given window
+fn check_threshold<T>(window: &mut Window<T>, targets: usize, threshold: u32) -> Option<usize> {
+    let threshold = usize::try_from(threshold).unwrap_or(usize::MAX);
+    if targets < threshold || window.reported {
+        return None;
+    }
+    window.reported = true;
+    Some(targets)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::port_scan::{PortScanDetector, ScanKind, ScanThresholds};
+    use crate::networking::types::traffic_direction::TrafficDirection;
+    use crate::Protocol;
+
+    const SECOND: i64 = 1_000_000;
+
+    fn key(source: &str, destination: &str, port: u16) -> AddressPortPair {
+        AddressPortPair::new(
+            source.to_string(),
+            Some(40000),
+            destination.to_string(),
+            Some(port),
+            Protocol::TCP,
+        )
+    }
+
+    #[test]
+    fn test_horizontal_scan() {
+        let mut detector = PortScanDetector::new(ScanThresholds {
+            window: 10,
+            hosts: 5,
+            ports: 5,
+        });
+        let mut scans = Vec::new();
+        for i in 1..=8 {
+            let destination = format!("10.0.0.{i}");
+            scans.extend(detector.new_connection(
+                &key("10.0.0.99", &destination, 22),
+                TrafficDirection::Incoming,
+                i * SECOND,
+            ));
+        }
+        // reported once, when the threshold is reached
+        assert_eq!(scans.len(), 1);
+        assert_eq!(scans[0].kind, ScanKind::Horizontal);
+        assert_eq!(scans[0].scanner, "10.0.0.99");
+        assert_eq!(scans[0].targets, 5);
+        assert_eq!(scans[0].ports, (22, 22));
+
+        // reported again after the window has been emptied
+        for i in 1..=5 {
+            let destination = format!("10.0.1.{i}");
+            let scan = detector.new_connection(
+                &key("10.0.0.99", &destination, 22),
+                TrafficDirection::Incoming,
+                60 * SECOND + i,
+            );
+            assert_eq!(scan.len(), usize::from(i == 5));
+        }
+    }
+
+    #[test]
+    fn test_vertical_scan() {
+        let mut detector = PortScanDetector::new(ScanThresholds {
+            window: 10,
+            hosts: 5,
+            ports: 5,
+        });
+        let mut scans = Vec::new();
+        for port in [443, 80, 22, 80, 8080, 21] {
+            scans.extend(detector.new_connection(
+                &key("192.168.1.10", "10.0.0.5", port),
+                TrafficDirection::Outgoing,
+                SECOND,
+            ));
+        }
+        assert_eq!(scans.len(), 1);
+        assert_eq!(scans[0].kind, ScanKind::Vertical("10.0.0.5".to_string()));
+        assert_eq!(scans[0].targets, 5);
+        assert_eq!(scans[0].ports, (21, 8080));
+    }
+
+    #[test]
+    fn test_browsing_not_detected() {
+        let mut detector = PortScanDetector::new(ScanThresholds::default());
+        for i in 1..=100 {
+            let destination = format!("203.0.113.{i}");
+            // the local host browsing many websites
+            assert!(detector
+                .new_connection(
+                    &key("192.168.1.10", &destination, 443),
+                    TrafficDirection::Outgoing,
+                    i * SECOND / 10
+                )
+                .is_empty());
+        }
+        // the same connections to another port are a scan
+        let scans: Vec<_> = (1..=20)
+            .flat_map(|i| {
+                let destination = format!("203.0.113.{i}");
+                detector.new_connection(
+                    &key("192.168.1.10", &destination, 22),
+                    TrafficDirection::Outgoing,
+                    10 * SECOND,
+                )
+            })
+            .collect();
+        assert_eq!(scans.len(), 1);
+        assert_eq!(scans[0].kind, ScanKind::Horizontal);
+    }
+
+    #[test]
+    fn test_slow_scan_not_detected() {
+        let mut detector = PortScanDetector::new(ScanThresholds {
+            window: 10,
+            hosts: 5,
+            ports: 5,
+        });
+        for i in 1..=20 {
+            let destination = format!("10.0.0.{i}");
+            // one new host every 3 seconds: at most 4 in the window
+            assert!(detector
+                .new_connection(
+                    &key("10.0.0.99", &destination, 22),
+                    TrafficDirection::Incoming,
+                    i * 3 * SECOND
+                )
+                .is_empty());
+            assert!(detector
+                .new_connection(
+                    &key("10.0.0.99", "10.0.0.200", 1000 + u16::try_from(i).unwrap()),
+                    TrafficDirection::Incoming,
+                    i * 3 * SECOND
+                )
+                .is_empty());
+        }
+    }
+}
@@ -0,0 +1,386 @@
+//! Module defining the `ProcessInfo` struct, which identifies the local process owning a socket,
+//! and the `ProcessResolver`, which finds it by correlating the sockets listed in `/proc/<pid>/net`
+//! for each network namespace with the file descriptors of the processes (Linux only).
+
+use std::collections::HashMap;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::networking::types::container_info::ContainerInfo;
+use crate::Protocol;
+
+/// Local process owning the socket of a connection
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessInfo {
+    /// Process identifier
+    pub pid: u32,
+    /// Name of the process (i.e., the content of `/proc/<pid>/comm`)
+    pub name: String,
+    /// Path of the executable (this is None if it can't be read, e.g., due to missing permissions)
+    pub executable: Option<String>,
+    /// Inode of the network namespace of the process
+    pub netns: Option<u64>,
+    /// Container of the process (this is None if it runs on the host)
+    pub container: Option<ContainerInfo>,
+}
+
+impl fmt::Display for ProcessInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (PID {})", self.name, self.pid)
+    }
+}
+
+/// Finds the local processes owning the sockets of the connections.
+///
+/// Sockets and processes are read from `/proc` by a background thread, which refreshes them
+/// at most once per `REFRESH_INTERVAL` when a socket can't be found, so that lookups never block;
+/// only the processes whose file descriptors are readable (all of them if running as root) can be identified.
+pub struct ProcessResolver {
+    /// Sockets and processes found by the last refresh
+    table: Arc<Mutex<ProcessTable>>,
+    /// Requests of a refresh to the background thread (this is None if not on Linux)
+    refresh_requests: Option<SyncSender<()>>,
+}
+
+impl ProcessResolver {
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Returns the process owning the local socket bound to the given address and port,
+    /// requesting a refresh if it can't be found
+    pub fn lookup(&self, protocol: Protocol, address: &str, port: u16) -> Option<ProcessInfo> {
+        let address: IpAddr = address.parse().ok()?;
+        let process = self
+            .table
+            .lock()
+            .unwrap()
+            .find(protocol, address, port)
+            .cloned();
+        if process.is_none() {
+            if let Some(refresh_requests) = &self.refresh_requests {
+                // the channel is full if a refresh is already pending
+                refresh_requests.try_send(()).unwrap_or(());
+            }
+        }
+        process
+    }
+
+    /// Refreshes the table every time it's requested, until the resolver is dropped
+    fn refresh_loop(table: &Mutex<ProcessTable>, refresh_requests: &Receiver<()>) {
+        let mut containers = HashMap::new();
+        while refresh_requests.recv().is_ok() {
+            let start = Instant::now();
+            let new_table = ProcessTable::read(&mut containers);
+            *table.lock().unwrap() = new_table;
+            thread::sleep(Self::REFRESH_INTERVAL.saturating_sub(start.elapsed()));
+        }
+    }
+}
+
+impl Default for ProcessResolver {
+    fn default() -> Self {
+        let table = Arc::new(Mutex::new(ProcessTable::default()));
+        let refresh_requests = if cfg!(target_os = "linux") {
+            let (sender, receiver) = mpsc::sync_channel(1);
+            let table_2 = table.clone();
+            thread::Builder::new()
+                .name("thread_resolve_processes".to_string())
+                .spawn(move || Self::refresh_loop(&table_2, &receiver))
+                .ok()
+                .map(|_| sender)
+        } else {
+            None
+        };
+        Self {
+            table,
+            refresh_requests,
+        }
+    }
+}
+
+/// Local sockets and the processes owning them
+#[derive(Default)]
+struct ProcessTable {
+    /// Inodes of the local sockets, identified by protocol, address, and port
+    sockets: HashMap<(Protocol, IpAddr, u16), u64>,
+    /// Processes owning the sockets, identified by inode
+    processes: HashMap<u64, ProcessInfo>,
+}
+
+impl ProcessTable {
+    /// Looks for a socket bound to the given address, or to any address
+    fn find(&self, protocol: Protocol, address: IpAddr, port: u16) -> Option<&ProcessInfo> {
+        let mut candidates = vec![
+            address,
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        ];
+        // IPv6 sockets also accept IPv4 connections, with IPv4-mapped addresses
+        if let IpAddr::V4(address_v4) = address {
+            candidates.push(IpAddr::V6(address_v4.to_ipv6_mapped()));
+        }
+        candidates
+            .into_iter()
+            .find_map(|candidate| self.sockets.get(&(protocol, candidate, port)))
+            .and_then(|inode| self.processes.get(inode))
+    }
+
+    /// Reads the sockets and processes from `/proc`,
+    /// caching the containers identified by the content of their cgroup file
+    #[cfg(target_os = "linux")]
+    fn read(containers: &mut HashMap<String, Option<ContainerInfo>>) -> Self {
+        use std::collections::HashSet;
+        use std::fs;
+        use std::path::PathBuf;
+
+        use crate::networking::types::network_namespace::namespace_inode;
+
+        let mut table = Self::default();
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return table;
+        };
+        let pids: Vec<(u32, PathBuf, Option<u64>)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let pid = entry.file_name().to_str()?.parse().ok()?;
+                let netns = namespace_inode(&format!("/proc/{pid}/ns/net"));
+                Some((pid, entry.path(), netns))
+            })
+            .collect();
+
+        // read the socket tables of each network namespace once, starting from the own one,
+        // so that the sockets of containers with their own namespace can be found as well
+        let mut namespaces_read = HashSet::new();
+        let own_tables = (
+            PathBuf::from("/proc/self"),
+            namespace_inode("/proc/self/ns/net"),
+        );
+        for (path, netns) in std::iter::once(own_tables)
+            .chain(pids.iter().map(|(_, path, netns)| (path.clone(), *netns)))
+        {
+            if netns.is_some_and(|netns| !namespaces_read.insert(netns)) {
+                continue;
+            }
+            for (socket_table, protocol) in [
+                ("tcp", Protocol::TCP),
+                ("tcp6", Protocol::TCP),
+                ("udp", Protocol::UDP),
+                ("udp6", Protocol::UDP),
+            ] {
+                let Ok(content) = fs::read_to_string(path.join("net").join(socket_table)) else {
+                    continue;
+                };
+                for (address, port, inode) in parse_socket_table(&content) {
+                    table
+                        .sockets
+                        .entry((protocol, address, port))
+                        .or_insert(inode);
+                }
+            }
+        }
+        let inodes: HashSet<u64> = table.sockets.values().copied().collect();
+
+        for (pid, path, netns) in pids {
+            let Ok(fds) = fs::read_dir(path.join("fd")) else {
+                continue;
+            };
+            let mut process: Option<ProcessInfo> = None;
+            for fd in fds.flatten() {
+                let Some(inode) = fs::read_link(fd.path())
+                    .ok()
+                    .and_then(|link| socket_inode(link.to_str()?))
+                    .filter(|inode| inodes.contains(inode))
+                else {
+                    continue;
+                };
+                let process = process.get_or_insert_with(|| ProcessInfo {
+                    pid,
+                    name: fs::read_to_string(path.join("comm"))
+                        .map(|name| name.trim_end().to_string())
+                        .unwrap_or_default(),
+                    executable: fs::read_link(path.join("exe"))
+                        .ok()
+                        .map(|path| path.to_string_lossy().to_string()),
+                    netns,
+                    container: fs::read_to_string(path.join("cgroup"))
+                        .ok()
+                        .and_then(|cgroup| {
+                            containers
+                                .entry(cgroup)
+                                .or_insert_with_key(|cgroup| ContainerInfo::from_cgroup(cgroup))
+                                .clone()
+                        }),
+                });
+                table.processes.insert(inode, process.clone());
+            }
+        }
+        table
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read(_containers: &mut HashMap<String, Option<ContainerInfo>>) -> Self {
+        Self::default()
+    }
+}
+
+/// Parses a socket table of `/proc/net` (`tcp`, `tcp6`, `udp`, or `udp6`),
+/// returning the local address, the local port, and the inode of each socket
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_socket_table(content: &str) -> Vec<(IpAddr, u16, u64)> {
+    content
+        .lines()
+        // the first line is the header
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (address, port) = fields.get(1)?.split_once(':')?;
+            let inode: u64 = fields.get(9)?.parse().ok()?;
+            // sockets in TIME_WAIT state aren't owned by any process anymore
+            if inode == 0 {
+                return None;
+            }
+            Some((
+                parse_hex_address(address)?,
+                u16::from_str_radix(port, 16).ok()?,
+                inode,
+            ))
+        })
+        .collect()
+}
+
+/// Parses an address of `/proc/net`, printed as hex 32-bit words in host byte order
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_hex_address(address: &str) -> Option<IpAddr> {
+    let mut bytes = Vec::with_capacity(16);
+    for i in (0..address.len()).step_by(8) {
+        let word = u32::from_str_radix(address.get(i..i + 8)?, 16).ok()?;
+        bytes.extend_from_slice(&word.to_ne_bytes());
+    }
+    match bytes.len() {
+        4 => Some(IpAddr::from(<[u8; 4]>::try_from(bytes).ok()?)),
+        16 => Some(IpAddr::from(<[u8; 16]>::try_from(bytes).ok()?)),
+        _ => None,
+    }
+}
+
+/// Returns the inode of a file descriptor linking to a socket (e.g., `socket:[12345]`)
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn socket_inode(link: &str) -> Option<u64> {
+    link.strip_prefix("socket:[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use crate::networking::types::process_info::{
+        parse_socket_table, socket_inode, ProcessInfo, ProcessResolver, ProcessTable,
+    };
+    use crate::Protocol;
+
+    // addresses are printed in host byte order (these tests assume a little-endian host)
+    const TCP_TABLE: &str = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21345 1 0000000000000000 100 0 0 10 0
+   1: 0A01A8C0:C350 22D8B85D:01BB 01 00000000:00000000 02:000A7B1D 00000000  1000        0 98765 2 0000000000000000 20 4 30 10 -1
+   2: 0A01A8C0:C351 22D8B85D:01BB 06 00000000:00000000 03:00001770 00000000     0        0 0 3 0000000000000000";
+
+    const TCP6_TABLE: &str = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000000000000000000000000000:0016 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 17021 1 0000000000000000 100 0 0 10 0
+   1: B80D0120000000000000000001000000:D431 B80D0120000000000000000002000000:01BB 01 00000000:00000000 00:00000000 00000000  1000        0 55501 1 0000000000000000 20 4 30 10 -1";
+
+    #[test]
+    fn test_parse_socket_tables() {
+        assert_eq!(
+            parse_socket_table(TCP_TABLE),
+            vec![
+                (IpAddr::from([127, 0, 0, 1]), 631, 21345),
+                (IpAddr::from([192, 168, 1, 10]), 50000, 98765),
+            ]
+        );
+        assert_eq!(
+            parse_socket_table(TCP6_TABLE),
+            vec![
+                ("::".parse().unwrap(), 22, 17021),
+                ("2001:db8::1".parse().unwrap(), 54321, 55501),
+            ]
+        );
+        assert_eq!(parse_socket_table(""), vec![]);
+    }
+
+    #[test]
+    fn test_socket_inode() {
+        assert_eq!(socket_inode("socket:[98765]"), Some(98765));
+        assert_eq!(socket_inode("pipe:[98765]"), None);
+        assert_eq!(socket_inode("/dev/null"), None);
+    }
+
+    #[test]
+    fn test_find_process() {
+        let mut table = ProcessTable::default();
+        let firefox = ProcessInfo {
+            pid: 4242,
+            name: "firefox".to_string(),
+            executable: Some("/usr/lib/firefox/firefox".to_string()),
+            netns: None,
+            container: None,
+        };
+        let sshd = ProcessInfo {
+            pid: 812,
+            name: "sshd".to_string(),
+            executable: None,
+            netns: None,
+            container: None,
+        };
+        for (address, port, inode) in parse_socket_table(TCP_TABLE)
+            .into_iter()
+            .chain(parse_socket_table(TCP6_TABLE))
+        {
+            table.sockets.insert((Protocol::TCP, address, port), inode);
+        }
+        table.processes.insert(98765, firefox.clone());
+        table.processes.insert(17021, sshd.clone());
+
+        let find = |address: &str, port: u16| {
+            table
+                .find(Protocol::TCP, address.parse().unwrap(), port)
+                .cloned()
+        };
+        assert_eq!(find("192.168.1.10", 50000), Some(firefox));
+        // sshd listens on all the IPv6 (and IPv4-mapped) addresses
+        assert_eq!(find("192.168.1.10", 22), Some(sshd.clone()));
+        assert_eq!(find("fe80::1", 22), Some(sshd.clone()));
+        // the process of the IPP socket is unknown
+        assert_eq!(find("127.0.0.1", 631), None);
+        assert_eq!(find("192.168.1.10", 50001), None);
+        assert_eq!(sshd.to_string(), "sshd (PID 812)");
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_lookup_own_socket() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let resolver = ProcessResolver::default();
+        // the first lookup misses, and requests a refresh to the background thread
+        let mut process = None;
+        for _ in 0..50 {
+            process = resolver.lookup(Protocol::TCP, "127.0.0.1", port);
+            if process.is_some() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        let process = process.unwrap();
+        assert_eq!(process.pid, std::process::id());
+        assert!(!process.name.is_empty());
+        assert!(process.netns.is_some());
+        // UDP sockets are looked up separately
+        assert_eq!(resolver.lookup(Protocol::UDP, "127.0.0.1", port), None);
+    }
+}
@@ -10,16 +10,200 @@ pub enum Protocol {
     UDP,
     /// Internet Control Message Protocol
     ICMP,
+    /// Any other IP protocol, identified by its number
+    Other(u8),
+    /// Used by filters to represent all the IP protocols other than TCP, UDP, and ICMP
+    AnyOther,
 }
 
 impl std::fmt::Display for Protocol {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Protocol::Other(number) => match iana_name(*number) {
+                Some(name) => write!(f, "{name}"),
+                None => write!(f, "{number}"),
+            },
+            Protocol::AnyOther => write!(f, "Other"),
+            _ => write!(f, "{self:?}"),
+        }
     }
 }
 
 impl Protocol {
-    pub const ALL: [Protocol; 3] = [Protocol::TCP, Protocol::UDP, Protocol::ICMP];
+    /// Protocols selectable as filters
+    pub const ALL: [Protocol; 4] = [
+        Protocol::TCP,
+        Protocol::UDP,
+        Protocol::ICMP,
+        Protocol::AnyOther,
+    ];
+
+    /// Returns the entry of `Protocol::ALL` that represents this protocol
+    pub fn filter_class(self) -> Protocol {
+        match self {
+            Protocol::Other(_) => Protocol::AnyOther,
+            protocol => protocol,
+        }
+    }
+
+    /// Same as `to_string`, but the number of other IP protocols is also reported along their name
+    pub fn to_string_with_number(self) -> String {
+        match self {
+            Protocol::Other(number) if iana_name(number).is_some() => format!("{self} ({number})"),
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// IANA keyword of the IP protocol with the given number
+/// (based on <https://www.iana.org/assignments/protocol-numbers> (2024-09-20))
+#[allow(clippy::too_many_lines)]
+fn iana_name(number: u8) -> Option<&'static str> {
+    let name = match number {
+        0 => "HOPOPT",
+        1 => "ICMP",
+        2 => "IGMP",
+        3 => "GGP",
+        4 => "IPv4",
+        5 => "ST",
+        6 => "TCP",
+        7 => "CBT",
+        8 => "EGP",
+        9 => "IGP",
+        10 => "BBN-RCC-MON",
+        11 => "NVP-II",
+        12 => "PUP",
+        13 => "ARGUS",
+        14 => "EMCON",
+        15 => "XNET",
+        16 => "CHAOS",
+        17 => "UDP",
+        18 => "MUX",
+        19 => "DCN-MEAS",
+        20 => "HMP",
+        21 => "PRM",
+        22 => "XNS-IDP",
+        23 => "TRUNK-1",
+        24 => "TRUNK-2",
+        25 => "LEAF-1",
+        26 => "LEAF-2",
+        27 => "RDP",
+        28 => "IRTP",
+        29 => "ISO-TP4",
+        30 => "NETBLT",
+        31 => "MFE-NSP",
+        32 => "MERIT-INP",
+        33 => "DCCP",
+        34 => "3PC",
+        35 => "IDPR",
+        36 => "XTP",
+        37 => "DDP",
+        38 => "IDPR-CMTP",
+        39 => "TP++",
+        40 => "IL",
+        41 => "IPv6",
+        42 => "SDRP",
+        43 => "IPv6-Route",
+        44 => "IPv6-Frag",
+        45 => "IDRP",
+        46 => "RSVP",
+        47 => "GRE",
+        48 => "DSR",
+        49 => "BNA",
+        50 => "ESP",
+        51 => "AH",
+        52 => "I-NLSP",
+        53 => "SWIPE",
+        54 => "NARP",
+        55 => "Min-IPv4",
+        56 => "TLSP",
+        57 => "SKIP",
+        58 => "IPv6-ICMP",
+        59 => "IPv6-NoNxt",
+        60 => "IPv6-Opts",
+        62 => "CFTP",
+        64 => "SAT-EXPAK",
+        65 => "KRYPTOLAN",
+        66 => "RVD",
+        67 => "IPPC",
+        69 => "SAT-MON",
+        70 => "VISA",
+        71 => "IPCV",
+        72 => "CPNX",
+        73 => "CPHB",
+        74 => "WSN",
+        75 => "PVP",
+        76 => "BR-SAT-MON",
+        77 => "SUN-ND",
+        78 => "WB-MON",
+        79 => "WB-EXPAK",
+        80 => "ISO-IP",
+        81 => "VMTP",
+        82 => "SECURE-VMTP",
+        83 => "VINES",
+        84 => "IPTM",
+        85 => "NSFNET-IGP",
+        86 => "DGP",
+        87 => "TCF",
+        88 => "EIGRP",
+        89 => "OSPFIGP",
+        90 => "Sprite-RPC",
+        91 => "LARP",
+        92 => "MTP",
+        93 => "AX.25",
+        94 => "IPIP",
+        95 => "MICP",
+        96 => "SCC-SP",
+        97 => "ETHERIP",
+        98 => "ENCAP",
+        100 => "GMTP",
+        101 => "IFMP",
+        102 => "PNNI",
+        103 => "PIM",
+        104 => "ARIS",
+        105 => "SCPS",
+        106 => "QNX",
+        107 => "A/N",
+        108 => "IPComp",
+        109 => "SNP",
+        110 => "Compaq-Peer",
+        111 => "IPX-in-IP",
+        112 => "VRRP",
+        113 => "PGM",
+        115 => "L2TP",
+        116 => "DDX",
+        117 => "IATP",
+        118 => "STP",
+        119 => "SRP",
+        120 => "UTI",
+        121 => "SMP",
+        122 => "SM",
+        123 => "PTP",
+        124 => "ISIS",
+        125 => "FIRE",
+        126 => "CRTP",
+        127 => "CRUDP",
+        128 => "SSCOPMCE",
+        129 => "IPLT",
+        130 => "SPS",
+        131 => "PIPE",
+        132 => "SCTP",
+        133 => "FC",
+        134 => "RSVP-E2E-IGNORE",
+        135 => "Mobility Header",
+        136 => "UDPLite",
+        137 => "MPLS-in-IP",
+        138 => "manet",
+        139 => "HIP",
+        140 => "Shim6",
+        141 => "WESP",
+        142 => "ROHC",
+        143 => "Ethernet",
+        144 => "AGGFRAG",
+        145 => "NSH",
+        _ => return None,
+    };
+    Some(name)
 }
 
 #[cfg(test)]
@@ -33,15 +217,49 @@ fn test_protocol_display() {
                 Protocol::TCP => assert_eq!(protocol.to_string(), "TCP"),
                 Protocol::UDP => assert_eq!(protocol.to_string(), "UDP"),
                 Protocol::ICMP => assert_eq!(protocol.to_string(), "ICMP"),
+                Protocol::AnyOther => assert_eq!(protocol.to_string(), "Other"),
+                Protocol::Other(_) => panic!(),
             }
         }
     }
 
+    #[test]
+    fn test_other_protocol_display() {
+        assert_eq!(Protocol::Other(2).to_string(), "IGMP");
+        assert_eq!(Protocol::Other(47).to_string(), "GRE");
+        assert_eq!(Protocol::Other(50).to_string(), "ESP");
+        assert_eq!(Protocol::Other(51).to_string(), "AH");
+        assert_eq!(Protocol::Other(89).to_string(), "OSPFIGP");
+        assert_eq!(Protocol::Other(132).to_string(), "SCTP");
+        assert_eq!(Protocol::Other(200).to_string(), "200");
+        assert_eq!(Protocol::Other(253).to_string(), "253");
+        assert_eq!(Protocol::Other(255).to_string(), "255");
+    }
+
+    #[test]
+    fn test_protocol_to_string_with_number() {
+        assert_eq!(Protocol::TCP.to_string_with_number(), "TCP");
+        assert_eq!(Protocol::ICMP.to_string_with_number(), "ICMP");
+        assert_eq!(Protocol::Other(132).to_string_with_number(), "SCTP (132)");
+        assert_eq!(Protocol::Other(146).to_string_with_number(), "146");
+    }
+
+    #[test]
+    fn test_protocol_filter_class() {
+        for protocol in Protocol::ALL {
+            assert_eq!(protocol.filter_class(), protocol);
+        }
+        assert_eq!(Protocol::Other(47).filter_class(), Protocol::AnyOther);
+        assert_eq!(Protocol::Other(0).filter_class(), Protocol::AnyOther);
+        assert_eq!(Protocol::Other(255).filter_class(), Protocol::AnyOther);
+    }
+
     #[test]
     fn test_all_protocols_collection() {
-        assert_eq!(Protocol::ALL.len(), 3);
+        assert_eq!(Protocol::ALL.len(), 4);
         assert_eq!(Protocol::ALL.get(0).unwrap(), &Protocol::TCP);
         assert_eq!(Protocol::ALL.get(1).unwrap(), &Protocol::UDP);
         assert_eq!(Protocol::ALL.get(2).unwrap(), &Protocol::ICMP);
+        assert_eq!(Protocol::ALL.get(3).unwrap(), &Protocol::AnyOther);
     }
 }
@@ -0,0 +1,438 @@
+//! Module in charge of decrypting the QUIC Initial packets sent by clients, to extract the
+//! TLS ClientHello they carry.
+//!
+//! The keys protecting Initial packets are derived from the Destination Connection ID chosen by the
+//! client, which is sent in clear (RFC 9001 for QUIC v1, RFC 9369 for QUIC v2).
+
+use std::collections::HashMap;
+
+use aes_gcm::aead::AeadInPlace;
+use aes_gcm::aes::cipher::{BlockEncrypt, KeyInit};
+use aes_gcm::aes::Aes128;
+use aes_gcm::{Aes128Gcm, Nonce, Tag};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::service::Service;
+use crate::networking::types::tls_client_hello::TlsClientHello;
+
+/// Port used by QUIC servers
+pub const QUIC_PORT: u16 = 443;
+/// Service associated to the connections identified as QUIC
+pub const QUIC_SERVICE: Service = Service::Name("quic/http3");
+
+/// Maximum number of Initial packets inspected to reassemble a ClientHello
+const MAX_INITIAL_PACKETS: usize = 4;
+/// Maximum number of ClientHello messages being reassembled at the same time
+const MAX_PENDING_REASSEMBLIES: usize = 1024;
+/// Maximum length of a connection ID
+const MAX_CID_LEN: usize = 20;
+/// Length of the authentication tag of AES-128-GCM
+const TAG_LEN: usize = 16;
+/// Length of the ciphertext sample used to compute the header protection mask
+const SAMPLE_LEN: usize = 16;
+
+/// Versions of QUIC whose Initial packets can be decrypted
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum QuicVersion {
+    V1,
+    V2,
+}
+
+impl QuicVersion {
+    fn from_u32(version: u32) -> Option<Self> {
+        match version {
+            0x0000_0001 => Some(Self::V1),
+            0x6b33_43cf => Some(Self::V2),
+            _ => None,
+        }
+    }
+
+    /// Value of the long header type bits identifying Initial packets
+    fn initial_packet_type(self) -> u8 {
+        match self {
+            Self::V1 => 0b00,
+            Self::V2 => 0b01,
+        }
+    }
+
+    fn initial_salt(self) -> [u8; 20] {
+        match self {
+            Self::V1 => [
+                0x38, 0x76, 0x2c, 0xf7, 0xf5, 0x59, 0x34, 0xb3, 0x4d, 0x17, 0x9a, 0xe6, 0xa4, 0xc8,
+                0x0c, 0xad, 0xcc, 0xbb, 0x7f, 0x0a,
+            ],
+            Self::V2 => [
+                0x0d, 0xed, 0xe3, 0xde, 0xf7, 0x00, 0xa6, 0xdb, 0x81, 0x93, 0x81, 0xbe, 0x6e, 0x26,
+                0x9d, 0xcb, 0xf9, 0xbd, 0x2e, 0xd9,
+            ],
+        }
+    }
+
+    /// Labels used to derive key, IV, and header protection key from the client secret
+    fn key_labels(self) -> [&'static str; 3] {
+        match self {
+            Self::V1 => ["quic key", "quic iv", "quic hp"],
+            Self::V2 => ["quicv2 key", "quicv2 iv", "quicv2 hp"],
+        }
+    }
+}
+
+/// Keys protecting the Initial packets sent by a client
+struct InitialKeys {
+    key: [u8; 16],
+    iv: [u8; 12],
+    hp: [u8; 16],
+}
+
+impl InitialKeys {
+    fn derive(version: QuicVersion, dcid: &[u8]) -> Option<Self> {
+        let initial = Hkdf::<Sha256>::new(Some(&version.initial_salt()), dcid);
+        let mut client_secret = [0; 32];
+        expand_label(&initial, "client in", &mut client_secret)?;
+
+        let client = Hkdf::<Sha256>::from_prk(&client_secret).ok()?;
+        let [key_label, iv_label, hp_label] = version.key_labels();
+        let mut keys = Self {
+            key: [0; 16],
+            iv: [0; 12],
+            hp: [0; 16],
+        };
+        expand_label(&client, key_label, &mut keys.key)?;
+        expand_label(&client, iv_label, &mut keys.iv)?;
+        expand_label(&client, hp_label, &mut keys.hp)?;
+        Some(keys)
+    }
+}
+
+/// Client Initial packet sniffed on the wire
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QuicClientInitial {
+    /// The ClientHello is not complete yet
+    Partial,
+    /// The ClientHello was completed by this packet
+    Complete(TlsClientHello),
+}
+
+/// Portion of the CRYPTO stream received so far
+#[derive(Default)]
+struct CryptoStream {
+    /// Data of the CRYPTO frames, with their offset
+    fragments: Vec<(usize, Vec<u8>)>,
+    /// Number of Initial packets received
+    packets: usize,
+}
+
+/// Collects the CRYPTO frames of the Initial packets sent by QUIC clients, to extract their ClientHello
+#[derive(Default)]
+pub struct QuicClientHelloReassembler {
+    /// CRYPTO streams of the connections whose ClientHello is incomplete
+    streams: HashMap<AddressPortPair, CryptoStream>,
+}
+
+impl QuicClientHelloReassembler {
+    /// Feeds a UDP datagram sent by a client over the connection identified by `key`.
+    ///
+    /// Returns None if the datagram doesn't start with a client Initial packet that can be decrypted.
+    pub fn feed(&mut self, key: &AddressPortPair, datagram: &[u8]) -> Option<QuicClientInitial> {
+        let plaintext = decrypt_client_initial(datagram)?;
+        let fragments = crypto_fragments(&plaintext);
+        if fragments.is_empty() {
+            return Some(QuicClientInitial::Partial);
+        }
+
+        if !self.streams.contains_key(key) && self.streams.len() >= MAX_PENDING_REASSEMBLIES {
+            self.streams.clear();
+        }
+        let stream = self.streams.entry(key.clone()).or_default();
+        stream.fragments.extend(
+            fragments
+                .into_iter()
+                .map(|(offset, data)| (offset, data.to_vec())),
+        );
+        stream.packets += 1;
+
+        let assembled = assemble(&mut stream.fragments);
+        let handshake_len = assembled.get(1..4).map(|len| {
+            4 + (usize::from(len[0]) << 16 | usize::from(len[1]) << 8 | usize::from(len[2]))
+        });
+        let is_complete =
+            handshake_len.is_some_and(|handshake_len| assembled.len() >= handshake_len);
+        if !is_complete && stream.packets < MAX_INITIAL_PACKETS {
+            return Some(QuicClientInitial::Partial);
+        }
+
+        self.streams.remove(key);
+        Some(match TlsClientHello::parse_handshake(&assembled) {
+            Some(client_hello) => QuicClientInitial::Complete(client_hello),
+            None => QuicClientInitial::Partial,
+        })
+    }
+}
+
+/// Removes header protection from the client Initial packet at the start of the given datagram,
+/// and returns its decrypted payload
+fn decrypt_client_initial(datagram: &[u8]) -> Option<Vec<u8>> {
+    let first_byte = *datagram.first()?;
+    // long header with the fixed bit set
+    if first_byte & 0xc0 != 0xc0 {
+        return None;
+    }
+    let version = QuicVersion::from_u32(u32::from_be_bytes(datagram.get(1..5)?.try_into().ok()?))?;
+    if (first_byte >> 4) & 0x03 != version.initial_packet_type() {
+        return None;
+    }
+
+    let dcid_len = usize::from(*datagram.get(5)?);
+    if dcid_len > MAX_CID_LEN {
+        return None;
+    }
+    let dcid = datagram.get(6..6 + dcid_len)?;
+    let mut offset = 6 + dcid_len;
+    let scid_len = usize::from(*datagram.get(offset)?);
+    if scid_len > MAX_CID_LEN {
+        return None;
+    }
+    offset += 1 + scid_len;
+    let (token_len, next) = read_varint(datagram, offset)?;
+    offset = next.checked_add(usize::try_from(token_len).ok()?)?;
+    let (length, pn_offset) = read_varint(datagram, offset)?;
+    let packet_end = pn_offset.checked_add(usize::try_from(length).ok()?)?;
+
+    let keys = InitialKeys::derive(version, dcid)?;
+
+    // remove header protection
+    let sample = datagram.get(pn_offset + 4..pn_offset + 4 + SAMPLE_LEN)?;
+    let mut mask = *aes_gcm::aes::Block::from_slice(sample);
+    Aes128::new(&keys.hp.into()).encrypt_block(&mut mask);
+    let first_byte = first_byte ^ (mask[0] & 0x0f);
+    let pn_len = usize::from(first_byte & 0x03) + 1;
+    let mut header = datagram.get(..pn_offset + pn_len)?.to_vec();
+    header[0] = first_byte;
+    let mut packet_number = 0;
+    for (i, byte) in header[pn_offset..].iter_mut().enumerate() {
+        *byte ^= mask[1 + i];
+        packet_number = (packet_number << 8) | u64::from(*byte);
+    }
+
+    // decrypt the payload
+    let protected_payload = datagram.get(pn_offset + pn_len..packet_end)?;
+    let (ciphertext, tag) =
+        protected_payload.split_at(protected_payload.len().checked_sub(TAG_LEN)?);
+    let mut nonce = keys.iv;
+    for (nonce_byte, pn_byte) in nonce[4..].iter_mut().zip(packet_number.to_be_bytes()) {
+        *nonce_byte ^= pn_byte;
+    }
+    let mut plaintext = ciphertext.to_vec();
+    Aes128Gcm::new(&keys.key.into())
+        .decrypt_in_place_detached(
+            Nonce::from_slice(&nonce),
+            &header,
+            &mut plaintext,
+            Tag::from_slice(tag),
+        )
+        .ok()?;
+    Some(plaintext)
+}
+
+/// Returns the data carried by the CRYPTO frames of a decrypted Initial packet, with their offset
+fn crypto_fragments(plaintext: &[u8]) -> Vec<(usize, &[u8])> {
+    let mut fragments = Vec::new();
+    let mut offset = 0;
+    while let Some((frame_type, next)) = read_varint(plaintext, offset) {
+        offset = next;
+        match frame_type {
+            // PADDING, PING
+            0x00 | 0x01 => {}
+            // ACK
+            0x02 | 0x03 => {
+                let Some(next) = skip_ack_frame(plaintext, offset, frame_type == 0x03) else {
+                    break;
+                };
+                offset = next;
+            }
+            // CRYPTO
+            0x06 => {
+                let Some((data_offset, next)) = read_varint(plaintext, offset) else {
+                    break;
+                };
+                let Some((data_len, next)) = read_varint(plaintext, next) else {
+                    break;
+                };
+                let (Ok(data_offset), Ok(data_len)) =
+                    (usize::try_from(data_offset), usize::try_from(data_len))
+                else {
+                    break;
+                };
+                let Some(data) = next
+                    .checked_add(data_len)
+                    .and_then(|end| plaintext.get(next..end))
+                else {
+                    break;
+                };
+                fragments.push((data_offset, data));
+                offset = next + data_len;
+            }
+            // no other frame is expected in the client Initial packets carrying the ClientHello
+            _ => break,
+        }
+    }
+    fragments
+}
+
+/// Returns the offset of the first byte after the ACK frame whose fields start at `offset`
+fn skip_ack_frame(bytes: &[u8], offset: usize, with_ecn_counts: bool) -> Option<usize> {
+    // largest acknowledged, ACK delay
+    let (_, offset) = read_varint(bytes, offset)?;
+    let (_, offset) = read_varint(bytes, offset)?;
+    let (range_count, mut offset) = read_varint(bytes, offset)?;
+    // first ACK range, then a gap and a length for each further range
+    let fields = 1 + 2 * range_count + if with_ecn_counts { 3 } else { 0 };
+    for _ in 0..fields {
+        offset = read_varint(bytes, offset)?.1;
+    }
+    Some(offset)
+}
+
+/// Concatenates the fragments of a CRYPTO stream, as long as they are contiguous from its start
+fn assemble(fragments: &mut [(usize, Vec<u8>)]) -> Vec<u8> {
+    fragments.sort_by_key(|(offset, _)| *offset);
+    let mut assembled = Vec::new();
+    for (offset, data) in fragments.iter() {
+        if *offset > assembled.len() {
+            break;
+        }
+        let overlap = assembled.len() - offset;
+        if let Some(new_data) = data.get(overlap..) {
+            assembled.extend_from_slice(new_data);
+        }
+    }
+    assembled
+}
+
+/// Reads the variable-length integer starting at `offset`.
+/// Returns the integer and the offset of the first byte after it.
+fn read_varint(bytes: &[u8], offset: usize) -> Option<(u64, usize)> {
+    let first_byte = *bytes.get(offset)?;
+    let len = 1 << (first_byte >> 6);
+    let mut value = u64::from(first_byte & 0x3f);
+    for byte in bytes.get(offset + 1..offset + len)? {
+        value = (value << 8) | u64::from(*byte);
+    }
+    Some((value, offset + len))
+}
+
+/// HKDF-Expand-Label, as defined by TLS 1.3 (with an empty context)
+fn expand_label(hkdf: &Hkdf<Sha256>, label: &str, okm: &mut [u8]) -> Option<()> {
+    let full_label = ["tls13 ", label].concat();
+    let mut info = u16::try_from(okm.len()).ok()?.to_be_bytes().to_vec();
+    info.push(u8::try_from(full_label.len()).ok()?);
+    info.extend_from_slice(full_label.as_bytes());
+    info.push(0);
+    hkdf.expand(&info, okm).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::quic_initial::{
+        assemble, crypto_fragments, read_varint, InitialKeys, QuicVersion,
+    };
+
+    #[test]
+    fn test_initial_keys_v1() {
+        // RFC 9001, Appendix A.1
+        let keys = InitialKeys::derive(
+            QuicVersion::V1,
+            &[0x83, 0x94, 0xc8, 0xf0, 0x3e, 0x51, 0x57, 0x08],
+        )
+        .unwrap();
+        assert_eq!(
+            keys.key,
+            [
+                0x1f, 0x36, 0x96, 0x13, 0xdd, 0x76, 0xd5, 0x46, 0x77, 0x30, 0xef, 0xcb, 0xe3, 0xb1,
+                0xa2, 0x2d
+            ]
+        );
+        assert_eq!(
+            keys.iv,
+            [0xfa, 0x04, 0x4b, 0x2f, 0x42, 0xa3, 0xfd, 0x3b, 0x46, 0xfb, 0x25, 0x5c]
+        );
+        assert_eq!(
+            keys.hp,
+            [
+                0x9f, 0x50, 0x44, 0x9e, 0x04, 0xa0, 0xe8, 0x10, 0x28, 0x3a, 0x1e, 0x99, 0x33, 0xad,
+                0xed, 0xd2
+            ]
+        );
+    }
+
+    #[test]
+    fn test_initial_keys_v2() {
+        // RFC 9369, Appendix A.1
+        let keys = InitialKeys::derive(
+            QuicVersion::V2,
+            &[0x83, 0x94, 0xc8, 0xf0, 0x3e, 0x51, 0x57, 0x08],
+        )
+        .unwrap();
+        assert_eq!(
+            keys.key,
+            [
+                0x8b, 0x1a, 0x0b, 0xc1, 0x21, 0x28, 0x42, 0x90, 0xa2, 0x9e, 0x09, 0x71, 0xb5, 0xcd,
+                0x04, 0x5d
+            ]
+        );
+        assert_eq!(
+            keys.iv,
+            [0x91, 0xf7, 0x3e, 0x23, 0x51, 0xd8, 0xfa, 0x91, 0x66, 0x0e, 0x90, 0x9f]
+        );
+        assert_eq!(
+            keys.hp,
+            [
+                0x45, 0xb9, 0x5e, 0x15, 0x23, 0x5d, 0x6f, 0x45, 0xa6, 0xb1, 0x9c, 0xbc, 0xb0, 0x29,
+                0x4b, 0xa9
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_varint() {
+        // RFC 9000, Appendix A.1
+        assert_eq!(
+            read_varint(&[0xc2, 0x19, 0x7c, 0x5e, 0xff, 0x14, 0xe8, 0x8c], 0),
+            Some((151_288_809_941_952_652, 8))
+        );
+        assert_eq!(
+            read_varint(&[0x9d, 0x7f, 0x3e, 0x7d], 0),
+            Some((494_878_333, 4))
+        );
+        assert_eq!(read_varint(&[0x7b, 0xbd], 0), Some((15_293, 2)));
+        assert_eq!(read_varint(&[0x25], 0), Some((37, 1)));
+        assert_eq!(read_varint(&[0x40, 0x25], 0), Some((37, 2)));
+        assert_eq!(read_varint(&[0x7b], 0), None);
+    }
+
+    #[test]
+    fn test_crypto_fragments() {
+        let plaintext = [
+            0x06, 0x05, 0x03, b'd', b'e', b'f', // CRYPTO (offset 5)
+            0x01, // PING
+            0x02, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00, // ACK with two ranges
+            0x06, 0x00, 0x05, b'a', b'b', b'c', b'd', b'e', // CRYPTO (offset 0)
+            0x00, 0x00, // PADDING
+            0x06, 0x08, 0x04, b'x', // truncated CRYPTO
+        ];
+        let fragments = crypto_fragments(&plaintext);
+        assert_eq!(fragments, vec![(5, &b"def"[..]), (0, &b"abcde"[..])]);
+
+        let mut fragments: Vec<(usize, Vec<u8>)> = fragments
+            .into_iter()
+            .map(|(offset, data)| (offset, data.to_vec()))
+            .collect();
+        assert_eq!(assemble(&mut fragments), b"abcdedef".to_vec());
+        // overlapping fragments are merged, and a gap stops the reassembly
+        fragments.push((4, b"ede".to_vec()));
+        fragments.push((10, b"zz".to_vec()));
+        assert_eq!(assemble(&mut fragments), b"abcdedef".to_vec());
+    }
+}
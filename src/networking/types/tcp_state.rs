@@ -0,0 +1,436 @@
+//! Module defining the structs and enums used to track the state and the performance of TCP connections.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use etherparse::TcpHeader;
+
+use crate::networking::types::os_fingerprint::TcpSignature;
+
+/// Handshake and teardown flags sent by one of the endpoints of a TCP connection
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct TcpFlags {
+    /// SYN without ACK (connection request)
+    pub syn: bool,
+    /// SYN with ACK (connection request accepted)
+    pub syn_ack: bool,
+    /// ACK without SYN
+    pub ack: bool,
+    /// FIN
+    pub fin: bool,
+    /// RST
+    pub rst: bool,
+}
+
+impl TcpFlags {
+    pub fn from_etherparse(tcp_header: &TcpHeader) -> Self {
+        Self {
+            syn: tcp_header.syn && !tcp_header.ack,
+            syn_ack: tcp_header.syn && tcp_header.ack,
+            ack: !tcp_header.syn && tcp_header.ack,
+            fin: tcp_header.fin,
+            rst: tcp_header.rst,
+        }
+    }
+
+    /// Adds the flags set in `other` to the flags of `self`
+    pub fn merge(&mut self, other: TcpFlags) {
+        self.syn |= other.syn;
+        self.syn_ack |= other.syn_ack;
+        self.ack |= other.ack;
+        self.fin |= other.fin;
+        self.rst |= other.rst;
+    }
+}
+
+impl fmt::Display for TcpFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let flags: Vec<&str> = [
+            (self.syn || self.syn_ack, "SYN"),
+            (self.syn_ack || self.ack, "ACK"),
+            (self.fin, "FIN"),
+            (self.rst, "RST"),
+        ]
+        .into_iter()
+        .filter_map(|(is_set, flag)| is_set.then_some(flag))
+        .collect();
+        if flags.is_empty() {
+            write!(f, "-")
+        } else {
+            write!(f, "{}", flags.join(", "))
+        }
+    }
+}
+
+/// Flags exchanged in a TCP connection, from the point of view of its source endpoint
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct TcpFlagsSeen {
+    /// Flags sent by the source
+    pub sent: TcpFlags,
+    /// Flags received by the source (i.e., sent by the destination)
+    pub received: TcpFlags,
+}
+
+impl TcpFlagsSeen {
+    /// Derives the state of the connection from the exchanged flags
+    pub fn state(&self) -> TcpState {
+        let (sent, received) = (self.sent, self.received);
+        let handshake_started = sent.syn || received.syn || sent.syn_ack || received.syn_ack;
+        let handshake_completed = (sent.syn && received.syn_ack && sent.ack)
+            || (received.syn && sent.syn_ack && received.ack);
+        // connections already in progress when the capture started are considered synchronized
+        let synchronized = handshake_completed || !handshake_started;
+        let syn_ack_seen = sent.syn_ack || received.syn_ack;
+
+        if sent.rst || received.rst {
+            if !synchronized && syn_ack_seen {
+                TcpState::HalfOpen
+            } else {
+                TcpState::Reset
+            }
+        } else if sent.fin && received.fin {
+            TcpState::Closed
+        } else if sent.fin || received.fin {
+            TcpState::Closing
+        } else if synchronized {
+            TcpState::Established
+        } else if syn_ack_seen {
+            TcpState::HalfOpen
+        } else {
+            TcpState::Opening
+        }
+    }
+}
+
+/// Details of a TCP segment, relevant to track the connection it belongs to
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct TcpSegment {
+    /// Handshake and teardown flags
+    pub flags: TcpFlags,
+    /// Sequence number
+    pub seq: u32,
+    /// Acknowledgment number (this is None if the ACK flag isn't set)
+    pub ack: Option<u32>,
+    /// Sequence space occupied by the segment (payload length, plus one for SYN and for FIN)
+    pub len: u32,
+    /// Fingerprint of the sender (only for SYN and SYN+ACK segments)
+    pub signature: Option<TcpSignature>,
+}
+
+impl TcpSegment {
+    /// The segment length is derived from `ip_payload_len`, as declared by the IP header,
+    /// since the captured payload may be truncated; `ttl` is the TTL (or hop limit) of the IP header.
+    pub fn from_etherparse(tcp_header: &TcpHeader, ip_payload_len: u32, ttl: u8) -> Self {
+        let payload_len = ip_payload_len.saturating_sub(u32::from(tcp_header.header_len_u16()));
+        Self {
+            flags: TcpFlags::from_etherparse(tcp_header),
+            seq: tcp_header.sequence_number,
+            ack: tcp_header.ack.then_some(tcp_header.acknowledgment_number),
+            len: payload_len + u32::from(tcp_header.syn) + u32::from(tcp_header.fin),
+            signature: TcpSignature::from_etherparse(tcp_header, ttl),
+        }
+    }
+
+    /// Length of the payload, as declared by the headers
+    pub fn payload_len(&self) -> u32 {
+        let syn = self.flags.syn || self.flags.syn_ack;
+        self.len - u32::from(syn) - u32::from(self.flags.fin)
+    }
+}
+
+/// Statistics about the round-trip time samples of a TCP connection, expressed in microseconds
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RttStats {
+    pub min: i64,
+    pub max: i64,
+    sum: i64,
+    count: i64,
+}
+
+impl RttStats {
+    pub fn add_sample(&mut self, rtt: i64) {
+        if self.count == 0 || rtt < self.min {
+            self.min = rtt;
+        }
+        if self.count == 0 || rtt > self.max {
+            self.max = rtt;
+        }
+        self.sum += rtt;
+        self.count += 1;
+    }
+
+    pub fn average(&self) -> Option<i64> {
+        (self.count > 0).then(|| self.sum / self.count)
+    }
+}
+
+/// Round-trip time and retransmission metrics of the segments sent by one of the endpoints of a TCP connection
+#[derive(Clone, Default, Debug)]
+pub struct TcpMetrics {
+    /// Sequence number following the highest segment sent
+    next_seq: Option<u32>,
+    /// Timestamp of the last segment that advanced the sequence number (µs)
+    last_advance_timestamp: i64,
+    /// Timestamp of the last SYN or SYN-ACK sent (µs)
+    syn_timestamp: Option<i64>,
+    /// Segments not acknowledged yet: sequence number following each segment, and its timestamp (µs)
+    unacked: VecDeque<(u32, i64)>,
+    /// Number of segments occupying sequence space
+    pub segments: u128,
+    /// Number of segments sent again
+    pub retransmissions: u128,
+    /// Number of segments sent after a segment following them in the sequence space
+    pub out_of_order: u128,
+    /// Round-trip times, measured from a segment to its acknowledgment
+    pub rtt: RttStats,
+    /// Round-trip time of the three-way handshake (SYN to SYN-ACK to ACK), in microseconds
+    pub handshake_rtt: Option<i64>,
+}
+
+impl TcpMetrics {
+    /// Maximum number of segments waiting for an acknowledgment to keep track of
+    const MAX_UNACKED: usize = 1024;
+    /// Minimum delay (µs) after which a segment behind the sequence space is considered retransmitted
+    const MIN_RETRANSMISSION_DELAY: i64 = 3_000;
+
+    /// Updates the metrics with a segment sent by the endpoint
+    pub fn on_segment_sent(&mut self, segment: &TcpSegment, timestamp: i64) {
+        if segment.flags.syn || segment.flags.syn_ack {
+            self.syn_timestamp = Some(timestamp);
+        }
+        if segment.len == 0 {
+            return;
+        }
+        self.segments += 1;
+        let end = segment.seq.wrapping_add(segment.len);
+
+        match self.next_seq {
+            Some(next_seq) if seq_before(segment.seq, next_seq) => {
+                // a segment that arrives shortly after a higher one is considered reordered
+                let threshold = self.rtt.min.max(Self::MIN_RETRANSMISSION_DELAY);
+                if timestamp - self.last_advance_timestamp < threshold {
+                    self.out_of_order += 1;
+                } else {
+                    self.retransmissions += 1;
+                    // Karn's algorithm: ambiguous acknowledgments don't produce RTT samples
+                    self.unacked
+                        .retain(|(unacked_end, _)| !seq_before(segment.seq, *unacked_end));
+                }
+                if seq_before(next_seq, end) {
+                    self.next_seq = Some(end);
+                }
+            }
+            _ => {
+                self.next_seq = Some(end);
+                self.last_advance_timestamp = timestamp;
+                if self.unacked.len() < Self::MAX_UNACKED {
+                    self.unacked.push_back((end, timestamp));
+                }
+            }
+        }
+    }
+
+    /// Updates the metrics with an acknowledgment sent by the other endpoint
+    pub fn on_ack_received(&mut self, ack: u32, timestamp: i64) {
+        let mut acked_timestamp = None;
+        while let Some(&(end, segment_timestamp)) = self.unacked.front() {
+            if seq_before(ack, end) {
+                break;
+            }
+            acked_timestamp = Some(segment_timestamp);
+            self.unacked.pop_front();
+        }
+        if let Some(acked_timestamp) = acked_timestamp {
+            self.rtt.add_sample(timestamp - acked_timestamp);
+        }
+    }
+
+    /// Records the handshake round-trip time, when the given segment completes the three-way handshake
+    /// started by the endpoint; returns the measured value
+    pub fn on_handshake_ack(&mut self, flags_seen: &TcpFlagsSeen, timestamp: i64) -> Option<i64> {
+        if self.handshake_rtt.is_none() && flags_seen.sent.syn && flags_seen.received.syn_ack {
+            self.handshake_rtt = self.syn_timestamp.map(|syn| timestamp - syn);
+            return self.handshake_rtt;
+        }
+        None
+    }
+
+    /// Fraction of retransmitted segments
+    pub fn retransmission_rate(&self) -> f32 {
+        if self.segments == 0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let rate = self.retransmissions as f32 / self.segments as f32;
+        rate
+    }
+}
+
+/// Returns true if sequence number `a` precedes `b`, taking wrap-around into account
+fn seq_before(a: u32, b: u32) -> bool {
+    #[allow(clippy::cast_possible_wrap)]
+    let diff = a.wrapping_sub(b) as i32;
+    diff < 0
+}
+
+/// State of a TCP connection
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TcpState {
+    /// Connection requested, but not accepted yet
+    Opening,
+    /// Three-way handshake completed (or connection already in progress)
+    Established,
+    /// One of the endpoints requested to close the connection
+    Closing,
+    /// Both the endpoints closed the connection
+    Closed,
+    /// Connection aborted or refused
+    Reset,
+    /// Connection accepted, but the handshake was never completed
+    HalfOpen,
+}
+
+impl fmt::Display for TcpState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = match self {
+            TcpState::Opening => "Opening",
+            TcpState::Established => "Established",
+            TcpState::Closing => "Closing",
+            TcpState::Closed => "Closed",
+            TcpState::Reset => "Reset",
+            TcpState::HalfOpen => "Half-open",
+        };
+        write!(f, "{state}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::tcp_state::{
+        seq_before, TcpFlags, TcpFlagsSeen, TcpMetrics, TcpSegment, TcpState,
+    };
+
+    const SYN: TcpFlags = TcpFlags {
+        syn: true,
+        syn_ack: false,
+        ack: false,
+        fin: false,
+        rst: false,
+    };
+    const SYN_ACK: TcpFlags = TcpFlags {
+        syn: false,
+        syn_ack: true,
+        ack: false,
+        fin: false,
+        rst: false,
+    };
+    const ACK: TcpFlags = TcpFlags {
+        syn: false,
+        syn_ack: false,
+        ack: true,
+        fin: false,
+        rst: false,
+    };
+    const FIN: TcpFlags = TcpFlags {
+        syn: false,
+        syn_ack: false,
+        ack: true,
+        fin: true,
+        rst: false,
+    };
+    const RST: TcpFlags = TcpFlags {
+        syn: false,
+        syn_ack: false,
+        ack: false,
+        fin: false,
+        rst: true,
+    };
+
+    fn state_after(sent: &[TcpFlags], received: &[TcpFlags]) -> TcpState {
+        let mut flags_seen = TcpFlagsSeen::default();
+        for flags in sent {
+            flags_seen.sent.merge(*flags);
+        }
+        for flags in received {
+            flags_seen.received.merge(*flags);
+        }
+        flags_seen.state()
+    }
+
+    #[test]
+    fn test_tcp_state_handshake() {
+        assert_eq!(state_after(&[SYN], &[]), TcpState::Opening);
+        assert_eq!(state_after(&[SYN], &[SYN_ACK]), TcpState::HalfOpen);
+        assert_eq!(state_after(&[SYN, ACK], &[SYN_ACK]), TcpState::Established);
+        // same connection, seen from the other endpoint
+        assert_eq!(state_after(&[], &[SYN]), TcpState::Opening);
+        assert_eq!(state_after(&[SYN_ACK], &[SYN]), TcpState::HalfOpen);
+        assert_eq!(
+            state_after(&[SYN_ACK, ACK], &[SYN, ACK]),
+            TcpState::Established
+        );
+    }
+
+    #[test]
+    fn test_tcp_state_already_in_progress() {
+        assert_eq!(state_after(&[ACK], &[]), TcpState::Established);
+        assert_eq!(state_after(&[ACK], &[ACK]), TcpState::Established);
+        assert_eq!(state_after(&[ACK, FIN], &[ACK]), TcpState::Closing);
+        assert_eq!(state_after(&[ACK, RST], &[ACK]), TcpState::Reset);
+    }
+
+    #[test]
+    fn test_tcp_state_teardown() {
+        assert_eq!(
+            state_after(&[SYN, ACK, FIN], &[SYN_ACK, ACK]),
+            TcpState::Closing
+        );
+        assert_eq!(
+            state_after(&[SYN, ACK, FIN], &[SYN_ACK, ACK, FIN]),
+            TcpState::Closed
+        );
+        assert_eq!(
+            state_after(&[SYN, ACK, FIN], &[SYN_ACK, ACK, RST]),
+            TcpState::Reset
+        );
+    }
+
+    #[test]
+    fn test_tcp_state_refused_and_half_open_scan() {
+        // connection refused
+        assert_eq!(state_after(&[SYN], &[RST]), TcpState::Reset);
+        // half-open (SYN) scan: the scanner resets the connection after the SYN-ACK
+        assert_eq!(state_after(&[SYN, RST], &[SYN_ACK]), TcpState::HalfOpen);
+        assert_eq!(state_after(&[SYN_ACK], &[SYN, RST]), TcpState::HalfOpen);
+    }
+
+    #[test]
+    fn test_tcp_state_display() {
+        assert_eq!(TcpState::Established.to_string(), "Established");
+        assert_eq!(TcpState::HalfOpen.to_string(), "Half-open");
+    }
+
+    #[test]
+    fn test_tcp_metrics_sequence_wrap_around() {
+        assert!(seq_before(u32::MAX - 10, 5));
+        assert!(!seq_before(5, u32::MAX - 10));
+
+        let segment = |seq: u32| TcpSegment {
+            flags: ACK,
+            seq,
+            ack: Some(1),
+            len: 20,
+            signature: None,
+        };
+        let mut metrics = TcpMetrics::default();
+        metrics.on_segment_sent(&segment(u32::MAX - 9), 0);
+        metrics.on_segment_sent(&segment(10), 1_000);
+        metrics.on_ack_received(30, 5_000);
+        assert_eq!(metrics.rtt.average(), Some(4_000));
+        // sent again well after the original
+        metrics.on_segment_sent(&segment(10), 500_000);
+        assert_eq!(metrics.segments, 3);
+        assert_eq!(metrics.retransmissions, 1);
+        assert_eq!(metrics.out_of_order, 0);
+    }
+}
@@ -0,0 +1,333 @@
+//! Module defining the `TlsClientHello` struct, which contains the relevant content of a TLS
+//! ClientHello message sniffed on the wire.
+
+use std::collections::HashMap;
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+
+/// Content type of the TLS records carrying handshake messages
+const CONTENT_TYPE_HANDSHAKE: u8 = 0x16;
+/// Type of the ClientHello handshake message
+const HANDSHAKE_TYPE_CLIENT_HELLO: u8 = 0x01;
+/// Server Name Indication extension
+const EXTENSION_SERVER_NAME: u16 = 0;
+/// Application-Layer Protocol Negotiation extension
+const EXTENSION_ALPN: u16 = 16;
+
+/// Maximum number of bytes buffered to reassemble a ClientHello split across TCP segments
+const MAX_REASSEMBLY_LEN: usize = 16 * 1024;
+/// Maximum number of ClientHello messages being reassembled at the same time
+const MAX_PENDING_REASSEMBLIES: usize = 1024;
+
+/// Relevant content of a TLS ClientHello message
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TlsClientHello {
+    /// Server name requested by the client (SNI)
+    pub server_name: Option<String>,
+    /// Application protocols offered by the client (ALPN), in order of preference
+    pub alpn: Vec<String>,
+}
+
+impl TlsClientHello {
+    /// Parses a ClientHello carried by a TLS record.
+    ///
+    /// Since captured packets may be truncated, the extensions following an incomplete one are ignored.
+    /// Returns None if the bytes don't contain the beginning of a ClientHello.
+    pub fn parse_record(bytes: &[u8]) -> Option<Self> {
+        record_len(bytes)?;
+        Self::parse_handshake(bytes.get(5..)?)
+    }
+
+    /// Parses a ClientHello handshake message (as carried by a TLS record or by QUIC CRYPTO frames).
+    ///
+    /// Since captured packets may be truncated, the extensions following an incomplete one are ignored.
+    /// Returns None if the bytes don't contain the beginning of a ClientHello.
+    pub fn parse_handshake(bytes: &[u8]) -> Option<Self> {
+        if *bytes.first()? != HANDSHAKE_TYPE_CLIENT_HELLO {
+            return None;
+        }
+        // handshake header (4 bytes), client version (2 bytes), random (32 bytes)
+        let mut offset = 38;
+        // session ID
+        offset += 1 + usize::from(*bytes.get(offset)?);
+        // cipher suites
+        offset += 2 + usize::from(read_u16(bytes, offset)?);
+        // compression methods
+        offset += 1 + usize::from(*bytes.get(offset)?);
+
+        let mut client_hello = Self::default();
+        // a ClientHello without extensions is still valid
+        let Some(extensions_len) = read_u16(bytes, offset) else {
+            return Some(client_hello);
+        };
+        let extensions_end = offset + 2 + usize::from(extensions_len);
+        offset += 2;
+        while offset + 4 <= extensions_end {
+            let (Some(extension_type), Some(extension_len)) =
+                (read_u16(bytes, offset), read_u16(bytes, offset + 2))
+            else {
+                break;
+            };
+            let data_offset = offset + 4;
+            let Some(data) = bytes.get(data_offset..data_offset + usize::from(extension_len))
+            else {
+                break;
+            };
+            match extension_type {
+                EXTENSION_SERVER_NAME => client_hello.server_name = parse_server_name(data),
+                EXTENSION_ALPN => client_hello.alpn = parse_alpn(data),
+                _ => {}
+            }
+            offset = data_offset + usize::from(extension_len);
+        }
+        Some(client_hello)
+    }
+}
+
+/// Collects the TCP payloads of ClientHello messages split across multiple segments
+#[derive(Default)]
+pub struct ClientHelloReassembler {
+    /// Bytes received so far for each connection whose ClientHello is incomplete
+    buffers: HashMap<AddressPortPair, Vec<u8>>,
+}
+
+impl ClientHelloReassembler {
+    /// Feeds the payload of a TCP segment sent over the connection identified by `key`.
+    ///
+    /// `declared_len` is the payload length declared by the headers: if the captured payload is
+    /// shorter, the packet was truncated and the ClientHello is parsed as is, without waiting for more data.
+    ///
+    /// Returns the ClientHello once it has been completely received.
+    pub fn feed(
+        &mut self,
+        key: &AddressPortPair,
+        payload: &[u8],
+        declared_len: u32,
+    ) -> Option<TlsClientHello> {
+        if payload.is_empty() {
+            return None;
+        }
+        let is_truncated = u32::try_from(payload.len()).unwrap_or(u32::MAX) < declared_len;
+
+        let buffer = if let Some(buffer) = self.buffers.get_mut(key) {
+            buffer.extend_from_slice(payload);
+            buffer
+        } else {
+            let expected_len = record_len(payload)?;
+            if is_truncated || payload.len() >= expected_len {
+                return TlsClientHello::parse_record(payload);
+            }
+            if self.buffers.len() >= MAX_PENDING_REASSEMBLIES {
+                self.buffers.clear();
+            }
+            self.buffers
+                .entry(key.clone())
+                .or_default()
+                .extend_from_slice(payload);
+            return None;
+        };
+
+        let expected_len = record_len(buffer).unwrap_or_default();
+        if is_truncated || buffer.len() >= expected_len || buffer.len() >= MAX_REASSEMBLY_LEN {
+            let buffer = self.buffers.remove(key)?;
+            return TlsClientHello::parse_record(&buffer);
+        }
+        None
+    }
+}
+
+/// Returns the total length of the TLS record starting with the given bytes,
+/// if it's a handshake record carrying a ClientHello
+fn record_len(bytes: &[u8]) -> Option<usize> {
+    if *bytes.first()? != CONTENT_TYPE_HANDSHAKE
+        || *bytes.get(1)? != 0x03
+        || *bytes.get(5)? != HANDSHAKE_TYPE_CLIENT_HELLO
+    {
+        return None;
+    }
+    Some(5 + usize::from(read_u16(bytes, 3)?))
+}
+
+/// Reads the host name contained in the data of a Server Name Indication extension
+fn parse_server_name(data: &[u8]) -> Option<String> {
+    let list_len = usize::from(read_u16(data, 0)?);
+    let list = data.get(2..2 + list_len)?;
+    let mut offset = 0;
+    while offset + 3 <= list.len() {
+        let name_type = list[offset];
+        let name_len = usize::from(read_u16(list, offset + 1)?);
+        let name = list.get(offset + 3..offset + 3 + name_len)?;
+        // host_name
+        if name_type == 0 {
+            return Some(name.escape_ascii().to_string().to_lowercase());
+        }
+        offset += 3 + name_len;
+    }
+    None
+}
+
+/// Reads the protocol names contained in the data of an ALPN extension
+fn parse_alpn(data: &[u8]) -> Vec<String> {
+    let mut protocols = Vec::new();
+    let Some(list_len) = read_u16(data, 0) else {
+        return protocols;
+    };
+    let list = data.get(2..).unwrap_or_default();
+    let list = &list[..list.len().min(usize::from(list_len))];
+    let mut offset = 0;
+    while let Some(len) = list.get(offset) {
+        let Some(protocol) = list.get(offset + 1..offset + 1 + usize::from(*len)) else {
+            break;
+        };
+        protocols.push(protocol.escape_ascii().to_string());
+        offset += 1 + usize::from(*len);
+    }
+    protocols
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::tls_client_hello::{ClientHelloReassembler, TlsClientHello};
+    use crate::Protocol;
+
+    /// Builds a TLS record carrying a ClientHello with the given extensions
+    fn client_hello_record(extensions: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut extensions_bytes = Vec::new();
+        for (extension_type, data) in extensions {
+            extensions_bytes.extend_from_slice(&extension_type.to_be_bytes());
+            extensions_bytes.extend_from_slice(&u16::try_from(data.len()).unwrap().to_be_bytes());
+            extensions_bytes.extend_from_slice(data);
+        }
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&[0xab; 32]); // random
+        body.extend_from_slice(&[32]); // session ID
+        body.extend_from_slice(&[0xcd; 32]);
+        body.extend_from_slice(&[0x00, 0x04, 0x13, 0x01, 0x13, 0x02]); // cipher suites
+        body.extend_from_slice(&[0x01, 0x00]); // compression methods
+        body.extend_from_slice(&u16::try_from(extensions_bytes.len()).unwrap().to_be_bytes());
+        body.extend_from_slice(&extensions_bytes);
+
+        let mut handshake = vec![0x01, 0x00];
+        handshake.extend_from_slice(&u16::try_from(body.len()).unwrap().to_be_bytes());
+        handshake.extend_from_slice(&body);
+
+        let mut record = vec![0x16, 0x03, 0x01];
+        record.extend_from_slice(&u16::try_from(handshake.len()).unwrap().to_be_bytes());
+        record.extend_from_slice(&handshake);
+        record
+    }
+
+    fn sni_extension(name: &str) -> (u16, Vec<u8>) {
+        let mut data = (u16::try_from(name.len()).unwrap() + 3)
+            .to_be_bytes()
+            .to_vec();
+        data.push(0);
+        data.extend_from_slice(&u16::try_from(name.len()).unwrap().to_be_bytes());
+        data.extend_from_slice(name.as_bytes());
+        (0, data)
+    }
+
+    fn alpn_extension(protocols: &[&str]) -> (u16, Vec<u8>) {
+        let mut list = Vec::new();
+        for protocol in protocols {
+            list.push(u8::try_from(protocol.len()).unwrap());
+            list.extend_from_slice(protocol.as_bytes());
+        }
+        let mut data = u16::try_from(list.len()).unwrap().to_be_bytes().to_vec();
+        data.extend_from_slice(&list);
+        (16, data)
+    }
+
+    #[test]
+    fn test_parse_client_hello() {
+        let record = client_hello_record(&[
+            (0x000a, vec![0x00, 0x02, 0x00, 0x1d]),
+            sni_extension("WWW.Example.com"),
+            alpn_extension(&["h2", "http/1.1"]),
+        ]);
+        assert_eq!(
+            TlsClientHello::parse_record(&record),
+            Some(TlsClientHello {
+                server_name: Some("www.example.com".to_string()),
+                alpn: vec!["h2".to_string(), "http/1.1".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_truncated_client_hello() {
+        let record = client_hello_record(&[
+            sni_extension("www.example.com"),
+            alpn_extension(&["h2", "http/1.1"]),
+        ]);
+        // the ALPN extension is cut off: the SNI is still available
+        let client_hello = TlsClientHello::parse_record(&record[..record.len() - 3]).unwrap();
+        assert_eq!(client_hello.server_name.as_deref(), Some("www.example.com"));
+        assert!(client_hello.alpn.is_empty());
+
+        // the extensions are cut off
+        assert_eq!(
+            TlsClientHello::parse_record(&record[..90]),
+            Some(TlsClientHello::default())
+        );
+        assert!(TlsClientHello::parse_record(&record[..20]).is_none());
+    }
+
+    #[test]
+    fn test_parse_not_client_hello() {
+        let mut record = client_hello_record(&[sni_extension("www.example.com")]);
+        // ServerHello
+        record[5] = 0x02;
+        assert!(TlsClientHello::parse_record(&record).is_none());
+        // application data
+        record[0] = 0x17;
+        record[5] = 0x01;
+        assert!(TlsClientHello::parse_record(&record).is_none());
+        assert!(TlsClientHello::parse_record(b"GET / HTTP/1.1\r\n").is_none());
+        assert!(TlsClientHello::parse_record(&[]).is_none());
+    }
+
+    #[test]
+    fn test_reassemble_client_hello() {
+        let key = AddressPortPair::new(
+            "192.168.1.2".to_string(),
+            Some(50000),
+            "93.184.216.34".to_string(),
+            Some(443),
+            Protocol::TCP,
+        );
+        let record = client_hello_record(&[
+            (0xfe0d, vec![0; 2000]),
+            sni_extension("www.example.com"),
+            alpn_extension(&["h2"]),
+        ]);
+        let len = |bytes: &[u8]| u32::try_from(bytes.len()).unwrap();
+
+        let mut reassembler = ClientHelloReassembler::default();
+        let (first, second) = record.split_at(1400);
+        assert!(reassembler.feed(&key, first, len(first)).is_none());
+        assert!(reassembler.feed(&key, &[], 0).is_none());
+        let client_hello = reassembler.feed(&key, second, len(second)).unwrap();
+        assert_eq!(client_hello.server_name.as_deref(), Some("www.example.com"));
+        assert_eq!(client_hello.alpn, vec!["h2".to_string()]);
+        assert!(reassembler.buffers.is_empty());
+
+        // other segments of the same connection are ignored
+        assert!(reassembler.feed(&key, second, len(second)).is_none());
+
+        // a truncated segment is parsed as is
+        let truncated = &record[..300];
+        assert_eq!(
+            reassembler.feed(&key, truncated, len(first)),
+            Some(TlsClientHello::default())
+        );
+        assert!(reassembler.buffers.is_empty());
+    }
+}
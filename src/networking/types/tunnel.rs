@@ -0,0 +1,104 @@
+//! Module defining the `Tunnel` struct, which contains the outer encapsulation details of a
+//! tunnelled connection, and the `TunnelAccounting` enum.
+
+use std::fmt;
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::translations::translations_4::{inner_flows_translation, outer_flows_translation};
+use crate::Language;
+
+/// Tunneling protocols that can be decapsulated
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TunnelProtocol {
+    Gre,
+    Vxlan,
+    IpInIp,
+    Geneve,
+}
+
+impl fmt::Display for TunnelProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            TunnelProtocol::Gre => "GRE",
+            TunnelProtocol::Vxlan => "VXLAN",
+            TunnelProtocol::IpInIp => "IP-in-IP",
+            TunnelProtocol::Geneve => "Geneve",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Outer encapsulation of a tunnelled connection
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Tunnel {
+    /// Tunneling protocol
+    pub protocol: TunnelProtocol,
+    /// Source address of the outer IP header
+    pub outer_source: IpAddr,
+    /// Destination address of the outer IP header
+    pub outer_dest: IpAddr,
+    /// VXLAN or Geneve network identifier (VNI), or GRE key
+    pub id: Option<u32>,
+}
+
+impl fmt::Display for Tunnel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.protocol)?;
+        if let Some(id) = self.id {
+            let id_name = match self.protocol {
+                TunnelProtocol::Gre => "key",
+                _ => "VNI",
+            };
+            write!(f, " ({id_name} {id})")?;
+        }
+        write!(f, "\n   {} → {}", self.outer_source, self.outer_dest)
+    }
+}
+
+/// Flows to account for when a tunnelled packet is sniffed
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum TunnelAccounting {
+    /// Account for the connection between the tunnel endpoints
+    #[default]
+    Outer,
+    /// Account for the encapsulated connection
+    Inner,
+}
+
+impl TunnelAccounting {
+    pub const ALL: [TunnelAccounting; 2] = [TunnelAccounting::Outer, TunnelAccounting::Inner];
+
+    pub fn get_label(self, language: Language) -> &'static str {
+        match self {
+            TunnelAccounting::Outer => outer_flows_translation(language),
+            TunnelAccounting::Inner => inner_flows_translation(language),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use crate::networking::types::tunnel::{Tunnel, TunnelProtocol};
+
+    #[test]
+    fn test_tunnel_display() {
+        let mut tunnel = Tunnel {
+            protocol: TunnelProtocol::Vxlan,
+            outer_source: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            outer_dest: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+            id: Some(42),
+        };
+        assert_eq!(tunnel.to_string(), "VXLAN (VNI 42)\n   10.0.0.1 → 10.0.0.2");
+
+        tunnel.protocol = TunnelProtocol::Gre;
+        assert_eq!(tunnel.to_string(), "GRE (key 42)\n   10.0.0.1 → 10.0.0.2");
+
+        tunnel.protocol = TunnelProtocol::IpInIp;
+        tunnel.id = None;
+        assert_eq!(tunnel.to_string(), "IP-in-IP\n   10.0.0.1 → 10.0.0.2");
+    }
+}
@@ -2,8 +2,11 @@
 
 use chrono::Local;
 
+use crate::networking::types::exfiltration::ExfiltrationAlert;
 use crate::notifications::types::logged_notification::{
-    BytesThresholdExceeded, FavoriteTransmitted, LoggedNotification, PacketsThresholdExceeded,
+    Beacons, BlocklistMatches, BytesThresholdExceeded, Exfiltration, FavoriteTransmitted,
+    LoggedNotification, MultipleDhcpServers, PacketsThresholdExceeded, PortScans, RuleMatches,
+    TrafficAnomaly,
 };
 use crate::notifications::types::notifications::Notifications;
 use crate::notifications::types::sound::{play, Sound};
@@ -19,98 +22,203 @@ pub fn notify_and_log(
 ) -> usize {
     let mut already_emitted_sound = false;
     let mut emitted_notifications = 0;
+    let timestamp = || Local::now().to_string().get(11..19).unwrap().to_string();
+    let mut log = |runtime_data: &mut RunTimeData, notification, sound| {
+        emitted_notifications += 1;
+        log_notification(
+            runtime_data,
+            notification,
+            sound,
+            notifications.volume,
+            &mut already_emitted_sound,
+        );
+    };
     // packets threshold
-    if notifications.packets_notification.threshold.is_some() {
+    if let Some(threshold) = notifications.packets_notification.threshold {
         let sent_packets_entry = runtime_data.tot_out_packets - runtime_data.tot_out_packets_prev;
         let received_packets_entry = runtime_data.tot_in_packets - runtime_data.tot_in_packets_prev;
-        if received_packets_entry + sent_packets_entry
-            > u128::from(notifications.packets_notification.threshold.unwrap())
-        {
-            // log this notification
-            emitted_notifications += 1;
-            if runtime_data.logged_notifications.len() >= 30 {
-                runtime_data.logged_notifications.pop_back();
-            }
-            runtime_data.logged_notifications.push_front(
+        if received_packets_entry + sent_packets_entry > u128::from(threshold) {
+            log(
+                runtime_data,
                 LoggedNotification::PacketsThresholdExceeded(PacketsThresholdExceeded {
                     threshold: notifications.packets_notification.previous_threshold,
                     incoming: received_packets_entry.try_into().unwrap(),
                     outgoing: sent_packets_entry.try_into().unwrap(),
-                    timestamp: Local::now().to_string().get(11..19).unwrap().to_string(),
+                    timestamp: timestamp(),
                 }),
+                notifications.packets_notification.sound,
             );
-            if notifications.packets_notification.sound.ne(&Sound::None) {
-                // emit sound
-                play(
-                    notifications.packets_notification.sound,
-                    notifications.volume,
-                );
-                already_emitted_sound = true;
-            }
         }
     }
     // bytes threshold
-    if notifications.bytes_notification.threshold.is_some() {
+    if let Some(threshold) = notifications.bytes_notification.threshold {
         let sent_bytes_entry = runtime_data.tot_out_bytes - runtime_data.tot_out_bytes_prev;
         let received_bytes_entry = runtime_data.tot_in_bytes - runtime_data.tot_in_bytes_prev;
-        if received_bytes_entry + sent_bytes_entry
-            > u128::from(notifications.bytes_notification.threshold.unwrap())
-        {
-            //log this notification
-            emitted_notifications += 1;
-            if runtime_data.logged_notifications.len() >= 30 {
-                runtime_data.logged_notifications.pop_back();
-            }
-            runtime_data.logged_notifications.push_front(
+        if received_bytes_entry + sent_bytes_entry > u128::from(threshold) {
+            log(
+                runtime_data,
                 LoggedNotification::BytesThresholdExceeded(BytesThresholdExceeded {
                     threshold: notifications.bytes_notification.previous_threshold,
                     incoming: received_bytes_entry.try_into().unwrap(),
                     outgoing: sent_bytes_entry.try_into().unwrap(),
-                    timestamp: Local::now().to_string().get(11..19).unwrap().to_string(),
+                    timestamp: timestamp(),
                 }),
+                notifications.bytes_notification.sound,
             );
-            if !already_emitted_sound && notifications.bytes_notification.sound.ne(&Sound::None) {
-                // emit sound
-                play(notifications.bytes_notification.sound, notifications.volume);
-                already_emitted_sound = true;
-            }
         }
     }
+    // traffic rates deviating from their baseline (learned even if the notification is disabled)
+    let anomalies = runtime_data.traffic_baseline.update(
+        (
+            runtime_data.tot_in_packets - runtime_data.tot_in_packets_prev,
+            runtime_data.tot_out_packets - runtime_data.tot_out_packets_prev,
+        ),
+        (
+            runtime_data.tot_in_bytes - runtime_data.tot_in_bytes_prev,
+            runtime_data.tot_out_bytes - runtime_data.tot_out_bytes_prev,
+        ),
+        notifications.anomaly_notification.sensitivity,
+    );
+    if notifications.anomaly_notification.notify_on_anomaly && !anomalies.is_empty() {
+        log(
+            runtime_data,
+            LoggedNotification::TrafficAnomaly(TrafficAnomaly {
+                anomalies,
+                sensitivity: notifications.anomaly_notification.sensitivity,
+                timestamp: timestamp(),
+            }),
+            notifications.anomaly_notification.sound,
+        );
+    }
+
+    let info_traffic_lock = info_traffic.lock().unwrap();
     // from favorites
     if notifications.favorite_notification.notify_on_favorite
-        && !info_traffic
-            .lock()
-            .unwrap()
-            .favorites_last_interval
-            .is_empty()
+        && !info_traffic_lock.favorites_last_interval.is_empty()
     {
-        let info_traffic_lock = info_traffic.lock().unwrap();
-        for host in &info_traffic_lock.favorites_last_interval.clone() {
-            //log this notification
-            emitted_notifications += 1;
-            if runtime_data.logged_notifications.len() >= 30 {
-                runtime_data.logged_notifications.pop_back();
-            }
-
-            runtime_data
-                .logged_notifications
-                .push_front(LoggedNotification::FavoriteTransmitted(
-                    FavoriteTransmitted {
-                        host: host.clone(),
-                        data_info_host: *info_traffic_lock.hosts.get(host).unwrap(),
-                        timestamp: Local::now().to_string().get(11..19).unwrap().to_string(),
-                    },
-                ));
-        }
-        drop(info_traffic_lock);
-        if !already_emitted_sound && notifications.favorite_notification.sound.ne(&Sound::None) {
-            // emit sound
-            play(
-                notifications.favorite_notification.sound,
-                notifications.volume,
+        // one notification for each host, with a single sound
+        let mut sound = notifications.favorite_notification.sound;
+        for host in &info_traffic_lock.favorites_last_interval {
+            log(
+                runtime_data,
+                LoggedNotification::FavoriteTransmitted(FavoriteTransmitted {
+                    host: host.clone(),
+                    data_info_host: *info_traffic_lock.hosts.get(host).unwrap(),
+                    timestamp: timestamp(),
+                }),
+                sound,
             );
+            sound = Sound::None;
         }
     }
+    // from DHCP servers
+    let dhcp_analytics = &info_traffic_lock.dhcp_analytics;
+    if notifications.dhcp_notification.notify_on_multiple_servers
+        && dhcp_analytics.multiple_servers()
+        && !dhcp_analytics.servers_last_interval.is_empty()
+    {
+        log(
+            runtime_data,
+            LoggedNotification::MultipleDhcpServers(MultipleDhcpServers {
+                servers: dhcp_analytics.servers.clone(),
+                timestamp: timestamp(),
+            }),
+            notifications.dhcp_notification.sound,
+        );
+    }
+    // from blocklists
+    if notifications.blocklist_notification.notify_on_match
+        && !info_traffic_lock.blocklist_matches_last_interval.is_empty()
+    {
+        log(
+            runtime_data,
+            LoggedNotification::BlocklistMatches(BlocklistMatches {
+                matches: info_traffic_lock.blocklist_matches_last_interval.clone(),
+                timestamp: timestamp(),
+            }),
+            notifications.blocklist_notification.sound,
+        );
+    }
+    // from port scans
+    if notifications.port_scan_notification.notify_on_scan
+        && !info_traffic_lock.port_scans_last_interval.is_empty()
+    {
+        log(
+            runtime_data,
+            LoggedNotification::PortScans(PortScans {
+                scans: info_traffic_lock.port_scans_last_interval.clone(),
+                timestamp: timestamp(),
+            }),
+            notifications.port_scan_notification.sound,
+        );
+    }
+    // from beaconing hosts
+    if notifications.beacon_notification.notify_on_beacon
+        && !info_traffic_lock.beacons_last_interval.is_empty()
+    {
+        log(
+            runtime_data,
+            LoggedNotification::Beacons(Beacons {
+                beacons: info_traffic_lock.beacons_last_interval.clone(),
+                timestamp: timestamp(),
+            }),
+            notifications.beacon_notification.sound,
+        );
+    }
+    // from DNS tunnelling and data exfiltration heuristics
+    let alerts: Vec<ExfiltrationAlert> = info_traffic_lock
+        .exfiltration_last_interval
+        .iter()
+        .filter(|alert| {
+            notifications
+                .exfiltration_notification
+                .is_enabled(alert.evidence.heuristic())
+        })
+        .cloned()
+        .collect();
+    if notifications.exfiltration_notification.is_any_enabled() && !alerts.is_empty() {
+        log(
+            runtime_data,
+            LoggedNotification::Exfiltration(Exfiltration {
+                alerts,
+                timestamp: timestamp(),
+            }),
+            notifications.exfiltration_notification.sound,
+        );
+    }
+    // from custom alert rules
+    if notifications.rule_notification.notify_on_match
+        && !info_traffic_lock.rule_matches_last_interval.is_empty()
+    {
+        log(
+            runtime_data,
+            LoggedNotification::RuleMatches(RuleMatches {
+                matches: info_traffic_lock.rule_matches_last_interval.clone(),
+                timestamp: timestamp(),
+            }),
+            notifications.rule_notification.sound,
+        );
+    }
+    drop(info_traffic_lock);
 
     emitted_notifications
 }
+
+/// Logs a notification, keeping only the last 30, and emits its sound
+/// unless another one was already emitted in this interval
+fn log_notification(
+    runtime_data: &mut RunTimeData,
+    notification: LoggedNotification,
+    sound: Sound,
+    volume: u8,
+    already_emitted_sound: &mut bool,
+) {
+    if runtime_data.logged_notifications.len() >= 30 {
+        runtime_data.logged_notifications.pop_back();
+    }
+    runtime_data.logged_notifications.push_front(notification);
+    if !*already_emitted_sound && sound.ne(&Sound::None) {
+        play(sound, volume);
+        *already_emitted_sound = true;
+    }
+}
@@ -0,0 +1,890 @@
+//! Module defining the `AlertRules` struct, which holds the custom alert rules loaded from
+//! a TOML file, and evaluates them against the sniffed traffic.
+//!
+//! Each rule is a `[[rule]]` table with a name and some conditions, all of which must hold:
+//!
+//! ```toml
+//! [[rule]]
+//! name = "Telnet"
+//! protocol = "TCP"
+//! dport = 23
+//!
+//! [[rule]]
+//! name = "Upload to XX"
+//! country = "XX"
+//! direction = "outgoing"
+//! bytes = 10_000_000
+//! window = 60
+//!
+//! [[rule]]
+//! name = "New host in AS 15169"
+//! event = "new_host"
+//! asn = 15169
+//! ```
+//!
+//! Rules are evaluated on the first packet of each new connection by default;
+//! rules with a `bytes` or `packets` threshold aggregate the matching traffic over a time window
+//! instead, and `new_host` rules are evaluated on the remote hosts seen for the first time.
+//!
+//! Invalid rules are skipped, and reported along with the rules loaded.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::net::IpAddr;
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use crate::countries::types::country::Country;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::blocklist::parse_range;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::host::Host;
+use crate::networking::types::packet_filters_fields::PacketFiltersFields;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::{IpVersion, Protocol, Service};
+
+/// Default time window of the rules with a threshold, in seconds
+const DEFAULT_WINDOW: u64 = 60;
+
+/// Content of a rules file (each rule is validated on its own)
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    #[serde(default)]
+    rule: Vec<toml::Value>,
+}
+
+/// A rule as written in the rules file
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct RuleDefinition {
+    name: String,
+    /// `connection`, `traffic`, or `new_host`
+    event: Option<String>,
+    /// `IPv4` or `IPv6`
+    ip_version: Option<String>,
+    /// Protocol name (`TCP`, `UDP`, `ICMP`) or number
+    protocol: Option<String>,
+    /// IP address, CIDR block, or range of addresses, matching the source or the destination
+    address: Option<String>,
+    /// Port, matching the source or the destination port
+    port: Option<u16>,
+    sport: Option<u16>,
+    dport: Option<u16>,
+    /// `incoming` or `outgoing`
+    direction: Option<String>,
+    service: Option<String>,
+    domain: Option<String>,
+    asn: Option<u32>,
+    country: Option<String>,
+    bytes: Option<u64>,
+    packets: Option<u64>,
+    /// Seconds
+    window: Option<u64>,
+}
+
+/// Event on which a rule is evaluated
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleEvent {
+    /// The first packet of a new connection
+    Connection,
+    /// The traffic matched in a time window, reaching a number of bytes or packets
+    Traffic {
+        bytes: Option<u128>,
+        packets: Option<u128>,
+        /// Seconds
+        window: u64,
+    },
+    /// A remote host seen for the first time
+    NewHost,
+}
+
+/// Conditions of a rule, all of which must hold
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct RuleConditions {
+    ip_version: Option<IpVersion>,
+    protocol: Option<Protocol>,
+    /// Inclusive bounds of the source or destination address
+    address: Option<(IpAddr, IpAddr)>,
+    port: Option<u16>,
+    sport: Option<u16>,
+    dport: Option<u16>,
+    direction: Option<TrafficDirection>,
+    service: Option<String>,
+    /// Domain, also matching its subdomains
+    domain: Option<String>,
+    /// Autonomous System number
+    asn: Option<String>,
+    country: Option<Country>,
+}
+
+impl RuleConditions {
+    /// Returns true if the packet satisfies the conditions not involving the remote host
+    fn matches_packet(&self, packet: &RulePacket) -> bool {
+        let fields = packet.fields;
+        self.ip_version
+            .is_none_or(|ip_version| ip_version == fields.ip_version)
+            && self
+                .protocol
+                .is_none_or(|protocol| protocol == fields.protocol)
+            && self.address.is_none_or(|(first, last)| {
+                (first..=last).contains(&fields.source) || (first..=last).contains(&fields.dest)
+            })
+            && self
+                .port
+                .is_none_or(|port| fields.sport == Some(port) || fields.dport == Some(port))
+            && self.sport.is_none_or(|port| fields.sport == Some(port))
+            && self.dport.is_none_or(|port| fields.dport == Some(port))
+            && self
+                .direction
+                .is_none_or(|direction| direction == packet.traffic_direction)
+            && self
+                .service
+                .as_ref()
+                .is_none_or(|service| match packet.service {
+                    Service::Name(name) => name.eq_ignore_ascii_case(service),
+                    Service::Unknown => service == "?",
+                    Service::NotApplicable => service == "-",
+                })
+    }
+
+    /// Returns true if some conditions involve the remote host
+    fn needs_host(&self) -> bool {
+        self.domain.is_some() || self.asn.is_some() || self.country.is_some()
+    }
+
+    /// Returns true if the remote host, known with the given name, satisfies the conditions
+    fn matches_host(&self, name: &str, host: &Host) -> bool {
+        self.domain
+            .as_ref()
+            .is_none_or(|domain| in_domain(name, domain))
+            && self.asn.as_ref().is_none_or(|asn| host.asn.code == *asn)
+            && self.country.is_none_or(|country| host.country == country)
+    }
+}
+
+/// Traffic matched by a rule in the last seconds, split in buckets of one second
+#[derive(Default)]
+struct TrafficWindow {
+    buckets: VecDeque<(i64, DataInfo)>,
+    total: DataInfo,
+}
+
+impl TrafficWindow {
+    /// Adds a packet captured at the given time (in microseconds), forgetting the traffic
+    /// older than `window` seconds, and returns the traffic in the window
+    fn add(
+        &mut self,
+        timestamp: i64,
+        window: u64,
+        bytes: u128,
+        traffic_direction: TrafficDirection,
+    ) -> DataInfo {
+        let second = timestamp / 1_000_000;
+        let window = i64::try_from(window).unwrap_or(i64::MAX);
+        while let Some(&(oldest, data_info)) = self.buckets.front() {
+            if second.saturating_sub(oldest) < window {
+                break;
+            }
+            self.total -= data_info;
+            self.buckets.pop_front();
+        }
+        let data_info = DataInfo::new_with_first_packet(bytes, traffic_direction);
+        match self.buckets.back_mut() {
+            Some((last, bucket)) if *last == second => *bucket += data_info,
+            _ => self.buckets.push_back((second, data_info)),
+        }
+        self.total += data_info;
+        self.total
+    }
+}
+
+/// A custom alert rule
+pub struct AlertRule {
+    pub name: String,
+    pub event: RuleEvent,
+    conditions: RuleConditions,
+    /// Traffic matched in the current window, for the rules with a threshold
+    window: TrafficWindow,
+}
+
+impl AlertRule {
+    /// Validates a rule as written in the rules file
+    fn compile(definition: RuleDefinition) -> Result<Self, String> {
+        let name = definition.name.trim().to_string();
+        if name.is_empty() {
+            return Err("a rule has no name".to_string());
+        }
+        let error = |problem: String| format!("{name}: {problem}");
+
+        let thresholds = definition.bytes.is_some() || definition.packets.is_some();
+        let event = match definition.event.as_deref() {
+            None if thresholds => "traffic",
+            None => "connection",
+            Some(event) => event,
+        };
+        let event = match event {
+            "connection" | "new_host" if thresholds => {
+                return Err(error(format!(
+                    "thresholds can't be used with the {event} event"
+                )));
+            }
+            "traffic" if !thresholds => {
+                return Err(error(
+                    "the traffic event needs bytes or packets".to_string(),
+                ));
+            }
+            "connection" => RuleEvent::Connection,
+            "new_host" => RuleEvent::NewHost,
+            "traffic" => RuleEvent::Traffic {
+                bytes: definition.bytes.map(u128::from),
+                packets: definition.packets.map(u128::from),
+                window: match definition.window {
+                    Some(0) => return Err(error("the window can't be empty".to_string())),
+                    Some(window) => window,
+                    None => DEFAULT_WINDOW,
+                },
+            },
+            other => return Err(error(format!("unknown event '{other}'"))),
+        };
+        if definition.window.is_some() && !thresholds {
+            return Err(error("the window needs bytes or packets".to_string()));
+        }
+        if event == RuleEvent::NewHost
+            && (definition.protocol.is_some()
+                || definition.port.is_some()
+                || definition.sport.is_some()
+                || definition.dport.is_some()
+                || definition.direction.is_some()
+                || definition.service.is_some())
+        {
+            return Err(error(
+                "only addresses, domains, ASNs, and countries can be matched on new hosts"
+                    .to_string(),
+            ));
+        }
+
+        let conditions = RuleConditions {
+            ip_version: match definition.ip_version.as_deref() {
+                None => None,
+                Some(ip_version) => Some(
+                    parse_ip_version(ip_version)
+                        .ok_or_else(|| error(format!("unknown IP version '{ip_version}'")))?,
+                ),
+            },
+            protocol: match definition.protocol.as_deref() {
+                None => None,
+                Some(protocol) => Some(
+                    parse_protocol(protocol)
+                        .ok_or_else(|| error(format!("unknown protocol '{protocol}'")))?,
+                ),
+            },
+            address: match definition.address.as_deref() {
+                None => None,
+                Some(address) => Some(
+                    parse_range(address.trim())
+                        .ok_or_else(|| error(format!("invalid address '{address}'")))?,
+                ),
+            },
+            port: definition.port,
+            sport: definition.sport,
+            dport: definition.dport,
+            direction: match definition.direction.as_deref() {
+                None => None,
+                Some(direction) => Some(
+                    parse_direction(direction)
+                        .ok_or_else(|| error(format!("unknown direction '{direction}'")))?,
+                ),
+            },
+            service: definition.service,
+            domain: definition
+                .domain
+                .map(|domain| domain.trim_end_matches('.').to_ascii_lowercase()),
+            asn: definition.asn.map(|asn| asn.to_string()),
+            country: match definition.country.as_deref() {
+                None => None,
+                Some(country) => Some(
+                    parse_country(country)
+                        .ok_or_else(|| error(format!("unknown country '{country}'")))?,
+                ),
+            },
+        };
+
+        Ok(Self {
+            name,
+            event,
+            conditions,
+            window: TrafficWindow::default(),
+        })
+    }
+}
+
+/// A packet to evaluate the rules against
+pub struct RulePacket<'a> {
+    pub key: &'a AddressPortPair,
+    pub fields: &'a PacketFiltersFields,
+    pub traffic_direction: TrafficDirection,
+    pub service: Service,
+    pub bytes: u128,
+    /// True if this is the first packet of a new connection
+    pub is_new_connection: bool,
+    /// Capture time, in microseconds
+    pub timestamp: i64,
+}
+
+/// What made a rule match
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RuleEvidence {
+    /// A new connection
+    Connection(AddressPortPair),
+    /// The traffic matched in a time window
+    Traffic {
+        packets: u128,
+        bytes: u128,
+        /// Seconds
+        window: u64,
+    },
+    /// A new remote host, with its address
+    NewHost { address: String, host: Host },
+}
+
+/// A custom alert rule that matched
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleMatch {
+    /// Name of the rule
+    pub rule: String,
+    pub evidence: RuleEvidence,
+}
+
+/// Rules read from a rules file, and the errors of the invalid rules skipped
+pub type RulesRead = (Vec<AlertRule>, Vec<String>);
+
+/// The custom alert rules currently loaded
+#[derive(Default)]
+pub struct AlertRules {
+    rules: Vec<AlertRule>,
+    /// Error preventing the rules file from being read, if any
+    error: Option<String>,
+    /// Errors of the invalid rules skipped
+    skipped: Vec<String>,
+    /// Path of the rules file
+    path: String,
+    /// Modification time of the rules file
+    modified: Option<SystemTime>,
+}
+
+impl AlertRules {
+    /// Reads and validates the given rules file (this doesn't need any lock)
+    pub fn read_file(path: &str) -> Result<RulesRead, String> {
+        if path.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
+        }
+        let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::parse(&content)
+    }
+
+    /// Parses and validates the content of a rules file, skipping the invalid rules
+    pub fn parse(content: &str) -> Result<RulesRead, String> {
+        let file: RulesFile = toml::from_str(content).map_err(|e| e.message().to_string())?;
+        let mut rules = Vec::new();
+        let mut skipped = Vec::new();
+        for value in file.rule {
+            let name = value
+                .get("name")
+                .and_then(toml::Value::as_str)
+                .map(ToString::to_string);
+            let rule = value
+                .try_into::<RuleDefinition>()
+                .map_err(|e| match name {
+                    Some(name) => format!("{}: {}", name.trim(), e.message()),
+                    None => e.message().to_string(),
+                })
+                .and_then(AlertRule::compile);
+            match rule {
+                Ok(rule) => rules.push(rule),
+                Err(error) => skipped.push(error),
+            }
+        }
+        Ok((rules, skipped))
+    }
+
+    /// Replaces the loaded rules with the ones read from the given file
+    pub fn set(
+        &mut self,
+        path: &str,
+        modified: Option<SystemTime>,
+        rules: Result<RulesRead, String>,
+    ) {
+        (self.rules, self.skipped, self.error) = match rules {
+            Ok((rules, skipped)) => (rules, skipped, None),
+            Err(error) => (Vec::new(), Vec::new(), Some(error)),
+        };
+        path.clone_into(&mut self.path);
+        self.modified = modified;
+    }
+
+    /// Returns true if the rules file was changed or modified since the last load,
+    /// and postpones the next check (the reload itself is performed in a separate thread)
+    pub fn reload_due(&mut self, path: &str) -> bool {
+        let modified = modified_time(path);
+        if self.path == path && self.modified == modified {
+            return false;
+        }
+        path.clone_into(&mut self.path);
+        self.modified = modified;
+        true
+    }
+
+    /// Rules loaded
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    /// Error preventing the rules file from being read, if any
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Errors of the invalid rules skipped
+    pub fn skipped(&self) -> &[String] {
+        &self.skipped
+    }
+
+    /// Returns true if no rule is loaded
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Evaluates the connection and traffic rules against a packet;
+    /// `host` returns the name and the remote host, and is only called if a rule involves them
+    pub fn packet(
+        &mut self,
+        packet: &RulePacket,
+        mut host: impl FnMut() -> (String, Host),
+    ) -> Vec<RuleMatch> {
+        let mut remote_host: Option<(String, Host)> = None;
+        let mut matches = Vec::new();
+        for rule in &mut self.rules {
+            let evaluated = match rule.event {
+                RuleEvent::Connection => packet.is_new_connection,
+                RuleEvent::Traffic { .. } => true,
+                RuleEvent::NewHost => false,
+            };
+            if !evaluated
+                || !rule.conditions.matches_packet(packet)
+                || (rule.conditions.needs_host() && {
+                    let (name, host) = remote_host.get_or_insert_with(&mut host);
+                    !rule.conditions.matches_host(name, host)
+                })
+            {
+                continue;
+            }
+            let evidence = match rule.event {
+                RuleEvent::Traffic {
+                    bytes,
+                    packets,
+                    window,
+                } => {
+                    let total = rule.window.add(
+                        packet.timestamp,
+                        window,
+                        packet.bytes,
+                        packet.traffic_direction,
+                    );
+                    if bytes.is_none_or(|bytes| total.tot_bytes() < bytes)
+                        && packets.is_none_or(|packets| total.tot_packets() < packets)
+                    {
+                        continue;
+                    }
+                    // start over, to report the same rule at most once per window
+                    rule.window = TrafficWindow::default();
+                    RuleEvidence::Traffic {
+                        packets: total.tot_packets(),
+                        bytes: total.tot_bytes(),
+                        window,
+                    }
+                }
+                _ => RuleEvidence::Connection(packet.key.clone()),
+            };
+            matches.push(RuleMatch {
+                rule: rule.name.clone(),
+                evidence,
+            });
+        }
+        matches
+    }
+
+    /// Evaluates the new host rules against a remote host seen for the first time,
+    /// known with the given name
+    pub fn new_host(&self, address: &str, name: &str, host: &Host) -> Vec<RuleMatch> {
+        let Ok(ip) = address.parse::<IpAddr>() else {
+            return Vec::new();
+        };
+        let ip_version = if ip.is_ipv4() {
+            IpVersion::IPv4
+        } else {
+            IpVersion::IPv6
+        };
+        self.rules
+            .iter()
+            .filter(|rule| rule.event == RuleEvent::NewHost)
+            .filter(|rule| {
+                let conditions = &rule.conditions;
+                conditions.ip_version.is_none_or(|v| v == ip_version)
+                    && conditions
+                        .address
+                        .is_none_or(|(first, last)| (first..=last).contains(&ip))
+                    && conditions.matches_host(name, host)
+            })
+            .map(|rule| RuleMatch {
+                rule: rule.name.clone(),
+                evidence: RuleEvidence::NewHost {
+                    address: address.to_string(),
+                    host: host.clone(),
+                },
+            })
+            .collect()
+    }
+}
+
+/// Returns the modification time of the given file, if it exists
+pub fn modified_time(path: &str) -> Option<SystemTime> {
+    if path.is_empty() {
+        return None;
+    }
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Returns true if the given name is the given (lowercase) domain or one of its subdomains
+fn in_domain(name: &str, domain: &str) -> bool {
+    let name = name.trim_end_matches('.').as_bytes();
+    let Some(start) = name.len().checked_sub(domain.len()) else {
+        return false;
+    };
+    name[start..].eq_ignore_ascii_case(domain.as_bytes()) && (start == 0 || name[start - 1] == b'.')
+}
+
+fn parse_ip_version(ip_version: &str) -> Option<IpVersion> {
+    match ip_version.to_ascii_lowercase().as_str() {
+        "ipv4" | "4" => Some(IpVersion::IPv4),
+        "ipv6" | "6" => Some(IpVersion::IPv6),
+        _ => None,
+    }
+}
+
+fn parse_protocol(protocol: &str) -> Option<Protocol> {
+    match protocol.to_ascii_lowercase().as_str() {
+        "tcp" => Some(Protocol::TCP),
+        "udp" => Some(Protocol::UDP),
+        "icmp" | "icmpv6" => Some(Protocol::ICMP),
+        number => match number.parse::<u8>().ok()? {
+            6 => Some(Protocol::TCP),
+            17 => Some(Protocol::UDP),
+            1 | 58 => Some(Protocol::ICMP),
+            number => Some(Protocol::Other(number)),
+        },
+    }
+}
+
+fn parse_direction(direction: &str) -> Option<TrafficDirection> {
+    match direction.to_ascii_lowercase().as_str() {
+        "incoming" => Some(TrafficDirection::Incoming),
+        "outgoing" => Some(TrafficDirection::Outgoing),
+        _ => None,
+    }
+}
+
+fn parse_country(country: &str) -> Option<Country> {
+    let code = country.to_ascii_uppercase();
+    let country = Country::from_str(&code);
+    (country != Country::ZZ || code == "ZZ").then_some(country)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use crate::countries::types::country::Country;
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::host::Host;
+    use crate::networking::types::packet_filters_fields::PacketFiltersFields;
+    use crate::networking::types::traffic_direction::TrafficDirection;
+    use crate::notifications::types::alert_rules::{
+        AlertRules, RuleEvent, RuleEvidence, RuleMatch, RulePacket,
+    };
+    use crate::{IpVersion, Protocol, Service};
+
+    const RULES: &str = r#"
+[[rule]]
+name = "Telnet"
+protocol = "TCP"
+dport = 23
+
+[[rule]]
+name = "Upload to XX"
+country = "it"
+direction = "outgoing"
+bytes = 10_000_000
+
+[[rule]]
+name = "New Google host"
+event = "new_host"
+asn = 15169
+"#;
+
+    fn fields(source: &str, sport: u16, dest: &str, dport: u16) -> PacketFiltersFields {
+        PacketFiltersFields {
+            ip_version: IpVersion::IPv4,
+            protocol: Protocol::TCP,
+            source: source.parse::<IpAddr>().unwrap(),
+            dest: dest.parse::<IpAddr>().unwrap(),
+            sport: Some(sport),
+            dport: Some(dport),
+        }
+    }
+
+    fn key(fields: &PacketFiltersFields) -> AddressPortPair {
+        AddressPortPair::new(
+            fields.source.to_string(),
+            fields.sport,
+            fields.dest.to_string(),
+            fields.dport,
+            fields.protocol,
+        )
+    }
+
+    fn host(asn: &str, country: Country) -> Host {
+        Host {
+            domain: "example.com".to_string(),
+            asn: Asn {
+                code: asn.to_string(),
+                name: String::new(),
+            },
+            country,
+        }
+    }
+
+    fn remote(asn: &str, country: Country) -> (String, Host) {
+        ("www.example.com".to_string(), host(asn, country))
+    }
+
+    fn rules() -> AlertRules {
+        let mut rules = AlertRules::default();
+        rules.set("rules.toml", None, AlertRules::parse(RULES));
+        rules
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rules = rules();
+        assert_eq!(rules.error(), None);
+        let events: Vec<RuleEvent> = rules.rules().iter().map(|rule| rule.event).collect();
+        assert_eq!(
+            events,
+            vec![
+                RuleEvent::Connection,
+                RuleEvent::Traffic {
+                    bytes: Some(10_000_000),
+                    packets: None,
+                    window: 60
+                },
+                RuleEvent::NewHost
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        for (content, error) in [
+            (
+                "[[rule]]\nname = \"A\"\nprotocol = \"XYZ\"",
+                "A: unknown protocol 'XYZ'",
+            ),
+            (
+                "[[rule]]\nname = \"B\"\ncountry = \"XY\"",
+                "B: unknown country 'XY'",
+            ),
+            (
+                "[[rule]]\nname = \"C\"\nevent = \"traffic\"",
+                "C: the traffic event needs bytes or packets",
+            ),
+            (
+                "[[rule]]\nname = \"D\"\nevent = \"new_host\"\ndport = 22",
+                "D: only addresses, domains, ASNs, and countries can be matched on new hosts",
+            ),
+            (
+                "[[rule]]\nname = \"E\"\naddress = \"10.0.0.0/33\"",
+                "E: invalid address '10.0.0.0/33'",
+            ),
+            ("[[rule]]\nname = \" \"", "a rule has no name"),
+        ] {
+            let (rules, skipped) = AlertRules::parse(content).unwrap();
+            assert!(rules.is_empty());
+            assert_eq!(skipped, vec![error.to_string()]);
+        }
+        // unknown fields are reported by the TOML parser
+        let (_, skipped) = AlertRules::parse("[[rule]]\nname = \"F\"\nprot = \"TCP\"").unwrap();
+        assert!(skipped[0].starts_with("F: unknown field `prot`"));
+        // invalid rules are skipped, keeping the valid ones
+        let mut rules = rules();
+        let content = format!("{RULES}\n[[rule]]\nname = \"G\"\nport = -1\n\n[[rule]]");
+        rules.set("rules.toml", None, AlertRules::parse(&content));
+        assert_eq!(rules.rules().len(), 3);
+        assert_eq!(rules.skipped().len(), 2);
+        assert_eq!(rules.error(), None);
+        // a file that can't be read leaves no rule in place
+        rules.set("rules.toml", None, AlertRules::parse("[[rule]"));
+        assert!(rules.is_empty());
+        assert!(rules.skipped().is_empty());
+        assert!(rules.error().is_some());
+    }
+
+    #[test]
+    fn test_domain_rule() {
+        let mut rules = AlertRules::default();
+        rules.set(
+            "rules.toml",
+            None,
+            AlertRules::parse("[[rule]]\nname = \"EU CDN\"\ndomain = \"eu.example.com.\""),
+        );
+        let https = fields("192.168.1.10", 50000, "203.0.113.5", 443);
+        let packet = RulePacket {
+            key: &key(&https),
+            fields: &https,
+            traffic_direction: TrafficDirection::Outgoing,
+            service: Service::Name("https"),
+            bytes: 60,
+            is_new_connection: true,
+            timestamp: 0,
+        };
+        // the full name is matched, not only the domain shown for the host
+        for (name, matched) in [
+            ("CDN.eu.example.com.", true),
+            ("eu.example.com", true),
+            ("cdn.us.example.com", false),
+            ("neu.example.com", false),
+            ("example.com", false),
+        ] {
+            let matches = rules.packet(&packet, || (name.to_string(), host("64496", Country::US)));
+            assert_eq!(!matches.is_empty(), matched, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_connection_rule() {
+        let mut rules = rules();
+        let telnet = fields("192.168.1.10", 50000, "203.0.113.5", 23);
+        let packet = RulePacket {
+            key: &key(&telnet),
+            fields: &telnet,
+            traffic_direction: TrafficDirection::Outgoing,
+            service: Service::Name("telnet"),
+            bytes: 60,
+            is_new_connection: true,
+            timestamp: 0,
+        };
+        assert_eq!(
+            rules.packet(&packet, || remote("64496", Country::US)),
+            vec![RuleMatch {
+                rule: "Telnet".to_string(),
+                evidence: RuleEvidence::Connection(key(&telnet)),
+            }]
+        );
+        // only new connections are reported
+        let packet = RulePacket {
+            is_new_connection: false,
+            ..packet
+        };
+        assert!(rules
+            .packet(&packet, || remote("64496", Country::US))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_traffic_rule() {
+        let mut rules = rules();
+        let upload = fields("192.168.1.10", 50000, "203.0.113.5", 443);
+        let key = key(&upload);
+        let packet = |timestamp: i64, traffic_direction| RulePacket {
+            key: &key,
+            fields: &upload,
+            traffic_direction,
+            service: Service::Name("https"),
+            bytes: 1_000_000,
+            is_new_connection: false,
+            timestamp,
+        };
+        // 9 MB in the first seconds
+        for second in 0..9 {
+            let packet = packet(second * 1_000_000, TrafficDirection::Outgoing);
+            assert!(rules
+                .packet(&packet, || remote("64496", Country::IT))
+                .is_empty());
+        }
+        // incoming traffic and traffic with other countries don't count
+        let incoming = packet(10_000_000, TrafficDirection::Incoming);
+        assert!(rules
+            .packet(&incoming, || remote("64496", Country::IT))
+            .is_empty());
+        let outgoing = packet(10_000_000, TrafficDirection::Outgoing);
+        assert!(rules
+            .packet(&outgoing, || remote("64496", Country::FR))
+            .is_empty());
+        // the first 2 MB are out of the window by now
+        let late = packet(61_000_000, TrafficDirection::Outgoing);
+        assert!(rules
+            .packet(&late, || remote("64496", Country::IT))
+            .is_empty());
+        let late = packet(61_500_000, TrafficDirection::Outgoing);
+        assert!(rules
+            .packet(&late, || remote("64496", Country::IT))
+            .is_empty());
+        let late = packet(62_000_000, TrafficDirection::Outgoing);
+        assert!(rules
+            .packet(&late, || remote("64496", Country::IT))
+            .is_empty());
+        let late = packet(62_500_000, TrafficDirection::Outgoing);
+        assert_eq!(
+            rules.packet(&late, || remote("64496", Country::IT)),
+            vec![RuleMatch {
+                rule: "Upload to XX".to_string(),
+                evidence: RuleEvidence::Traffic {
+                    packets: 10,
+                    bytes: 10_000_000,
+                    window: 60
+                },
+            }]
+        );
+        // the window starts over after a match
+        assert!(rules
+            .packet(&late, || remote("64496", Country::IT))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_new_host_rule() {
+        let rules = rules();
+        let google = host("15169", Country::US);
+        assert_eq!(
+            rules.new_host("142.250.180.14", "www.google.com", &google),
+            vec![RuleMatch {
+                rule: "New Google host".to_string(),
+                evidence: RuleEvidence::NewHost {
+                    address: "142.250.180.14".to_string(),
+                    host: google,
+                },
+            }]
+        );
+        assert!(rules
+            .new_host(
+                "203.0.113.5",
+                "www.example.com",
+                &host("64496", Country::US)
+            )
+            .is_empty());
+    }
+}
@@ -1,5 +1,11 @@
+use crate::networking::types::beaconing::Beacon;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dhcp_analytics::DhcpServer;
+use crate::networking::types::exfiltration::ExfiltrationAlert;
 use crate::networking::types::host::Host;
+use crate::networking::types::port_scan::PortScan;
+use crate::notifications::types::alert_rules::RuleMatch;
+use crate::notifications::types::traffic_baseline::{AnomalySensitivity, RateAnomaly};
 
 /// Enum representing the possible notification events.
 pub enum LoggedNotification {
@@ -9,6 +15,20 @@ pub enum LoggedNotification {
     BytesThresholdExceeded(BytesThresholdExceeded),
     /// Favorite connection exchanged data
     FavoriteTransmitted(FavoriteTransmitted),
+    /// More than one DHCP server answered
+    MultipleDhcpServers(MultipleDhcpServers),
+    /// Remote addresses matched by a blocklist
+    BlocklistMatches(BlocklistMatches),
+    /// Port scans detected
+    PortScans(PortScans),
+    /// Traffic rates deviating from their baseline
+    TrafficAnomaly(TrafficAnomaly),
+    /// Remote hosts contacted at regular intervals
+    Beacons(Beacons),
+    /// Hosts suspected of DNS tunnelling or data exfiltration
+    Exfiltration(Exfiltration),
+    /// Custom alert rules matched
+    RuleMatches(RuleMatches),
 }
 
 #[derive(Clone)]
@@ -33,3 +53,50 @@ pub struct FavoriteTransmitted {
     pub(crate) data_info_host: DataInfoHost,
     pub(crate) timestamp: String,
 }
+
+#[derive(Clone)]
+pub struct MultipleDhcpServers {
+    pub(crate) servers: Vec<DhcpServer>,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone)]
+pub struct BlocklistMatches {
+    /// Remote addresses matched, with the name of the list
+    pub(crate) matches: Vec<(String, String)>,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone)]
+pub struct PortScans {
+    pub(crate) scans: Vec<PortScan>,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone)]
+pub struct TrafficAnomaly {
+    pub(crate) anomalies: Vec<RateAnomaly>,
+    pub(crate) sensitivity: AnomalySensitivity,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone)]
+pub struct Beacons {
+    /// Remote addresses, with the periodicity of their traffic
+    pub(crate) beacons: Vec<(String, Beacon)>,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone)]
+pub struct Exfiltration {
+    /// Suspected hosts, with the evidence found
+    pub(crate) alerts: Vec<ExfiltrationAlert>,
+    pub(crate) timestamp: String,
+}
+
+#[derive(Clone)]
+pub struct RuleMatches {
+    /// Rules matched, with what made them match
+    pub(crate) matches: Vec<RuleMatch>,
+    pub(crate) timestamp: String,
+}
@@ -1,3 +1,5 @@
+pub mod alert_rules;
 pub mod logged_notification;
 pub mod notifications;
 pub mod sound;
+pub mod traffic_baseline;
@@ -1,6 +1,10 @@
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
+use crate::networking::types::exfiltration::ExfiltrationHeuristic;
 use crate::notifications::types::sound::Sound;
+use crate::notifications::types::traffic_baseline::AnomalySensitivity;
 use crate::ByteMultiple;
 
 /// Used to contain the notifications configuration set by the user
@@ -10,6 +14,13 @@ pub struct Notifications {
     pub packets_notification: PacketsNotification,
     pub bytes_notification: BytesNotification,
     pub favorite_notification: FavoriteNotification,
+    pub dhcp_notification: DhcpNotification,
+    pub blocklist_notification: BlocklistNotification,
+    pub port_scan_notification: PortScanNotification,
+    pub anomaly_notification: AnomalyNotification,
+    pub beacon_notification: BeaconNotification,
+    pub exfiltration_notification: ExfiltrationNotification,
+    pub rule_notification: RuleNotification,
 }
 
 impl Default for Notifications {
@@ -19,6 +30,13 @@ fn default() -> Self {
             packets_notification: PacketsNotification::default(),
             bytes_notification: BytesNotification::default(),
             favorite_notification: FavoriteNotification::default(),
+            dhcp_notification: DhcpNotification::default(),
+            blocklist_notification: BlocklistNotification::default(),
+            port_scan_notification: PortScanNotification::default(),
+            anomaly_notification: AnomalyNotification::default(),
+            beacon_notification: BeaconNotification::default(),
+            exfiltration_notification: ExfiltrationNotification::default(),
+            rule_notification: RuleNotification::default(),
         }
     }
 }
@@ -32,6 +50,20 @@ pub enum Notification {
     Bytes(BytesNotification),
     /// Favorites notification
     Favorite(FavoriteNotification),
+    /// DHCP servers notification
+    Dhcp(DhcpNotification),
+    /// Blocklist matches notification
+    Blocklist(BlocklistNotification),
+    /// Port scans notification
+    PortScan(PortScanNotification),
+    /// Traffic rate anomalies notification
+    Anomaly(AnomalyNotification),
+    /// Beaconing hosts notification
+    Beacon(BeaconNotification),
+    /// DNS tunnelling and data exfiltration notification
+    Exfiltration(ExfiltrationNotification),
+    /// Custom alert rules notification
+    Rule(RuleNotification),
 }
 
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
@@ -171,6 +203,286 @@ pub fn off(sound: Sound) -> Self {
     }
 }
 
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct DhcpNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_multiple_servers: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for DhcpNotification {
+    fn default() -> Self {
+        DhcpNotification {
+            notify_on_multiple_servers: false,
+            sound: Sound::Pop,
+        }
+    }
+}
+
+impl DhcpNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        DhcpNotification {
+            notify_on_multiple_servers: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use. Note that sound is used here for caching, although it won't actively be used.
+    pub fn off(sound: Sound) -> Self {
+        DhcpNotification {
+            notify_on_multiple_servers: false,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct BlocklistNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_match: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for BlocklistNotification {
+    fn default() -> Self {
+        BlocklistNotification {
+            notify_on_match: false,
+            sound: Sound::Swhoosh,
+        }
+    }
+}
+
+impl BlocklistNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        BlocklistNotification {
+            notify_on_match: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use. Note that sound is used here for caching, although it won't actively be used.
+    pub fn off(sound: Sound) -> Self {
+        BlocklistNotification {
+            notify_on_match: false,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct PortScanNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_scan: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for PortScanNotification {
+    fn default() -> Self {
+        PortScanNotification {
+            notify_on_scan: false,
+            sound: Sound::Swhoosh,
+        }
+    }
+}
+
+impl PortScanNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        PortScanNotification {
+            notify_on_scan: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use. Note that sound is used here for caching, although it won't actively be used.
+    pub fn off(sound: Sound) -> Self {
+        PortScanNotification {
+            notify_on_scan: false,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct AnomalyNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_anomaly: bool,
+    /// How far from the usual rates the traffic has to be, to emit a notification
+    pub sensitivity: AnomalySensitivity,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for AnomalyNotification {
+    fn default() -> Self {
+        AnomalyNotification {
+            notify_on_anomaly: false,
+            sensitivity: AnomalySensitivity::default(),
+            sound: Sound::Gulp,
+        }
+    }
+}
+
+impl AnomalyNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sensitivity: AnomalySensitivity, sound: Sound) -> Self {
+        AnomalyNotification {
+            notify_on_anomaly: true,
+            sensitivity,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use. Note that sensitivity and sound are used here for caching, although they won't actively be used.
+    pub fn off(sensitivity: AnomalySensitivity, sound: Sound) -> Self {
+        AnomalyNotification {
+            notify_on_anomaly: false,
+            sensitivity,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct BeaconNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_beacon: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for BeaconNotification {
+    fn default() -> Self {
+        BeaconNotification {
+            notify_on_beacon: false,
+            sound: Sound::Swhoosh,
+        }
+    }
+}
+
+impl BeaconNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        BeaconNotification {
+            notify_on_beacon: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use. Note that sound is used here for caching, although it won't actively be used.
+    pub fn off(sound: Sound) -> Self {
+        BeaconNotification {
+            notify_on_beacon: false,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct ExfiltrationNotification {
+    /// Flag to determine if long or high-entropy DNS labels are notified
+    pub suspicious_labels: bool,
+    /// Flag to determine if many unique subdomains of the same domain are notified
+    pub unique_subdomains: bool,
+    /// Flag to determine if large TXT and NULL DNS records are notified
+    pub large_records: bool,
+    /// Flag to determine if connections with a skewed byte ratio are notified
+    pub skewed_flows: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for ExfiltrationNotification {
+    fn default() -> Self {
+        ExfiltrationNotification {
+            suspicious_labels: false,
+            unique_subdomains: false,
+            large_records: false,
+            skewed_flows: false,
+            sound: Sound::Swhoosh,
+        }
+    }
+}
+
+impl ExfiltrationNotification {
+    /// Whether the given heuristic is notified
+    pub fn is_enabled(self, heuristic: ExfiltrationHeuristic) -> bool {
+        match heuristic {
+            ExfiltrationHeuristic::SuspiciousLabels => self.suspicious_labels,
+            ExfiltrationHeuristic::UniqueSubdomains => self.unique_subdomains,
+            ExfiltrationHeuristic::LargeRecords => self.large_records,
+            ExfiltrationHeuristic::SkewedFlows => self.skewed_flows,
+        }
+    }
+
+    /// Heuristics which are notified
+    pub fn enabled_heuristics(self) -> HashSet<ExfiltrationHeuristic> {
+        ExfiltrationHeuristic::ALL
+            .into_iter()
+            .filter(|heuristic| self.is_enabled(*heuristic))
+            .collect()
+    }
+
+    /// Whether any heuristic is notified
+    pub fn is_any_enabled(self) -> bool {
+        ExfiltrationHeuristic::ALL
+            .into_iter()
+            .any(|heuristic| self.is_enabled(heuristic))
+    }
+
+    /// Returns this notification with the given heuristic enabled or disabled
+    pub fn with(self, heuristic: ExfiltrationHeuristic, enabled: bool) -> Self {
+        let mut notification = self;
+        match heuristic {
+            ExfiltrationHeuristic::SuspiciousLabels => notification.suspicious_labels = enabled,
+            ExfiltrationHeuristic::UniqueSubdomains => notification.unique_subdomains = enabled,
+            ExfiltrationHeuristic::LargeRecords => notification.large_records = enabled,
+            ExfiltrationHeuristic::SkewedFlows => notification.skewed_flows = enabled,
+        }
+        notification
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct RuleNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_match: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for RuleNotification {
+    fn default() -> Self {
+        RuleNotification {
+            notify_on_match: false,
+            sound: Sound::Gulp,
+        }
+    }
+}
+
+impl RuleNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        RuleNotification {
+            notify_on_match: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use. Note that sound is used here for caching, although it won't actively be used.
+    pub fn off(sound: Sound) -> Self {
+        RuleNotification {
+            notify_on_match: false,
+            sound,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -271,4 +583,22 @@ fn test_can_instantiate_packet_notification_from_string(
     ) {
         assert_eq!(expected, PacketsNotification::from(input, None));
     }
+
+    #[test]
+    fn test_exfiltration_heuristics_switched_individually() {
+        let notification = ExfiltrationNotification::default();
+        assert!(!notification.is_any_enabled());
+        let notification = notification
+            .with(ExfiltrationHeuristic::LargeRecords, true)
+            .with(ExfiltrationHeuristic::SkewedFlows, true);
+        assert!(notification.is_any_enabled());
+        assert_eq!(
+            ExfiltrationHeuristic::ALL.map(|heuristic| notification.is_enabled(heuristic)),
+            [false, false, true, true]
+        );
+        let notification = notification
+            .with(ExfiltrationHeuristic::LargeRecords, false)
+            .with(ExfiltrationHeuristic::SkewedFlows, false);
+        assert_eq!(notification, ExfiltrationNotification::default());
+    }
 }
@@ -0,0 +1,311 @@
+//! Module defining the `TrafficBaseline` struct, which learns the usual traffic rates
+//! in order to detect the anomalous ones.
+
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::translations::translations_4::{high_translation, low_translation, medium_translation};
+use crate::{ChartType, Language};
+
+/// Number of samples (seconds) needed before the baseline is used to detect anomalies
+const WARM_UP_SAMPLES: u32 = 30;
+
+/// Number of samples (seconds) weighting the most in the moving average
+const BASELINE_SAMPLES: f64 = 300.0;
+
+/// How far from the baseline the traffic rate has to be, to be reported
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum AnomalySensitivity {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl AnomalySensitivity {
+    pub const ALL: [AnomalySensitivity; 3] = [
+        AnomalySensitivity::Low,
+        AnomalySensitivity::Medium,
+        AnomalySensitivity::High,
+    ];
+
+    pub fn get_label(self, language: Language) -> &'static str {
+        match self {
+            AnomalySensitivity::Low => low_translation(language),
+            AnomalySensitivity::Medium => medium_translation(language),
+            AnomalySensitivity::High => high_translation(language),
+        }
+    }
+
+    /// Number of standard deviations from the mean making a rate anomalous
+    fn deviations(self) -> f64 {
+        match self {
+            AnomalySensitivity::Low => 5.0,
+            AnomalySensitivity::Medium => 3.5,
+            AnomalySensitivity::High => 2.5,
+        }
+    }
+}
+
+/// A traffic rate deviating from its baseline
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateAnomaly {
+    pub chart_type: ChartType,
+    pub direction: TrafficDirection,
+    /// Rate expected from the baseline, per second
+    pub expected: u128,
+    /// Rate observed, per second
+    pub observed: u128,
+}
+
+/// Exponentially weighted moving average and variance of a rate
+#[derive(Clone, Copy, Debug)]
+struct RateBaseline {
+    mean: f64,
+    variance: f64,
+    samples: u32,
+    /// Whether the last sample was anomalous: an anomaly is only reported when it starts
+    anomalous: bool,
+    /// Deviations smaller than this are never anomalous, even if the rate is usually stable
+    min_deviation: f64,
+}
+
+impl RateBaseline {
+    fn new(min_deviation: f64) -> Self {
+        Self {
+            mean: 0.0,
+            variance: 0.0,
+            samples: 0,
+            anomalous: false,
+            min_deviation,
+        }
+    }
+
+    /// Adds a sample to the baseline, returning the expected rate if the sample is a new anomaly
+    fn update(&mut self, observed: f64, sensitivity: AnomalySensitivity) -> Option<f64> {
+        let expected = self.mean;
+        let tolerance = (sensitivity.deviations() * self.variance.sqrt()).max(self.min_deviation);
+        let is_anomalous =
+            self.samples >= WARM_UP_SAMPLES && (observed - expected).abs() > tolerance;
+        let is_new_anomaly = is_anomalous && !self.anomalous;
+        self.anomalous = is_anomalous;
+
+        // plain average until enough samples are collected, then exponentially weighted
+        self.samples = self.samples.saturating_add(1);
+        let alpha = (1.0 / f64::from(self.samples)).max(1.0 / BASELINE_SAMPLES);
+        let diff = observed - self.mean;
+        let increment = alpha * diff;
+        self.mean += increment;
+        self.variance = (1.0 - alpha) * (self.variance + diff * increment);
+
+        is_new_anomaly.then_some(expected)
+    }
+}
+
+/// Baselines of the traffic rates, for each direction
+#[derive(Clone, Copy, Debug)]
+pub struct TrafficBaseline {
+    incoming_packets: RateBaseline,
+    outgoing_packets: RateBaseline,
+    incoming_bytes: RateBaseline,
+    outgoing_bytes: RateBaseline,
+}
+
+impl Default for TrafficBaseline {
+    fn default() -> Self {
+        Self {
+            incoming_packets: RateBaseline::new(50.0),
+            outgoing_packets: RateBaseline::new(50.0),
+            incoming_bytes: RateBaseline::new(50_000.0),
+            outgoing_bytes: RateBaseline::new(50_000.0),
+        }
+    }
+}
+
+impl TrafficBaseline {
+    /// Adds the rates of the last second to the baselines, returning the new anomalies
+    pub fn update(
+        &mut self,
+        packets: (u128, u128),
+        bytes: (u128, u128),
+        sensitivity: AnomalySensitivity,
+    ) -> Vec<RateAnomaly> {
+        let rates = [
+            (
+                &mut self.incoming_packets,
+                ChartType::Packets,
+                TrafficDirection::Incoming,
+                packets.0,
+            ),
+            (
+                &mut self.outgoing_packets,
+                ChartType::Packets,
+                TrafficDirection::Outgoing,
+                packets.1,
+            ),
+            (
+                &mut self.incoming_bytes,
+                ChartType::Bytes,
+                TrafficDirection::Incoming,
+                bytes.0,
+            ),
+            (
+                &mut self.outgoing_bytes,
+                ChartType::Bytes,
+                TrafficDirection::Outgoing,
+                bytes.1,
+            ),
+        ];
+        rates
+            .into_iter()
+            .filter_map(|(baseline, chart_type, direction, observed)| {
+                #[allow(clippy::cast_precision_loss)]
+                let expected = baseline.update(observed as f64, sensitivity)?;
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let expected = expected.round() as u128;
+                Some(RateAnomaly {
+                    chart_type,
+                    direction,
+                    expected,
+                    observed,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::traffic_direction::TrafficDirection;
+    use crate::notifications::types::traffic_baseline::{
+        AnomalySensitivity, RateAnomaly, TrafficBaseline,
+    };
+    use crate::ChartType;
+
+    /// Usual traffic: 1000 +/- 100 packets per second in, 500 +/- 50 out, with 1000 bytes packets
+    fn usual_traffic(second: u128) -> ((u128, u128), (u128, u128)) {
+        let noise = second % 5 * 50;
+        let packets = (900 + noise, 450 + noise / 2);
+        (packets, (packets.0 * 1000, packets.1 * 1000))
+    }
+
+    fn learn_usual_traffic(baseline: &mut TrafficBaseline, seconds: u128) {
+        for second in 0..seconds {
+            let (packets, bytes) = usual_traffic(second);
+            assert!(baseline
+                .update(packets, bytes, AnomalySensitivity::Medium)
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn test_spike_detected_once() {
+        let mut baseline = TrafficBaseline::default();
+        learn_usual_traffic(&mut baseline, 600);
+
+        // incoming traffic spikes for a few seconds
+        let anomalies = baseline.update(
+            (5000, 500),
+            (5_000_000, 500_000),
+            AnomalySensitivity::Medium,
+        );
+        assert_eq!(anomalies.len(), 2);
+        let RateAnomaly {
+            chart_type,
+            direction,
+            expected,
+            observed,
+        } = anomalies[0];
+        assert_eq!(chart_type, ChartType::Packets);
+        assert_eq!(direction, TrafficDirection::Incoming);
+        assert!((990..=1010).contains(&expected));
+        assert_eq!(observed, 5000);
+        let RateAnomaly {
+            chart_type,
+            direction,
+            expected,
+            observed,
+        } = anomalies[1];
+        assert_eq!(chart_type, ChartType::Bytes);
+        assert_eq!(direction, TrafficDirection::Incoming);
+        assert!((990_000..=1_010_000).contains(&expected));
+        assert_eq!(observed, 5_000_000);
+        // the ongoing anomaly isn't reported again
+        assert!(baseline
+            .update(
+                (5000, 500),
+                (5_000_000, 500_000),
+                AnomalySensitivity::Medium
+            )
+            .is_empty());
+
+        // back to the usual traffic, then a new spike in the outgoing direction
+        learn_usual_traffic(&mut baseline, 10);
+        let anomalies = baseline.update(
+            (1000, 3000),
+            (1_000_000, 3_000_000),
+            AnomalySensitivity::Medium,
+        );
+        assert_eq!(anomalies.len(), 2);
+        assert!(anomalies
+            .iter()
+            .all(|anomaly| anomaly.direction == TrafficDirection::Outgoing));
+    }
+
+    #[test]
+    fn test_drop_detected() {
+        let mut baseline = TrafficBaseline::default();
+        learn_usual_traffic(&mut baseline, 600);
+        let anomalies = baseline.update((0, 0), (0, 0), AnomalySensitivity::Medium);
+        assert_eq!(anomalies.len(), 4);
+        assert!(anomalies.iter().all(|anomaly| anomaly.observed == 0));
+    }
+
+    #[test]
+    fn test_sensitivity() {
+        let mut baseline = TrafficBaseline::default();
+        learn_usual_traffic(&mut baseline, 600);
+        // a moderate increase is only an anomaly for the highest sensitivity
+        let increase = ((1200, 600), (1_200_000, 600_000));
+        for sensitivity in [AnomalySensitivity::Low, AnomalySensitivity::Medium] {
+            let mut baseline = baseline;
+            assert!(baseline
+                .update(increase.0, increase.1, sensitivity)
+                .is_empty());
+        }
+        assert_eq!(
+            baseline
+                .update(increase.0, increase.1, AnomalySensitivity::High)
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_warm_up() {
+        let mut baseline = TrafficBaseline::default();
+        learn_usual_traffic(&mut baseline, 10);
+        // not enough samples yet
+        assert!(baseline
+            .update(
+                (5000, 5000),
+                (5_000_000, 5_000_000),
+                AnomalySensitivity::High
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn test_small_deviations_ignored() {
+        let mut baseline = TrafficBaseline::default();
+        // a perfectly stable trickle of traffic
+        for _ in 0..600 {
+            assert!(baseline
+                .update((10, 10), (1000, 1000), AnomalySensitivity::High)
+                .is_empty());
+        }
+        assert!(baseline
+            .update((40, 10), (30_000, 1000), AnomalySensitivity::High)
+            .is_empty());
+    }
+}
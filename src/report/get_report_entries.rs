@@ -1,10 +1,13 @@
-use std::cmp::min;
+use std::cmp::{min, Ordering, Reverse};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use crate::networking::manage_packets::get_address_to_lookup;
 use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::beaconing::Beacon;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dns_analytics::DnsTransaction;
 use crate::networking::types::host::Host;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
 use crate::report::types::sort_type::SortType;
@@ -34,21 +37,37 @@ pub fn get_searched_entries(
         .collect();
     all_results.sort_by(|&(_, a), &(_, b)| match sniffer.report_sort_type {
         ReportSortType {
-            byte_sort,
-            packet_sort: SortType::Neutral,
-        } => match byte_sort {
-            SortType::Ascending => a.transmitted_bytes.cmp(&b.transmitted_bytes),
-            SortType::Descending => b.transmitted_bytes.cmp(&a.transmitted_bytes),
-            SortType::Neutral => b.final_timestamp.cmp(&a.final_timestamp),
-        },
+            byte_sort: SortType::Ascending,
+            ..
+        } => a.transmitted_bytes.cmp(&b.transmitted_bytes),
         ReportSortType {
-            byte_sort: SortType::Neutral,
-            packet_sort,
-        } => match packet_sort {
-            SortType::Ascending => a.transmitted_packets.cmp(&b.transmitted_packets),
-            SortType::Descending => b.transmitted_packets.cmp(&a.transmitted_packets),
-            SortType::Neutral => b.final_timestamp.cmp(&a.final_timestamp),
-        },
+            byte_sort: SortType::Descending,
+            ..
+        } => b.transmitted_bytes.cmp(&a.transmitted_bytes),
+        ReportSortType {
+            packet_sort: SortType::Ascending,
+            ..
+        } => a.transmitted_packets.cmp(&b.transmitted_packets),
+        ReportSortType {
+            packet_sort: SortType::Descending,
+            ..
+        } => b.transmitted_packets.cmp(&a.transmitted_packets),
+        ReportSortType {
+            rtt_sort: SortType::Ascending,
+            ..
+        } => a.average_rtt().cmp(&b.average_rtt()),
+        ReportSortType {
+            rtt_sort: SortType::Descending,
+            ..
+        } => b.average_rtt().cmp(&a.average_rtt()),
+        ReportSortType {
+            retransmission_sort: SortType::Ascending,
+            ..
+        } => cmp_retransmission_rate(a, b),
+        ReportSortType {
+            retransmission_sort: SortType::Descending,
+            ..
+        } => cmp_retransmission_rate(b, a),
         _ => b.final_timestamp.cmp(&a.final_timestamp),
     });
 
@@ -65,6 +84,75 @@ pub fn get_searched_entries(
     )
 }
 
+/// Returns the DNS transactions which belong to the given page, and the total number of transactions
+pub fn get_dns_entries(sniffer: &Sniffer) -> (Vec<DnsTransaction>, usize) {
+    let info_traffic_lock = sniffer.info_traffic.lock().unwrap();
+    let transactions = &info_traffic_lock.dns_analytics.transactions;
+    let page_start = (sniffer.dns_page_number - 1) * 20;
+    // most recent transactions first
+    let page = match sniffer.dns_sort_type {
+        SortType::Neutral => transactions
+            .iter()
+            .rev()
+            .skip(page_start)
+            .take(20)
+            .cloned()
+            .collect(),
+        sort_type => {
+            let mut all_results: Vec<&DnsTransaction> = transactions.iter().rev().collect();
+            if sort_type == SortType::Ascending {
+                all_results.sort_by_key(|transaction| transaction.latency);
+            } else {
+                all_results.sort_by_key(|transaction| Reverse(transaction.latency));
+            }
+            all_results
+                .into_iter()
+                .skip(page_start)
+                .take(20)
+                .cloned()
+                .collect()
+        }
+    };
+    (page, transactions.len())
+}
+
+/// Returns the remote hosts whose traffic periodicity could be estimated which belong to the given page,
+/// with their host name and their periodicity, and the total number of hosts
+pub fn get_beacon_entries(sniffer: &Sniffer) -> (Vec<(String, String, Beacon)>, usize) {
+    let info_traffic_lock = sniffer.info_traffic.lock().unwrap();
+    let mut all_results: Vec<(&String, &Beacon)> = info_traffic_lock.beacons.iter().collect();
+    match sniffer.beacon_sort_type {
+        SortType::Ascending => all_results.sort_by_key(|(_, beacon)| beacon.score),
+        SortType::Descending => all_results.sort_by_key(|(_, beacon)| Reverse(beacon.score)),
+        // most recent bursts first
+        SortType::Neutral => {
+            all_results.sort_by_key(|(_, beacon)| Reverse(beacon.last_burst));
+        }
+    }
+    let page = all_results
+        .iter()
+        .skip((sniffer.beacon_page_number - 1) * 20)
+        .take(20)
+        .map(|&(address, beacon)| {
+            let host_name = info_traffic_lock
+                .addresses_resolved
+                .get(address)
+                .map(|(name, _)| name.clone())
+                .unwrap_or_default();
+            (address.clone(), host_name, *beacon)
+        })
+        .collect();
+    (page, all_results.len())
+}
+
+/// Compares the retransmission rate of two entries (connections that aren't TCP come first)
+fn cmp_retransmission_rate(a: &InfoAddressPortPair, b: &InfoAddressPortPair) -> Ordering {
+    match (a.retransmission_rate(), b.retransmission_rate()) {
+        (Some(rate_a), Some(rate_b)) => rate_a.total_cmp(&rate_b),
+        (rate_a, rate_b) => rate_a.is_some().cmp(&rate_b.is_some()),
+    }
+}
+
 pub fn get_host_entries(
     info_traffic: &Arc<Mutex<InfoTraffic>>,
     chart_type: ChartType,
@@ -82,6 +170,33 @@ pub fn get_host_entries(
         .collect()
 }
 
+/// Returns the hosts with an address matched by a blocklist, with the name of the list
+pub fn get_blocklisted_hosts(info_traffic: &Arc<Mutex<InfoTraffic>>) -> HashMap<Host, String> {
+    let info_traffic_lock = info_traffic.lock().unwrap();
+    info_traffic_lock
+        .blocklisted_addresses
+        .iter()
+        .filter_map(|(address, list)| {
+            let (_, host) = info_traffic_lock.addresses_resolved.get(address)?;
+            Some((host.clone(), list.clone()))
+        })
+        .collect()
+}
+
+/// Returns the number of connections matched by each blocklist
+pub fn get_blocklist_hits(info_traffic: &Arc<Mutex<InfoTraffic>>) -> HashMap<String, usize> {
+    let info_traffic_lock = info_traffic.lock().unwrap();
+    let mut hits = HashMap::new();
+    for list in info_traffic_lock
+        .map
+        .values()
+        .filter_map(|info| info.blocklist.as_ref())
+    {
+        *hits.entry(list.clone()).or_default() += 1;
+    }
+    hits
+}
+
 pub fn get_service_entries(
     info_traffic: &Arc<Mutex<InfoTraffic>>,
     chart_type: ChartType,
@@ -102,3 +217,27 @@ pub fn get_service_entries(
         .map(|&(service, data_info)| (*service, *data_info))
         .collect()
 }
+
+/// Returns the local applications (or containers, if grouped by container) with their data info
+pub fn get_app_entries(
+    info_traffic: &Arc<Mutex<InfoTraffic>>,
+    chart_type: ChartType,
+    sort_type: SortType,
+    group_by_container: bool,
+) -> Vec<(String, DataInfo)> {
+    let info_traffic_lock = info_traffic.lock().unwrap();
+    let map = if group_by_container {
+        &info_traffic_lock.containers
+    } else {
+        &info_traffic_lock.apps
+    };
+    let mut sorted_vec: Vec<(&String, &DataInfo)> = map.iter().collect();
+
+    sorted_vec.sort_by(|&(_, a), &(_, b)| a.compare(b, sort_type, chart_type));
+
+    let n_entry = min(sorted_vec.len(), 30);
+    sorted_vec[0..n_entry]
+        .iter()
+        .map(|&(app, data_info)| (app.clone(), *data_info))
+        .collect()
+}
@@ -6,16 +6,23 @@
 };
 use crate::translations::translations_2::{destination_translation, source_translation};
 use crate::translations::translations_3::{port_translation, service_translation};
+use crate::translations::translations_4::{
+    container_translation, process_translation, retransmissions_translation,
+    server_name_translation, state_translation,
+};
 use crate::translations::types::language::Language;
+use crate::utils::formatted_strings::get_formatted_rtt;
 use crate::ByteMultiple;
 
-// total width: 1012.0
+// total width: 1461.0 (the report scrolls horizontally)
 
 const LARGE_COL_WIDTH: f32 = 221.0;
-const SMALL_COL_WIDTH: f32 = 95.0;
+const MEDIUM_COL_WIDTH: f32 = 90.0;
+const SMALL_COL_WIDTH: f32 = 66.0;
 
 const LARGE_COL_MAX_CHARS: usize = 25;
-const SMALL_COL_MAX_CHARS: usize = 10;
+const MEDIUM_COL_MAX_CHARS: usize = 10;
+const SMALL_COL_MAX_CHARS: usize = 8;
 
 #[derive(Eq, PartialEq)]
 pub enum ReportCol {
@@ -23,20 +30,32 @@ pub enum ReportCol {
     SrcPort,
     DstIp,
     DstPort,
+    ServerName,
+    Process,
+    Container,
     Proto,
     Service,
+    State,
+    Rtt,
+    Retransmissions,
     Bytes,
     Packets,
 }
 
 impl ReportCol {
-    pub(crate) const ALL: [ReportCol; 8] = [
+    pub(crate) const ALL: [ReportCol; 14] = [
         ReportCol::SrcIp,
         ReportCol::SrcPort,
         ReportCol::DstIp,
         ReportCol::DstPort,
+        ReportCol::ServerName,
+        ReportCol::Process,
+        ReportCol::Container,
         ReportCol::Proto,
         ReportCol::Service,
+        ReportCol::State,
+        ReportCol::Rtt,
+        ReportCol::Retransmissions,
         ReportCol::Bytes,
         ReportCol::Packets,
     ];
@@ -45,8 +64,14 @@ pub(crate) fn get_title(&self, language: Language) -> String {
         match self {
             ReportCol::SrcIp | ReportCol::DstIp => address_translation(language).to_string(),
             ReportCol::SrcPort | ReportCol::DstPort => port_translation(language).to_string(),
+            ReportCol::ServerName => server_name_translation(language).to_string(),
+            ReportCol::Process => process_translation(language).to_string(),
+            ReportCol::Container => container_translation(language).to_string(),
             ReportCol::Proto => protocol_translation(language).to_string(),
             ReportCol::Service => service_translation(language).to_string(),
+            ReportCol::State => state_translation(language).to_string(),
+            ReportCol::Rtt => "RTT".to_string(),
+            ReportCol::Retransmissions => retransmissions_translation(language).to_string(),
             ReportCol::Bytes => {
                 let mut str = bytes_translation(language).to_string();
                 str.remove(0).to_uppercase().to_string() + &str
@@ -88,16 +113,53 @@ pub(crate) fn get_value(&self, key: &AddressPortPair, val: &InfoAddressPortPair)
                     "-".to_string()
                 }
             }
+            ReportCol::ServerName => val.server_name().unwrap_or("-").to_string(),
+            ReportCol::Process => val
+                .process
+                .as_ref()
+                .map_or_else(|| "-".to_string(), |process| process.name.clone()),
+            ReportCol::Container => val
+                .process
+                .as_ref()
+                .and_then(|process| process.container.as_ref())
+                .map_or_else(|| "-".to_string(), |container| container.name.clone()),
             ReportCol::Proto => key.protocol.to_string(),
             ReportCol::Service => val.service.to_string(),
+            ReportCol::State => {
+                if let Some(state) = val.tcp_state() {
+                    state.to_string()
+                } else {
+                    "-".to_string()
+                }
+            }
+            ReportCol::Rtt => {
+                if let Some(rtt) = val.average_rtt() {
+                    get_formatted_rtt(rtt)
+                } else {
+                    "-".to_string()
+                }
+            }
+            ReportCol::Retransmissions => {
+                if let Some(rate) = val.retransmission_rate() {
+                    format!("{:.1}%", 100.0 * rate)
+                } else {
+                    "-".to_string()
+                }
+            }
             ReportCol::Bytes => ByteMultiple::formatted_string(val.transmitted_bytes),
             ReportCol::Packets => val.transmitted_packets.to_string(),
         }
     }
 
+    /// Width of the whole report
+    pub(crate) fn total_width() -> f32 {
+        ReportCol::ALL.iter().map(ReportCol::get_width).sum()
+    }
+
     pub(crate) fn get_width(&self) -> f32 {
         match self {
-            ReportCol::SrcIp | ReportCol::DstIp => LARGE_COL_WIDTH,
+            ReportCol::SrcIp | ReportCol::DstIp | ReportCol::ServerName => LARGE_COL_WIDTH,
+            ReportCol::State | ReportCol::Process | ReportCol::Container => MEDIUM_COL_WIDTH,
             _ => SMALL_COL_WIDTH,
         }
     }
@@ -111,20 +173,39 @@ pub(crate) fn get_max_chars(&self, language_opt: Option<Language>) -> usize {
             1
         };
         match self {
-            ReportCol::SrcIp | ReportCol::DstIp => LARGE_COL_MAX_CHARS / reduction_factor,
+            ReportCol::SrcIp | ReportCol::DstIp | ReportCol::ServerName => {
+                LARGE_COL_MAX_CHARS / reduction_factor
+            }
+            ReportCol::State | ReportCol::Process | ReportCol::Container => {
+                MEDIUM_COL_MAX_CHARS / reduction_factor
+            }
             _ => SMALL_COL_MAX_CHARS / reduction_factor,
         }
     }
 
+    /// Returns true if the column has sort arrows instead of a filter input
+    pub(crate) fn is_sortable(&self) -> bool {
+        matches!(
+            self,
+            ReportCol::Rtt | ReportCol::Retransmissions | ReportCol::Bytes | ReportCol::Packets
+        )
+    }
+
     pub(crate) fn get_filter_input_type(&self) -> FilterInputType {
         match self {
             ReportCol::SrcIp => FilterInputType::AddressSrc,
             ReportCol::DstIp => FilterInputType::AddressDst,
             ReportCol::SrcPort => FilterInputType::PortSrc,
             ReportCol::DstPort => FilterInputType::PortDst,
+            ReportCol::ServerName => FilterInputType::Domain,
             ReportCol::Proto => FilterInputType::Proto,
             ReportCol::Service => FilterInputType::Service,
-            ReportCol::Bytes | ReportCol::Packets => FilterInputType::Country, // just to not panic...
+            ReportCol::State => FilterInputType::State,
+            ReportCol::Process => FilterInputType::Process,
+            ReportCol::Container => FilterInputType::Container,
+            ReportCol::Rtt | ReportCol::Retransmissions | ReportCol::Bytes | ReportCol::Packets => {
+                FilterInputType::Country
+            } // just to not panic...
         }
     }
 }
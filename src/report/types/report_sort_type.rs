@@ -13,6 +13,8 @@
 pub struct ReportSortType {
     pub byte_sort: SortType,
     pub packet_sort: SortType,
+    pub rtt_sort: SortType,
+    pub retransmission_sort: SortType,
 }
 
 impl ReportSortType {
@@ -20,11 +22,19 @@ pub fn next_sort(self, report_col: &ReportCol) -> Self {
         match report_col {
             ReportCol::Bytes => Self {
                 byte_sort: self.byte_sort.next_sort(),
-                packet_sort: SortType::Neutral,
+                ..Self::default()
             },
             ReportCol::Packets => Self {
-                byte_sort: SortType::Neutral,
                 packet_sort: self.packet_sort.next_sort(),
+                ..Self::default()
+            },
+            ReportCol::Rtt => Self {
+                rtt_sort: self.rtt_sort.next_sort(),
+                ..Self::default()
+            },
+            ReportCol::Retransmissions => Self {
+                retransmission_sort: self.retransmission_sort.next_sort(),
+                ..Self::default()
             },
             _ => Self::default(),
         }
@@ -34,6 +44,8 @@ pub fn icon(self, report_col: &ReportCol) -> Text<'static, StyleType> {
         match report_col {
             ReportCol::Bytes => self.byte_sort.icon(),
             ReportCol::Packets => self.packet_sort.icon(),
+            ReportCol::Rtt => self.rtt_sort.icon(),
+            ReportCol::Retransmissions => self.retransmission_sort.icon(),
             _ => Icon::SortNeutral.to_text(),
         }
     }
@@ -42,6 +54,8 @@ pub fn button_type(self, report_col: &ReportCol) -> ButtonType {
         match report_col {
             ReportCol::Bytes => self.byte_sort.button_type(),
             ReportCol::Packets => self.packet_sort.button_type(),
+            ReportCol::Rtt => self.rtt_sort.button_type(),
+            ReportCol::Retransmissions => self.retransmission_sort.button_type(),
             _ => ButtonType::SortArrows,
         }
     }
@@ -60,7 +74,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
 
@@ -69,7 +84,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                ..ReportSortType::default()
             }
         );
 
@@ -78,7 +94,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Ascending
+                packet_sort: SortType::Ascending,
+                ..ReportSortType::default()
             }
         );
 
@@ -87,7 +104,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
 
@@ -96,7 +114,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                ..ReportSortType::default()
             }
         );
 
@@ -105,7 +124,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Descending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
 
@@ -114,7 +134,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                ..ReportSortType::default()
             }
         );
 
@@ -123,7 +144,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Descending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
 
@@ -132,7 +154,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Ascending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
 
@@ -141,7 +164,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Descending
+                packet_sort: SortType::Descending,
+                ..ReportSortType::default()
             }
         );
 
@@ -150,7 +174,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Descending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
 
@@ -159,7 +184,8 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Ascending,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
             }
         );
 
@@ -168,7 +194,49 @@ fn test_next_report_sort() {
             sort,
             ReportSortType {
                 byte_sort: SortType::Neutral,
-                packet_sort: SortType::Neutral
+                packet_sort: SortType::Neutral,
+                ..ReportSortType::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_report_sort_tcp_metrics() {
+        let mut sort = ReportSortType::default().next_sort(&ReportCol::Bytes);
+
+        sort = sort.next_sort(&ReportCol::Rtt);
+        assert_eq!(
+            sort,
+            ReportSortType {
+                rtt_sort: SortType::Descending,
+                ..ReportSortType::default()
+            }
+        );
+
+        sort = sort.next_sort(&ReportCol::Rtt);
+        assert_eq!(
+            sort,
+            ReportSortType {
+                rtt_sort: SortType::Ascending,
+                ..ReportSortType::default()
+            }
+        );
+
+        sort = sort.next_sort(&ReportCol::Retransmissions);
+        assert_eq!(
+            sort,
+            ReportSortType {
+                retransmission_sort: SortType::Descending,
+                ..ReportSortType::default()
+            }
+        );
+
+        sort = sort.next_sort(&ReportCol::Packets);
+        assert_eq!(
+            sort,
+            ReportSortType {
+                packet_sort: SortType::Descending,
+                ..ReportSortType::default()
             }
         );
     }
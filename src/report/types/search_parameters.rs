@@ -19,12 +19,22 @@ pub struct SearchParameters {
     pub proto: String,
     /// Service
     pub service: String,
+    /// TCP connection state
+    pub state: String,
     /// Country
     pub country: String,
     /// Domain
     pub domain: String,
     /// Autonomous System name
     pub as_name: String,
+    /// Host of the plain HTTP requests
+    pub http_host: String,
+    /// Operating system guessed for the source or the destination
+    pub os: String,
+    /// Name or PID of the local process
+    pub process: String,
+    /// Name or identifier of the container of the local process
+    pub container: String,
     /// Whether to display only favorites
     pub only_favorites: bool,
 }
@@ -38,7 +48,10 @@ pub fn match_entry(
         is_favorite: bool,
     ) -> bool {
         // if a host-related filter is active and this address has not been resolved yet => false
-        if r_dns_host.is_none() && self.is_some_host_filter_active() {
+        // (except for the domain filter, which also applies to the server name seen on the wire)
+        if r_dns_host.is_none()
+            && (self.only_favorites || !self.country.is_empty() || !self.as_name.is_empty())
+        {
             return false;
         }
 
@@ -69,6 +82,8 @@ pub fn reset_host_filters(&self) -> Self {
             country: String::new(),
             domain: String::new(),
             as_name: String::new(),
+            http_host: String::new(),
+            os: String::new(),
             only_favorites: false,
             ..self.clone()
         }
@@ -93,6 +108,20 @@ pub fn new_service_search(service: &Service) -> Self {
             ..SearchParameters::default()
         }
     }
+
+    pub fn new_app_search(app: &str) -> Self {
+        Self {
+            process: format!("={app}"),
+            ..SearchParameters::default()
+        }
+    }
+
+    pub fn new_container_search(container: &str) -> Self {
+        Self {
+            container: format!("={container}"),
+            ..SearchParameters::default()
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -103,22 +132,32 @@ pub enum FilterInputType {
     PortDst,
     Proto,
     Service,
+    State,
     Country,
     Domain,
     AsName,
+    HttpHost,
+    Os,
+    Process,
+    Container,
 }
 
 impl FilterInputType {
-    pub const ALL: [FilterInputType; 9] = [
+    pub const ALL: [FilterInputType; 14] = [
         Self::AddressSrc,
         Self::PortSrc,
         Self::AddressDst,
         Self::PortDst,
         Self::Proto,
         Self::Service,
+        Self::State,
         Self::Country,
         Self::Domain,
         Self::AsName,
+        Self::HttpHost,
+        Self::Os,
+        Self::Process,
+        Self::Container,
     ];
 
     pub fn matches_entry(
@@ -134,13 +173,44 @@ pub fn matches_entry(
             return true;
         }
 
-        let entry_value = self.entry_value(key, value, r_dns_host).to_lowercase();
+        let matches_value = |entry_value: &str| {
+            let entry_value = entry_value.to_lowercase();
+            if let Some(stripped_filter) = filter_value.strip_prefix('=') {
+                return entry_value.eq(stripped_filter);
+            }
+            entry_value.contains(&filter_value)
+        };
 
-        if let Some(stripped_filter) = filter_value.strip_prefix('=') {
-            return entry_value.eq(stripped_filter);
+        if matches_value(&self.entry_value(key, value, r_dns_host)) {
+            return true;
         }
 
-        entry_value.contains(&filter_value)
+        match self {
+            // the domain filter also applies to the server name requested in the TLS ClientHello
+            FilterInputType::Domain => value.server_name().is_some_and(matches_value),
+            // the HTTP host filter applies to all the requests remembered, not only to the last one
+            FilterInputType::HttpHost => value
+                .http_requests
+                .iter()
+                .filter_map(|request| request.host.as_deref())
+                .any(matches_value),
+            // the operating system filter applies to both the endpoints
+            FilterInputType::Os => value
+                .destination_os
+                .is_some_and(|os_guess| matches_value(os_guess.os)),
+            // the process filter also applies to the PID
+            FilterInputType::Process => value
+                .process
+                .as_ref()
+                .is_some_and(|process| matches_value(&process.pid.to_string())),
+            // the container filter also applies to the container identifier
+            FilterInputType::Container => value
+                .process
+                .as_ref()
+                .and_then(|process| process.container.as_ref())
+                .is_some_and(|container| matches_value(&container.id)),
+            _ => false,
+        }
     }
 
     pub fn current_value(self, search_params: &SearchParameters) -> &str {
@@ -151,9 +221,14 @@ pub fn current_value(self, search_params: &SearchParameters) -> &str {
             FilterInputType::PortDst => &search_params.port_dst,
             FilterInputType::Proto => &search_params.proto,
             FilterInputType::Service => &search_params.service,
+            FilterInputType::State => &search_params.state,
             FilterInputType::Country => &search_params.country,
             FilterInputType::Domain => &search_params.domain,
             FilterInputType::AsName => &search_params.as_name,
+            FilterInputType::HttpHost => &search_params.http_host,
+            FilterInputType::Os => &search_params.os,
+            FilterInputType::Process => &search_params.process,
+            FilterInputType::Container => &search_params.container,
         }
     }
 
@@ -182,9 +257,35 @@ pub fn entry_value(
             }
             FilterInputType::Proto => key.protocol.to_string(),
             FilterInputType::Service => value.service.to_string(),
-            FilterInputType::Country => r_dns_host.unwrap().1.country.to_string(),
-            FilterInputType::Domain => r_dns_host.unwrap().0.to_string(),
-            FilterInputType::AsName => r_dns_host.unwrap().1.asn.name.to_string(),
+            FilterInputType::State => {
+                if let Some(state) = value.tcp_state() {
+                    state.to_string()
+                } else {
+                    "-".to_string()
+                }
+            }
+            FilterInputType::Country => {
+                r_dns_host.map_or_else(|| "-".to_string(), |(_, host)| host.country.to_string())
+            }
+            FilterInputType::Domain => {
+                r_dns_host.map_or_else(|| "-".to_string(), |(r_dns, _)| r_dns.to_string())
+            }
+            FilterInputType::AsName => {
+                r_dns_host.map_or_else(|| "-".to_string(), |(_, host)| host.asn.name.to_string())
+            }
+            FilterInputType::HttpHost => value.http_host().unwrap_or("-").to_string(),
+            FilterInputType::Os => value
+                .source_os
+                .map_or_else(|| "-".to_string(), |os_guess| os_guess.os.to_string()),
+            FilterInputType::Process => value
+                .process
+                .as_ref()
+                .map_or_else(|| "-".to_string(), |process| process.name.clone()),
+            FilterInputType::Container => value
+                .process
+                .as_ref()
+                .and_then(|process| process.container.as_ref())
+                .map_or_else(|| "-".to_string(), |container| container.name.clone()),
         }
     }
 
@@ -214,6 +315,10 @@ pub fn clear_search(self, search_params: &SearchParameters) -> SearchParameters
                 service: String::new(),
                 ..search_params.clone()
             },
+            FilterInputType::State => SearchParameters {
+                state: String::new(),
+                ..search_params.clone()
+            },
             FilterInputType::Domain => SearchParameters {
                 domain: String::new(),
                 ..search_params.clone()
@@ -226,6 +331,22 @@ pub fn clear_search(self, search_params: &SearchParameters) -> SearchParameters
                 as_name: String::new(),
                 ..search_params.clone()
             },
+            FilterInputType::HttpHost => SearchParameters {
+                http_host: String::new(),
+                ..search_params.clone()
+            },
+            FilterInputType::Os => SearchParameters {
+                os: String::new(),
+                ..search_params.clone()
+            },
+            FilterInputType::Process => SearchParameters {
+                process: String::new(),
+                ..search_params.clone()
+            },
+            FilterInputType::Container => SearchParameters {
+                container: String::new(),
+                ..search_params.clone()
+            },
         }
     }
 
@@ -259,6 +380,10 @@ pub fn new_search(
                 service: new_value.trim().to_string(),
                 ..search_params.clone()
             },
+            FilterInputType::State => SearchParameters {
+                state: new_value.trim().to_string(),
+                ..search_params.clone()
+            },
             FilterInputType::Domain => SearchParameters {
                 domain: new_value.trim().to_string(),
                 ..search_params.clone()
@@ -271,6 +396,22 @@ pub fn new_search(
                 as_name: new_value,
                 ..search_params.clone()
             },
+            FilterInputType::HttpHost => SearchParameters {
+                http_host: new_value.trim().to_string(),
+                ..search_params.clone()
+            },
+            FilterInputType::Os => SearchParameters {
+                os: new_value,
+                ..search_params.clone()
+            },
+            FilterInputType::Process => SearchParameters {
+                process: new_value.trim().to_string(),
+                ..search_params.clone()
+            },
+            FilterInputType::Container => SearchParameters {
+                container: new_value.trim().to_string(),
+                ..search_params.clone()
+            },
         }
     }
 }
@@ -6,25 +6,42 @@
 
 use etherparse::err::ip::{HeaderError, LaxHeaderSliceError};
 use etherparse::err::{Layer, LenError};
-use etherparse::{LaxPacketHeaders, LenSource};
-use pcap::Packet;
+use etherparse::{EtherType, LaxPacketHeaders, LenSource};
 
 use crate::mmdb::types::mmdb_reader::MmdbReader;
 use crate::networking::manage_packets::{
-    analyze_headers, get_address_to_lookup, modify_or_insert_in_map, reverse_dns_lookup,
+    analyze_headers, detect_service_from_payload, get_address_to_lookup, get_dhcp_message,
+    get_dns_message, get_nbns_message, get_process, modify_or_insert_in_map, reverse_dns_lookup,
+    update_beacon_info, update_blocklist_info, update_blocklists_matches, update_detected_service,
+    update_dhcp_info, update_dns_info, update_exfiltration_info, update_http_info,
+    update_nbns_info, update_os_info, update_port_scans, update_process_info, update_rule_matches,
+    update_skewed_flow, update_tcp_connection, update_tls_info,
 };
+use crate::networking::types::beaconing::BeaconDetector;
+use crate::networking::types::blocklist::Blocklists;
 use crate::networking::types::capture_context::CaptureContext;
 use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::exfiltration::ExfiltrationDetector;
 use crate::networking::types::filters::Filters;
+use crate::networking::types::http_message::HttpMessage;
 use crate::networking::types::icmp_type::IcmpType;
-use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::networking::types::info_address_port_pair::InfoSnapshot;
 use crate::networking::types::my_device::MyDevice;
 use crate::networking::types::my_link_type::MyLinkType;
 use crate::networking::types::packet_filters_fields::PacketFiltersFields;
-use crate::InfoTraffic;
+use crate::networking::types::port_scan::{PortScanDetector, ScanThresholds};
+use crate::networking::types::process_info::ProcessResolver;
+use crate::networking::types::quic_initial::{
+    QuicClientHelloReassembler, QuicClientInitial, QUIC_PORT, QUIC_SERVICE,
+};
+use crate::networking::types::tls_client_hello::ClientHelloReassembler;
+use crate::networking::types::tunnel::TunnelAccounting;
+use crate::notifications::types::alert_rules::AlertRules;
+use crate::{InfoTraffic, Protocol};
 
 /// The calling thread enters a loop in which it waits for network packets, parses them according
 /// to the user specified filters, and inserts them into the shared map variable.
+#[allow(clippy::too_many_arguments)]
 pub fn parse_packets(
     current_capture_id: &Arc<Mutex<usize>>,
     device: &MyDevice,
@@ -32,12 +49,27 @@ pub fn parse_packets(
     info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
     country_mmdb_reader: &Arc<MmdbReader>,
     asn_mmdb_reader: &Arc<MmdbReader>,
+    blocklists_mutex: &Arc<Mutex<Blocklists>>,
+    alert_rules_mutex: &Arc<Mutex<AlertRules>>,
     capture_context: CaptureContext,
+    tunnel_accounting: TunnelAccounting,
+    scan_thresholds: ScanThresholds,
 ) {
     let my_link_type = capture_context.my_link_type();
     let (mut cap, mut savefile) = capture_context.consume();
 
     let capture_id = *current_capture_id.lock().unwrap();
+    // listing the devices can be expensive (especially in another network namespace):
+    // their addresses are only read when the capture starts
+    let my_interface_addresses = device.refresh_addresses();
+
+    let mut client_hellos = ClientHelloReassembler::default();
+    let mut quic_client_hellos = QuicClientHelloReassembler::default();
+    let process_resolver = ProcessResolver::default();
+    let mut blocklists_generation = 0;
+    let mut port_scan_detector = PortScanDetector::new(scan_thresholds);
+    let mut beacon_detector = BeaconDetector::default();
+    let mut exfiltration_detector = ExfiltrationDetector::default();
 
     loop {
         match cap.next_packet() {
@@ -56,6 +88,13 @@ pub fn parse_packets(
                     let mut mac_addresses = (None, None);
                     let mut icmp_type = IcmpType::default();
                     let mut packet_filters_fields = PacketFiltersFields::default();
+                    let mut tunnel = None;
+                    let mut tcp_segment = None;
+                    let mut app_payload: &[u8] = &[];
+                    // capture time of the packet, in microseconds
+                    #[allow(clippy::useless_conversion)]
+                    let timestamp = i64::from(packet.header.ts.tv_sec) * 1_000_000
+                        + i64::from(packet.header.ts.tv_usec);
 
                     let key_option = analyze_headers(
                         headers,
@@ -63,13 +102,36 @@ pub fn parse_packets(
                         &mut exchanged_bytes,
                         &mut icmp_type,
                         &mut packet_filters_fields,
+                        tunnel_accounting,
+                        &mut tunnel,
+                        &mut tcp_segment,
+                        &mut app_payload,
                     );
                     if key_option.is_none() {
                         continue;
                     }
 
                     let key = key_option.unwrap();
-                    let mut new_info = InfoAddressPortPair::default();
+                    let dns_message = get_dns_message(&key, app_payload);
+                    let dhcp_message = get_dhcp_message(&key, app_payload);
+                    let nbns_message = get_nbns_message(&key, app_payload);
+                    let source_mac = dhcp_message.as_ref().and(mac_addresses.0.clone());
+                    let quic_initial =
+                        if key.protocol == Protocol::UDP && key.port2 == Some(QUIC_PORT) {
+                            quic_client_hellos.feed(&key, app_payload)
+                        } else {
+                            None
+                        };
+                    let is_quic = quic_initial.is_some();
+                    let client_hello = match quic_initial {
+                        Some(QuicClientInitial::Complete(client_hello)) => Some(client_hello),
+                        Some(QuicClientInitial::Partial) => None,
+                        None => tcp_segment.and_then(|segment| {
+                            client_hellos.feed(&key, app_payload, segment.payload_len())
+                        }),
+                    };
+                    let http_message = tcp_segment.and_then(|_| HttpMessage::parse(app_payload));
+                    let mut new_info = InfoSnapshot::default();
 
                     let passed_filters = filters.matches(&packet_filters_fields);
                     if passed_filters {
@@ -81,12 +143,19 @@ pub fn parse_packets(
                         new_info = modify_or_insert_in_map(
                             info_traffic_mutex,
                             &key,
-                            device,
+                            &my_interface_addresses,
                             mac_addresses,
                             icmp_type,
                             exchanged_bytes,
+                            tunnel,
                         );
                     }
+                    // look for the local process owning the socket, until it's found
+                    let process = if passed_filters && !new_info.has_process {
+                        get_process(&process_resolver, &key, new_info.traffic_direction)
+                    } else {
+                        None
+                    };
 
                     let mut info_traffic = info_traffic_mutex
                         .lock()
@@ -94,14 +163,124 @@ pub fn parse_packets(
                     //increment number of sniffed packets and bytes
                     info_traffic.all_packets += 1;
                     info_traffic.all_bytes += exchanged_bytes;
-                    // update dropped packets number
-                    if let Ok(stats) = cap.stats() {
-                        info_traffic.dropped_packets = stats.dropped;
+                    // keep track of the DNS messages seen on the wire, regardless of the filters
+                    if let Some(dns_message) = &dns_message {
+                        update_dns_info(&mut info_traffic, &key, dns_message, timestamp);
+                        update_exfiltration_info(
+                            &mut info_traffic,
+                            &mut exfiltration_detector,
+                            &key,
+                            dns_message,
+                            timestamp,
+                        );
+                    }
+                    // keep track of the DHCP leases and servers, regardless of the filters
+                    if let Some(dhcp_message) = &dhcp_message {
+                        update_dhcp_info(&mut info_traffic, &key, dhcp_message, source_mac);
+                    }
+                    // keep track of the names announced by local devices, regardless of the filters
+                    if let Some(nbns_message) = &nbns_message {
+                        update_nbns_info(&mut info_traffic, nbns_message);
+                    }
+                    // keep track of the TLS server names requested, regardless of the filters
+                    if let Some(client_hello) = &client_hello {
+                        update_tls_info(&mut info_traffic, &key, client_hello);
+                    }
+                    // keep track of the plain HTTP requests and responses, regardless of the filters
+                    if let Some(http_message) = &http_message {
+                        update_http_info(&mut info_traffic, &key, http_message);
+                    }
+                    // guess the operating systems from the TCP handshakes, regardless of the filters
+                    if let Some(signature) = tcp_segment.and_then(|segment| segment.signature) {
+                        update_os_info(&mut info_traffic, &key, &signature);
                     }
 
                     if passed_filters {
                         info_traffic.add_packet(exchanged_bytes, new_info.traffic_direction);
 
+                        if let Some(process) = process {
+                            update_process_info(&mut info_traffic, &key, process);
+                        }
+
+                        // match the remote addresses against the blocklists, after every (re)load,
+                        // for every new connection, and when the server name is known
+                        let blocklists = blocklists_mutex.lock().unwrap();
+                        if blocklists.generation != blocklists_generation {
+                            blocklists_generation = blocklists.generation;
+                            let my_interface_addresses = device.addresses.lock().unwrap();
+                            update_blocklists_matches(
+                                &mut info_traffic,
+                                &blocklists,
+                                &my_interface_addresses,
+                            );
+                        } else if new_info.transmitted_packets == 1 || client_hello.is_some() {
+                            let my_interface_addresses = device.addresses.lock().unwrap();
+                            update_blocklist_info(
+                                &mut info_traffic,
+                                &key,
+                                &blocklists,
+                                &my_interface_addresses,
+                            );
+                        }
+                        drop(blocklists);
+
+                        // look for the remote hosts contacted at regular intervals
+                        update_beacon_info(
+                            &mut info_traffic,
+                            &mut beacon_detector,
+                            &get_address_to_lookup(&key, new_info.traffic_direction),
+                            timestamp,
+                        );
+
+                        // evaluate the custom alert rules
+                        update_rule_matches(
+                            &mut info_traffic,
+                            &mut alert_rules_mutex.lock().unwrap(),
+                            &key,
+                            &packet_filters_fields,
+                            &new_info,
+                            exchanged_bytes,
+                            timestamp,
+                            country_mmdb_reader,
+                            asn_mmdb_reader,
+                        );
+
+                        // look for connections sending much more than they receive
+                        update_skewed_flow(
+                            &mut info_traffic,
+                            &mut exfiltration_detector,
+                            &key,
+                            new_info.traffic_direction,
+                            new_info.transmitted_bytes,
+                            timestamp,
+                        );
+
+                        // look for port scans among the new connections
+                        if new_info.transmitted_packets == 1 {
+                            update_port_scans(
+                                &mut info_traffic,
+                                &mut port_scan_detector,
+                                &key,
+                                new_info.traffic_direction,
+                                tcp_segment,
+                                timestamp,
+                            );
+                        }
+
+                        // keep the first packets of the connection to inspect their bytes
+                        info_traffic.packet_samples.add(
+                            &key,
+                            timestamp,
+                            packet.header.len,
+                            tcp_segment.map(|segment| segment.flags),
+                            packet.data,
+                        );
+
+                        // keep track of the TCP connection state and metrics
+                        if let Some(tcp_segment) = tcp_segment {
+                            update_tcp_connection(&mut info_traffic, &key, tcp_segment, timestamp);
+                        }
+
                         // check the rDNS status of this address and act accordingly
                         let address_to_lookup =
                             get_address_to_lookup(&key, new_info.traffic_direction);
@@ -135,6 +314,8 @@ pub fn parse_packets(
                                 let device2 = device.clone();
                                 let country_db_reader_2 = country_mmdb_reader.clone();
                                 let asn_db_reader_2 = asn_mmdb_reader.clone();
+                                let blocklists_2 = blocklists_mutex.clone();
+                                let alert_rules_2 = alert_rules_mutex.clone();
                                 thread::Builder::new()
                                     .name("thread_reverse_dns_lookup".to_string())
                                     .spawn(move || {
@@ -145,6 +326,8 @@ pub fn parse_packets(
                                             &device2,
                                             &country_db_reader_2,
                                             &asn_db_reader_2,
+                                            &blocklists_2,
+                                            &alert_rules_2,
                                         );
                                     })
                                     .unwrap();
@@ -192,6 +375,41 @@ pub fn parse_packets(
                                     new_info.traffic_direction,
                                 )
                             });
+
+                        //increment the packet count for the application and container owning the socket
+                        let process = if new_info.has_process {
+                            info_traffic
+                                .map
+                                .get(&key)
+                                .and_then(|info| info.process.clone())
+                        } else {
+                            None
+                        };
+                        if let Some(process) = process {
+                            let data_info = DataInfo::new_with_first_packet(
+                                exchanged_bytes,
+                                new_info.traffic_direction,
+                            );
+                            *info_traffic.apps.entry(process.name.clone()).or_default() +=
+                                data_info;
+                            if let Some(container) = &process.container {
+                                *info_traffic
+                                    .containers
+                                    .entry(container.name.clone())
+                                    .or_default() += data_info;
+                            }
+                        }
+
+                        // identify the service from the payload, instead of guessing it from the ports
+                        if is_quic {
+                            update_detected_service(&mut info_traffic, &key, QUIC_SERVICE);
+                        } else {
+                            detect_service_from_payload(&mut info_traffic, &key, app_payload);
+                        }
+                    }
+                    // update dropped packets number
+                    if let Ok(stats) = cap.stats() {
+                        info_traffic.dropped_packets = stats.dropped;
                     }
                 }
             }
@@ -199,10 +417,10 @@ pub fn parse_packets(
     }
 }
 
-fn get_sniffable_headers<'a>(
-    packet: &'a Packet,
+fn get_sniffable_headers(
+    packet: &[u8],
     my_link_type: MyLinkType,
-) -> Result<LaxPacketHeaders<'a>, LaxHeaderSliceError> {
+) -> Result<LaxPacketHeaders<'_>, LaxHeaderSliceError> {
     match my_link_type {
         MyLinkType::Ethernet(_) | MyLinkType::Unsupported(_) | MyLinkType::NotYetAssigned => {
             LaxPacketHeaders::from_ethernet(packet).map_err(LaxHeaderSliceError::Len)
@@ -211,18 +429,15 @@ fn get_sniffable_headers<'a>(
             LaxPacketHeaders::from_ip(packet)
         }
         MyLinkType::Null(_) | MyLinkType::Loop(_) => from_null(packet),
+        MyLinkType::LinuxSll(_) => from_linux_sll(packet),
+        MyLinkType::LinuxSll2(_) => from_linux_sll2(packet),
+        MyLinkType::Ieee80211Radiotap(_) => from_ieee80211_radiotap(packet),
     }
 }
 
-fn from_null(packet: &[u8]) -> Result<LaxPacketHeaders, LaxHeaderSliceError> {
+fn from_null(packet: &[u8]) -> Result<LaxPacketHeaders<'_>, LaxHeaderSliceError> {
     if packet.len() <= 4 {
-        return Err(LaxHeaderSliceError::Len(LenError {
-            required_len: 4,
-            len: packet.len(),
-            len_source: LenSource::Slice,
-            layer: Layer::Ethernet2Header,
-            layer_start_offset: 0,
-        }));
+        return Err(too_short_error(4, packet.len(), Layer::Ethernet2Header));
     }
 
     let is_valid_af_inet = {
@@ -245,8 +460,1054 @@ fn matches(value: u32) -> bool {
     if is_valid_af_inet {
         LaxPacketHeaders::from_ip(&packet[4..])
     } else {
-        Err(LaxHeaderSliceError::Content(
-            HeaderError::UnsupportedIpVersion { version_number: 0 },
+        Err(unsupported_content_error())
+    }
+}
+
+fn from_linux_sll(packet: &[u8]) -> Result<LaxPacketHeaders<'_>, LaxHeaderSliceError> {
+    // based on https://www.tcpdump.org/linktypes/LINKTYPE_LINUX_SLL.html (2024-09-20)
+    // 16 bytes header: the protocol type is in the last two bytes
+    if packet.len() <= 16 {
+        return Err(too_short_error(16, packet.len(), Layer::LinuxSllHeader));
+    }
+    let ether_type = EtherType(u16::from_be_bytes([packet[14], packet[15]]));
+    Ok(LaxPacketHeaders::from_ether_type(ether_type, &packet[16..]))
+}
+
+fn from_linux_sll2(packet: &[u8]) -> Result<LaxPacketHeaders<'_>, LaxHeaderSliceError> {
+    // based on https://www.tcpdump.org/linktypes/LINKTYPE_LINUX_SLL2.html (2024-09-20)
+    // 20 bytes header: the protocol type is in the first two bytes
+    if packet.len() <= 20 {
+        return Err(too_short_error(20, packet.len(), Layer::LinuxSllHeader));
+    }
+    let ether_type = EtherType(u16::from_be_bytes([packet[0], packet[1]]));
+    Ok(LaxPacketHeaders::from_ether_type(ether_type, &packet[20..]))
+}
+
+fn from_ieee80211_radiotap(packet: &[u8]) -> Result<LaxPacketHeaders<'_>, LaxHeaderSliceError> {
+    // based on https://www.radiotap.org (2024-09-20)
+    // the radiotap header has a variable length, stored (little endian) in bytes 2 and 3
+    if packet.len() < 4 {
+        return Err(too_short_error(4, packet.len(), Layer::Ethernet2Header));
+    }
+    let radiotap_len = usize::from(u16::from_le_bytes([packet[2], packet[3]]));
+    let frame = packet.get(radiotap_len..).unwrap_or_default();
+
+    // only unprotected data frames carry a (readable) network layer
+    if frame.len() < 24 {
+        return Err(too_short_error(
+            radiotap_len + 24,
+            packet.len(),
+            Layer::Ethernet2Header,
+        ));
+    }
+    let (frame_control_0, frame_control_1) = (frame[0], frame[1]);
+    let is_data = (frame_control_0 >> 2) & 0b11 == 2;
+    let is_null = frame_control_0 & 0b0100_0000 != 0;
+    let is_protected = frame_control_1 & 0b0100_0000 != 0;
+    if !is_data || is_null || is_protected {
+        return Err(unsupported_content_error());
+    }
+
+    // MAC header length depends on the DS bits, on QoS, and on the HT control field
+    let to_and_from_ds = frame_control_1 & 0b11 == 0b11;
+    let is_qos = frame_control_0 & 0b1000_0000 != 0;
+    let has_ht_control = is_qos && frame_control_1 & 0b1000_0000 != 0;
+    let mac_header_len = 24
+        + if to_and_from_ds { 6 } else { 0 }
+        + if is_qos { 2 } else { 0 }
+        + if has_ht_control { 4 } else { 0 };
+
+    // LLC + SNAP header: AA AA 03 00 00 00 followed by the EtherType
+    let llc_snap_len = 8;
+    match frame.get(mac_header_len..mac_header_len + llc_snap_len) {
+        Some([0xAA, 0xAA, 0x03, 0x00, 0x00, 0x00, e0, e1]) => {
+            let ether_type = EtherType(u16::from_be_bytes([*e0, *e1]));
+            Ok(LaxPacketHeaders::from_ether_type(
+                ether_type,
+                &frame[mac_header_len + llc_snap_len..],
+            ))
+        }
+        Some(_) => Err(unsupported_content_error()),
+        None => Err(too_short_error(
+            radiotap_len + mac_header_len + llc_snap_len,
+            packet.len(),
+            Layer::Ethernet2Header,
+        )),
+    }
+}
+
+fn too_short_error(required_len: usize, len: usize, layer: Layer) -> LaxHeaderSliceError {
+    LaxHeaderSliceError::Len(LenError {
+        required_len,
+        len,
+        len_source: LenSource::Slice,
+        layer,
+        layer_start_offset: 0,
+    })
+}
+
+fn unsupported_content_error() -> LaxHeaderSliceError {
+    LaxHeaderSliceError::Content(HeaderError::UnsupportedIpVersion { version_number: 0 })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::manage_packets::{
+        analyze_headers, get_dhcp_message, get_dns_message, get_nbns_message, update_beacon_info,
+        update_dhcp_info, update_dns_info, update_http_info, update_nbns_info, update_os_info,
+        update_port_scans, update_tcp_connection, update_tls_info,
+    };
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::beaconing::BeaconDetector;
+    use crate::networking::types::data_info_host::DataInfoHost;
+    use crate::networking::types::dhcp_analytics::{DhcpClient, DhcpServer};
+    use crate::networking::types::dns_analytics::DnsTransaction;
+    use crate::networking::types::host::Host;
+    use crate::networking::types::http_message::{HttpMessage, HttpRequest};
+    use crate::networking::types::icmp_type::IcmpType;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::networking::types::local_names::NameProtocol;
+    use crate::networking::types::my_link_type::MyLinkType;
+    use crate::networking::types::os_fingerprint::OsGuess;
+    use crate::networking::types::packet_filters_fields::PacketFiltersFields;
+    use crate::networking::types::port_scan::{
+        PortScan, PortScanDetector, ScanKind, ScanThresholds,
+    };
+    use crate::networking::types::quic_initial::{
+        QuicClientHelloReassembler, QuicClientInitial, QUIC_PORT,
+    };
+    use crate::networking::types::tcp_state::{TcpSegment, TcpState};
+    use crate::networking::types::tls_client_hello::{ClientHelloReassembler, TlsClientHello};
+    use crate::networking::types::traffic_direction::TrafficDirection;
+    use crate::networking::types::tunnel::{Tunnel, TunnelAccounting, TunnelProtocol};
+    use crate::report::types::search_parameters::SearchParameters;
+    use crate::secondary_threads::parse_packets::get_sniffable_headers;
+    use crate::utils::test_captures::TestCapture;
+    use crate::{InfoTraffic, Protocol};
+
+    fn analyze_capture(
+        file_name: &str,
+        tunnel_accounting: TunnelAccounting,
+    ) -> Vec<(Option<AddressPortPair>, Option<Tunnel>)> {
+        let capture = TestCapture::load(file_name);
+        let my_link_type = MyLinkType::from_pcap_link_type(capture.link_type);
+        assert!(my_link_type.is_supported());
+        capture
+            .packets
+            .iter()
+            .map(|(_, packet)| {
+                let mut tunnel = None;
+                let key = get_sniffable_headers(packet, my_link_type)
+                    .ok()
+                    .and_then(|headers| {
+                        analyze_headers(
+                            headers,
+                            &mut (None, None),
+                            &mut 0,
+                            &mut IcmpType::default(),
+                            &mut PacketFiltersFields::default(),
+                            tunnel_accounting,
+                            &mut tunnel,
+                            &mut None,
+                            &mut &[][..],
+                        )
+                    });
+                (key, tunnel)
+            })
+            .collect()
+    }
+
+    fn keys_from_capture(file_name: &str) -> Vec<Option<AddressPortPair>> {
+        analyze_capture(file_name, TunnelAccounting::Outer)
+            .into_iter()
+            .map(|(key, tunnel)| {
+                assert!(tunnel.is_none());
+                key
+            })
+            .collect()
+    }
+
+    /// Analyzes each packet of a capture where all the packets have a transport layer,
+    /// yielding its timestamp, key, TCP segment, and application payload
+    fn analyzed_packets(
+        capture: &TestCapture,
+    ) -> impl Iterator<Item = (i64, AddressPortPair, Option<TcpSegment>, &[u8])> + '_ {
+        let my_link_type = MyLinkType::from_pcap_link_type(capture.link_type);
+        capture.packets.iter().map(move |(timestamp, packet)| {
+            let (key, tcp_segment, app_payload) =
+                analyze_packet(packet, my_link_type, &mut (None, None));
+            (*timestamp, key, tcp_segment, app_payload)
+        })
+    }
+
+    fn analyze_packet<'a>(
+        packet: &'a [u8],
+        my_link_type: MyLinkType,
+        mac_addresses: &mut (Option<String>, Option<String>),
+    ) -> (AddressPortPair, Option<TcpSegment>, &'a [u8]) {
+        let mut tcp_segment = None;
+        let mut app_payload: &[u8] = &[];
+        let key = analyze_headers(
+            get_sniffable_headers(packet, my_link_type).unwrap(),
+            mac_addresses,
+            &mut 0,
+            &mut IcmpType::default(),
+            &mut PacketFiltersFields::default(),
+            TunnelAccounting::Outer,
+            &mut None,
+            &mut tcp_segment,
+            &mut app_payload,
+        )
+        .unwrap();
+        (key, tcp_segment, app_payload)
+    }
+
+    fn expected_ipv4_tcp() -> Option<AddressPortPair> {
+        Some(AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(52000),
+            "93.184.216.34".to_string(),
+            Some(443),
+            Protocol::TCP,
+        ))
+    }
+
+    fn expected_ipv6_udp() -> Option<AddressPortPair> {
+        Some(AddressPortPair::new(
+            "2001:db8::1".to_string(),
+            Some(5353),
+            "2001:db8::2".to_string(),
+            Some(53),
+            Protocol::UDP,
+        ))
+    }
+
+    fn expected_outer_udp(port: u16) -> Option<AddressPortPair> {
+        Some(AddressPortPair::new(
+            "10.0.0.1".to_string(),
+            Some(50000),
+            "10.0.0.2".to_string(),
+            Some(port),
+            Protocol::UDP,
         ))
     }
+
+    fn expected_outer_other(number: u8) -> Option<AddressPortPair> {
+        Some(AddressPortPair::new(
+            "10.0.0.1".to_string(),
+            None,
+            "10.0.0.2".to_string(),
+            None,
+            Protocol::Other(number),
+        ))
+    }
+
+    fn expected_tunnel(protocol: TunnelProtocol, id: Option<u32>) -> Option<Tunnel> {
+        Some(Tunnel {
+            protocol,
+            outer_source: "10.0.0.1".parse().unwrap(),
+            outer_dest: "10.0.0.2".parse().unwrap(),
+            id,
+        })
+    }
+
+    #[test]
+    fn test_linux_sll_capture() {
+        assert!(matches!(
+            MyLinkType::from_pcap_link_type(TestCapture::load("linux_sll.pcap").link_type),
+            MyLinkType::LinuxSll(_)
+        ));
+        assert_eq!(
+            keys_from_capture("linux_sll.pcap"),
+            vec![expected_ipv4_tcp(), expected_ipv6_udp(), None]
+        );
+    }
+
+    #[test]
+    fn test_linux_sll2_capture() {
+        assert!(matches!(
+            MyLinkType::from_pcap_link_type(TestCapture::load("linux_sll2.pcap").link_type),
+            MyLinkType::LinuxSll2(_)
+        ));
+        assert_eq!(
+            keys_from_capture("linux_sll2.pcap"),
+            vec![expected_ipv4_tcp(), expected_ipv6_udp(), None]
+        );
+    }
+
+    #[test]
+    fn test_ieee80211_radiotap_capture() {
+        assert!(matches!(
+            MyLinkType::from_pcap_link_type(TestCapture::load("ieee80211_radiotap.pcap").link_type),
+            MyLinkType::Ieee80211Radiotap(_)
+        ));
+        // data frame, QoS data frame, beacon, protected data frame
+        assert_eq!(
+            keys_from_capture("ieee80211_radiotap.pcap"),
+            vec![expected_ipv4_tcp(), expected_ipv6_udp(), None, None]
+        );
+    }
+
+    #[test]
+    fn test_truncated_link_headers() {
+        for my_link_type in [
+            MyLinkType::LinuxSll(pcap::Linktype::LINUX_SLL),
+            MyLinkType::LinuxSll2(pcap::Linktype::LINUX_SLL2),
+            MyLinkType::Ieee80211Radiotap(pcap::Linktype::IEEE802_11_RADIOTAP),
+        ] {
+            assert!(get_sniffable_headers(&[], my_link_type).is_err());
+            assert!(get_sniffable_headers(&[0; 16], my_link_type).is_err());
+        }
+    }
+
+    #[test]
+    fn test_tunnels_capture_outer_accounting() {
+        // VXLAN, GRE with key, GRE with Ethernet, IPv4-in-IPv4, IPv6-in-IPv4,
+        // Geneve with Ethernet, Geneve with IPv6, GRE with ARP, truncated VXLAN
+        assert_eq!(
+            keys_from_capture("tunnels.pcap"),
+            vec![
+                expected_outer_udp(4789),
+                expected_outer_other(47),
+                expected_outer_other(47),
+                expected_outer_other(4),
+                expected_outer_other(41),
+                expected_outer_udp(6081),
+                expected_outer_udp(6081),
+                expected_outer_other(47),
+                expected_outer_udp(4789),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tunnels_capture_inner_accounting() {
+        assert_eq!(
+            analyze_capture("tunnels.pcap", TunnelAccounting::Inner),
+            vec![
+                (
+                    expected_ipv4_tcp(),
+                    expected_tunnel(TunnelProtocol::Vxlan, Some(42))
+                ),
+                (
+                    expected_ipv4_tcp(),
+                    expected_tunnel(TunnelProtocol::Gre, Some(7))
+                ),
+                (
+                    expected_ipv6_udp(),
+                    expected_tunnel(TunnelProtocol::Gre, None)
+                ),
+                (
+                    expected_ipv4_tcp(),
+                    expected_tunnel(TunnelProtocol::IpInIp, None)
+                ),
+                (
+                    expected_ipv6_udp(),
+                    expected_tunnel(TunnelProtocol::IpInIp, None)
+                ),
+                (
+                    expected_ipv4_tcp(),
+                    expected_tunnel(TunnelProtocol::Geneve, Some(1000))
+                ),
+                (
+                    expected_ipv6_udp(),
+                    expected_tunnel(TunnelProtocol::Geneve, Some(7))
+                ),
+                (expected_outer_other(47), None),
+                (expected_outer_udp(4789), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_other_protocols_capture() {
+        let expected_other = |address1: &str, address2: &str, number: u8| {
+            Some(AddressPortPair::new(
+                address1.to_string(),
+                None,
+                address2.to_string(),
+                None,
+                Protocol::Other(number),
+            ))
+        };
+        // SCTP, ESP, IGMP, OSPF, unassigned, truncated TCP
+        assert_eq!(
+            keys_from_capture("other_protocols.pcap"),
+            vec![
+                expected_other("192.168.1.10", "93.184.216.34", 132),
+                expected_other("192.168.1.10", "93.184.216.34", 50),
+                expected_other("192.168.1.10", "93.184.216.34", 2),
+                expected_other("fe80::1", "ff02::5", 89),
+                expected_other("192.168.1.10", "93.184.216.34", 200),
+                None,
+            ]
+        );
+    }
+
+    fn tcp_traffic_from_capture(file_name: &str) -> InfoTraffic {
+        let capture = TestCapture::load(file_name);
+        let mut info_traffic = InfoTraffic::new();
+        for (timestamp, key, tcp_segment, _) in analyzed_packets(&capture) {
+            info_traffic.map.entry(key.clone()).or_default();
+            update_tcp_connection(&mut info_traffic, &key, tcp_segment.unwrap(), timestamp);
+        }
+        info_traffic
+    }
+
+    #[test]
+    fn test_tcp_states_capture() {
+        let info_traffic = tcp_traffic_from_capture("tcp_states.pcap");
+
+        let states = |local_port: u16, remote_port: u16| {
+            let key = AddressPortPair::new(
+                "192.168.1.10".to_string(),
+                Some(local_port),
+                "93.184.216.34".to_string(),
+                Some(remote_port),
+                Protocol::TCP,
+            );
+            let state = |key: &AddressPortPair| {
+                info_traffic
+                    .map
+                    .get(key)
+                    .and_then(InfoAddressPortPair::tcp_state)
+            };
+            (state(&key), state(&key.reversed()))
+        };
+
+        let closed = Some(TcpState::Closed);
+        assert_eq!(states(52000, 443), (closed, closed));
+        let reset = Some(TcpState::Reset);
+        assert_eq!(states(52001, 81), (reset, reset));
+        let half_open = Some(TcpState::HalfOpen);
+        assert_eq!(states(52002, 22), (half_open, half_open));
+        assert_eq!(states(52003, 8080), (Some(TcpState::Opening), None));
+        let closing = Some(TcpState::Closing);
+        assert_eq!(states(52004, 993), (closing, closing));
+    }
+
+    #[test]
+    fn test_tcp_rtt_capture() {
+        let info_traffic = tcp_traffic_from_capture("tcp_rtt.pcap");
+        let key = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(52000),
+            "93.184.216.34".to_string(),
+            Some(443),
+            Protocol::TCP,
+        );
+        let metrics = |key: &AddressPortPair| {
+            info_traffic
+                .map
+                .get(key)
+                .and_then(|info| info.tcp_metrics.clone())
+                .unwrap()
+        };
+
+        let client = metrics(&key);
+        assert_eq!(client.handshake_rtt, Some(12_000));
+        assert_eq!(client.rtt.min, 10_000);
+        assert_eq!(client.rtt.average(), Some(16_666));
+        assert_eq!(client.rtt.max, 20_000);
+        assert_eq!(client.segments, 6);
+        assert_eq!(client.retransmissions, 1);
+        assert_eq!(client.out_of_order, 1);
+        assert!((client.retransmission_rate() - 1.0 / 6.0).abs() < f32::EPSILON);
+
+        let server = metrics(&key.reversed());
+        assert_eq!(server.handshake_rtt, Some(12_000));
+        assert_eq!(server.rtt.min, 2_000);
+        assert_eq!(server.rtt.average(), Some(2_000));
+        assert_eq!(server.segments, 1);
+        assert_eq!(server.retransmissions, 0);
+        assert_eq!(server.out_of_order, 0);
+    }
+
+    fn dns_traffic_from_capture(file_name: &str) -> InfoTraffic {
+        let capture = TestCapture::load(file_name);
+        let mut info_traffic = InfoTraffic::new();
+        for (timestamp, key, _, app_payload) in analyzed_packets(&capture) {
+            if let Some(dns_message) = get_dns_message(&key, app_payload) {
+                update_dns_info(&mut info_traffic, &key, &dns_message, timestamp);
+            }
+        }
+        info_traffic
+    }
+
+    #[test]
+    fn test_passive_dns_capture() {
+        let info_traffic = dns_traffic_from_capture("dns.pcap");
+
+        let dns_name = |address: &str| info_traffic.dns_names.get(address).map(String::as_str);
+        // UDP response following a CNAME chain
+        assert_eq!(dns_name("93.184.216.34"), Some("www.example.com"));
+        assert_eq!(dns_name("93.184.216.35"), Some("www.example.com"));
+        // TCP response
+        assert_eq!(dns_name("2606:2800:220:1::1"), Some("ipv6.example.org"));
+        // mDNS announcement
+        assert_eq!(dns_name("192.168.1.20"), Some("printer.local"));
+        // queries and failed responses don't resolve anything
+        assert_eq!(info_traffic.dns_names.len(), 4);
+    }
+
+    #[test]
+    fn test_dns_analytics_capture() {
+        let dns_analytics = dns_traffic_from_capture("dns.pcap").dns_analytics;
+        let transaction = |name: &str, record_type: u16, response_code, latency| DnsTransaction {
+            name: name.to_string(),
+            record_type,
+            resolver: "192.168.1.1".to_string(),
+            response_code,
+            latency,
+        };
+        // mDNS messages are excluded
+        assert_eq!(
+            dns_analytics.transactions,
+            vec![
+                transaction("www.example.com", 1, Some(0), Some(15_000)),
+                transaction("ipv6.example.org", 28, Some(0), None),
+                transaction("missing.example.com", 1, Some(3), Some(40_000)),
+                transaction("www.example.com", 28, None, None),
+                transaction("broken.example.net", 1, Some(2), None),
+            ]
+        );
+        assert_eq!(dns_analytics.nxdomain, 1);
+        assert_eq!(dns_analytics.servfail, 1);
+        assert_eq!(dns_analytics.average_latency(), Some(27_500));
+        assert_eq!(
+            dns_analytics.top_domains(2),
+            vec![
+                ("www.example.com".to_string(), 2),
+                ("missing.example.com".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tls_client_hello_capture() {
+        let capture = TestCapture::load("tls.pcap");
+        let mut info_traffic = InfoTraffic::new();
+        let mut client_hellos = ClientHelloReassembler::default();
+        for (_, key, tcp_segment, app_payload) in analyzed_packets(&capture) {
+            info_traffic.map.entry(key.clone()).or_default();
+            if let Some(client_hello) =
+                client_hellos.feed(&key, app_payload, tcp_segment.unwrap().payload_len())
+            {
+                update_tls_info(&mut info_traffic, &key, &client_hello);
+            }
+        }
+
+        let client_hello = |src: &str, src_port: u16, dst: &str, dst_port: u16| {
+            let key = AddressPortPair::new(
+                src.to_string(),
+                Some(src_port),
+                dst.to_string(),
+                Some(dst_port),
+                Protocol::TCP,
+            );
+            let info = info_traffic.map.get(&key).unwrap();
+            if let Some(reverse_info) = info_traffic.map.get(&key.reversed()) {
+                assert_eq!(info.client_hello, reverse_info.client_hello);
+            }
+            info.client_hello.clone()
+        };
+
+        // ClientHello reassembled from two segments
+        assert_eq!(
+            client_hello("192.168.1.10", 52000, "93.184.216.34", 443),
+            Some(TlsClientHello {
+                server_name: Some("www.example.com".to_string()),
+                alpn: vec!["h2".to_string(), "http/1.1".to_string()],
+            })
+        );
+        // ClientHello in a single segment
+        assert_eq!(
+            client_hello("2001:db8::10", 52001, "2606:2800:220:1::1", 8443),
+            Some(TlsClientHello {
+                server_name: Some("api.example.org".to_string()),
+                alpn: Vec::new(),
+            })
+        );
+        // not TLS
+        assert_eq!(
+            info_traffic
+                .map
+                .get(&AddressPortPair::new(
+                    "192.168.1.10".to_string(),
+                    Some(52002),
+                    "93.184.216.34".to_string(),
+                    Some(443),
+                    Protocol::TCP,
+                ))
+                .unwrap()
+                .client_hello,
+            None
+        );
+
+        let server_name =
+            |address: &str| info_traffic.server_names.get(address).map(String::as_str);
+        assert_eq!(server_name("93.184.216.34"), Some("www.example.com"));
+        assert_eq!(server_name("2606:2800:220:1::1"), Some("api.example.org"));
+        assert_eq!(info_traffic.server_names.len(), 2);
+
+        // search by server name, before the reverse DNS lookup completes
+        let key = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(52000),
+            "93.184.216.34".to_string(),
+            Some(443),
+            Protocol::TCP,
+        );
+        let info = info_traffic.map.get(&key).unwrap();
+        let search = |domain: &str| SearchParameters {
+            domain: domain.to_string(),
+            ..SearchParameters::default()
+        };
+        assert!(search("example.com").match_entry(&key, info, None, false));
+        assert!(!search("example.org").match_entry(&key, info, None, false));
+    }
+
+    #[test]
+    fn test_quic_initial_capture() {
+        let capture = TestCapture::load("quic.pcap");
+        let mut quic_client_hellos = QuicClientHelloReassembler::default();
+        let quic_initials: Vec<Option<QuicClientInitial>> = analyzed_packets(&capture)
+            .map(|(_, key, _, app_payload)| {
+                if key.port2 == Some(QUIC_PORT) {
+                    quic_client_hellos.feed(&key, app_payload)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        assert_eq!(
+            quic_initials,
+            vec![
+                // QUIC v1, ClientHello split across CRYPTO frames out of order
+                Some(QuicClientInitial::Complete(TlsClientHello {
+                    server_name: Some("quic.example.com".to_string()),
+                    alpn: vec!["h3".to_string()],
+                })),
+                // server reply
+                None,
+                // QUIC v2, ClientHello split across two Initial packets
+                Some(QuicClientInitial::Partial),
+                Some(QuicClientInitial::Complete(TlsClientHello {
+                    server_name: Some("v2.example.net".to_string()),
+                    alpn: vec!["h3".to_string()],
+                })),
+                // unknown version
+                None,
+                // authentication failure
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_http_capture() {
+        let capture = TestCapture::load("http.pcap");
+        let mut info_traffic = InfoTraffic::new();
+        for (_, key, _, app_payload) in analyzed_packets(&capture) {
+            info_traffic.map.entry(key.clone()).or_default();
+            if let Some(http_message) = HttpMessage::parse(app_payload) {
+                update_http_info(&mut info_traffic, &key, &http_message);
+            }
+        }
+
+        let key = |src_port: u16, dst_port: u16| {
+            AddressPortPair::new(
+                "192.168.1.20".to_string(),
+                Some(src_port),
+                "198.51.100.7".to_string(),
+                Some(dst_port),
+                Protocol::TCP,
+            )
+        };
+
+        // two requests, the second one preceded by an informational response
+        let info = info_traffic.map.get(&key(40000, 8080)).unwrap();
+        assert_eq!(
+            Vec::from(info.http_requests.clone()),
+            vec![
+                HttpRequest {
+                    method: "GET".to_string(),
+                    host: Some("legacy.example.net:8080".to_string()),
+                    path: "/firmware/update.bin".to_string(),
+                    user_agent: Some("OldDevice/1.0".to_string()),
+                    status: Some(200),
+                },
+                HttpRequest {
+                    method: "POST".to_string(),
+                    host: Some("legacy.example.net:8080".to_string()),
+                    path: "/api/report".to_string(),
+                    user_agent: Some("OldDevice/1.0".to_string()),
+                    status: Some(403),
+                },
+            ]
+        );
+        // requests are remembered by the client side only
+        assert!(info_traffic
+            .map
+            .get(&key(40000, 8080).reversed())
+            .unwrap()
+            .http_requests
+            .is_empty());
+
+        // request without headers, never answered
+        let info_no_headers = info_traffic.map.get(&key(40001, 80)).unwrap();
+        assert_eq!(
+            Vec::from(info_no_headers.http_requests.clone()),
+            vec![HttpRequest {
+                method: "HEAD".to_string(),
+                path: "/".to_string(),
+                ..HttpRequest::default()
+            }]
+        );
+
+        // search by HTTP host
+        let search = SearchParameters {
+            http_host: "legacy.example".to_string(),
+            ..SearchParameters::default()
+        };
+        assert!(search.match_entry(&key(40000, 8080), info, None, false));
+        assert!(!search.match_entry(&key(40001, 80), info_no_headers, None, false));
+    }
+
+    #[test]
+    fn test_dhcp_capture() {
+        let capture = TestCapture::load("dhcp.pcap");
+        let my_link_type = MyLinkType::from_pcap_link_type(capture.link_type);
+        let mut info_traffic = InfoTraffic::new();
+        for (_, packet) in &capture.packets {
+            let mut mac_addresses = (None, None);
+            let (key, _, app_payload) = analyze_packet(packet, my_link_type, &mut mac_addresses);
+            let dhcp_message = get_dhcp_message(&key, app_payload).unwrap();
+            update_dhcp_info(&mut info_traffic, &key, &dhcp_message, mac_addresses.0);
+        }
+
+        let dhcp_analytics = &info_traffic.dhcp_analytics;
+        assert_eq!(
+            dhcp_analytics.sorted_clients(),
+            vec![
+                &DhcpClient {
+                    mac_address: Some("3c:22:fb:01:02:03".to_string()),
+                    address: Some("192.168.1.23".to_string()),
+                    hostname: Some("alice-laptop".to_string()),
+                    vendor_class: Some("MSFT 5.0".to_string()),
+                    lease_time: Some(86400),
+                    server: Some("192.168.1.1".to_string()),
+                },
+                &DhcpClient {
+                    mac_address: Some("3c:22:fb:0a:0b:0c".to_string()),
+                    address: Some("2001:db8::42".to_string()),
+                    hostname: Some("nas1.lan".to_string()),
+                    vendor_class: None,
+                    lease_time: Some(86400),
+                    server: Some("fe80::1".to_string()),
+                },
+            ]
+        );
+
+        // a rogue server answered the discover too
+        let server = |address: &str, mac_address: &str, messages: usize| DhcpServer {
+            address: address.to_string(),
+            mac_address: Some(mac_address.to_string()),
+            messages,
+        };
+        assert_eq!(
+            dhcp_analytics.servers,
+            vec![
+                server("192.168.1.1", "02:00:00:00:00:01", 2),
+                server("192.168.1.254", "02:00:00:00:00:fe", 1),
+                server("fe80::1", "02:00:00:00:00:aa", 1),
+            ]
+        );
+        assert_eq!(
+            dhcp_analytics.servers_last_interval,
+            vec!["192.168.1.1", "192.168.1.254", "fe80::1"]
+        );
+        assert!(dhcp_analytics.multiple_servers());
+
+        assert_eq!(info_traffic.dhcp_names.len(), 2);
+        assert_eq!(
+            info_traffic.dhcp_names.get("192.168.1.23").unwrap(),
+            "alice-laptop"
+        );
+        assert_eq!(
+            info_traffic.dhcp_names.get("2001:db8::42").unwrap(),
+            "nas1.lan"
+        );
+    }
+
+    #[test]
+    fn test_local_names_capture() {
+        let capture = TestCapture::load("local_names.pcap");
+        let mut info_traffic = InfoTraffic::new();
+        // the smart TV was already resolved without a name
+        let unnamed_tv = Host {
+            domain: "192.168.1.60".to_string(),
+            ..Host::default()
+        };
+        info_traffic.addresses_resolved.insert(
+            "192.168.1.60".to_string(),
+            ("192.168.1.60".to_string(), unnamed_tv.clone()),
+        );
+        info_traffic
+            .hosts
+            .insert(unnamed_tv.clone(), DataInfoHost::default());
+        for (timestamp, key, _, app_payload) in analyzed_packets(&capture) {
+            if let Some(dns_message) = get_dns_message(&key, app_payload) {
+                update_dns_info(&mut info_traffic, &key, &dns_message, timestamp);
+            }
+            if let Some(nbns_message) = get_nbns_message(&key, app_payload) {
+                update_nbns_info(&mut info_traffic, &nbns_message);
+            }
+        }
+
+        let local_names = &info_traffic.local_names;
+        let devices = local_names.sorted_devices();
+        assert_eq!(devices.len(), 3);
+        // the printer announced its name and two service types
+        let printer = local_names.devices.get("192.168.1.50").unwrap();
+        assert_eq!(
+            printer.name,
+            Some(("office-printer.local".to_string(), NameProtocol::Mdns))
+        );
+        assert_eq!(
+            printer.services.iter().collect::<Vec<&String>>(),
+            vec!["_http._tcp", "_ipp._tcp"]
+        );
+        // the NetBIOS name of the PC doesn't replace the one answered over LLMNR
+        assert_eq!(
+            local_names.devices.get("192.168.1.42").unwrap().name,
+            Some(("desktop-7qk2m".to_string(), NameProtocol::Llmnr))
+        );
+        assert_eq!(
+            local_names.devices.get("192.168.1.60").unwrap().name,
+            Some(("LIVINGROOM-TV".to_string(), NameProtocol::Nbns))
+        );
+
+        // LLMNR names aren't DNS names, but mDNS ones are
+        assert_eq!(
+            info_traffic.dns_names.get("192.168.1.50").unwrap(),
+            "office-printer.local"
+        );
+        assert!(!info_traffic.dns_names.contains_key("192.168.1.42"));
+
+        // the TV host was relabelled with its name
+        let named_tv = Host {
+            domain: "LIVINGROOM-TV".to_string(),
+            ..Host::default()
+        };
+        assert_eq!(
+            info_traffic
+                .addresses_resolved
+                .get("192.168.1.60")
+                .unwrap()
+                .1,
+            named_tv
+        );
+        assert!(info_traffic.hosts.contains_key(&named_tv));
+        assert!(!info_traffic.hosts.contains_key(&unnamed_tv));
+        // no DNS transaction was recorded
+        assert!(info_traffic.dns_analytics.transactions.is_empty());
+    }
+
+    #[test]
+    fn test_os_fingerprint_capture() {
+        let capture = TestCapture::load("os_fingerprint.pcap");
+        let mut info_traffic = InfoTraffic::new();
+        for (_, key, tcp_segment, _) in analyzed_packets(&capture) {
+            info_traffic.map.entry(key.clone()).or_default();
+            let signature = tcp_segment.unwrap().signature.unwrap();
+            update_os_info(&mut info_traffic, &key, &signature);
+        }
+
+        let linux = OsGuess {
+            os: "Linux 4.x-6.x",
+            confidence: 95,
+            hop_distance: 0,
+        };
+        let windows_server = OsGuess {
+            os: "Windows",
+            confidence: 80,
+            hop_distance: 11,
+        };
+        let windows_client = OsGuess {
+            os: "Windows 10/11",
+            confidence: 95,
+            hop_distance: 0,
+        };
+        // the less confident guess of the Linux client was discarded, and the unknown layout ignored
+        assert_eq!(info_traffic.os_guesses.len(), 3);
+        assert_eq!(info_traffic.os_guesses.get("192.168.1.20"), Some(&linux));
+        assert_eq!(
+            info_traffic.os_guesses.get("192.168.1.30"),
+            Some(&windows_client)
+        );
+        assert_eq!(
+            info_traffic.os_guesses.get("203.0.113.10"),
+            Some(&windows_server)
+        );
+
+        let key = |address: &str, port: u16| {
+            AddressPortPair::new(
+                address.to_string(),
+                Some(port),
+                "203.0.113.10".to_string(),
+                Some(443),
+                Protocol::TCP,
+            )
+        };
+        // both directions of the connection know the guesses of both the endpoints
+        let info = info_traffic.map.get(&key("192.168.1.20", 50000)).unwrap();
+        assert_eq!(info.source_os, Some(linux));
+        assert_eq!(info.destination_os, Some(windows_server));
+        let info_reversed = info_traffic
+            .map
+            .get(&key("192.168.1.20", 50000).reversed())
+            .unwrap();
+        assert_eq!(info_reversed.source_os, Some(windows_server));
+        assert_eq!(info_reversed.destination_os, Some(linux));
+        let info_windows = info_traffic.map.get(&key("192.168.1.30", 50001)).unwrap();
+        assert_eq!(info_windows.source_os, Some(windows_client));
+
+        // search by operating system, matching either the source or the destination
+        let search = SearchParameters {
+            os: "linux".to_string(),
+            ..SearchParameters::default()
+        };
+        assert!(search.match_entry(&key("192.168.1.20", 50000), info, None, false));
+        assert!(search.match_entry(
+            &key("192.168.1.20", 50000).reversed(),
+            info_reversed,
+            None,
+            false
+        ));
+        assert!(!search.match_entry(&key("192.168.1.30", 50001), info_windows, None, false));
+    }
+
+    fn port_scans_from_capture(file_name: &str, scan_thresholds: ScanThresholds) -> Vec<PortScan> {
+        let capture = TestCapture::load(file_name);
+        let mut info_traffic = InfoTraffic::new();
+        let mut detector = PortScanDetector::new(scan_thresholds);
+        for (timestamp, key, tcp_segment, _) in analyzed_packets(&capture) {
+            // only the first packet of each connection is fed to the detector
+            if info_traffic.map.contains_key(&key) {
+                continue;
+            }
+            info_traffic.map.entry(key.clone()).or_default();
+            // the capture was taken on 192.168.1.10
+            let traffic_direction = if key.address1 == "192.168.1.10" {
+                TrafficDirection::Outgoing
+            } else {
+                TrafficDirection::Incoming
+            };
+            update_port_scans(
+                &mut info_traffic,
+                &mut detector,
+                &key,
+                traffic_direction,
+                tcp_segment,
+                timestamp,
+            );
+        }
+        info_traffic.port_scans_last_interval
+    }
+
+    #[test]
+    fn test_port_scans_capture() {
+        let scans = port_scans_from_capture("port_scans.pcap", ScanThresholds::default());
+        // the replies of the scanned hosts, the browser, the web server,
+        // the DNS queries and the slow scan don't raise any scan
+        assert_eq!(
+            scans,
+            vec![
+                // incoming horizontal scan
+                PortScan {
+                    kind: ScanKind::Horizontal,
+                    scanner: "203.0.113.66".to_string(),
+                    protocol: Protocol::TCP,
+                    targets: 20,
+                    ports: (22, 22),
+                },
+                // outgoing vertical scan
+                PortScan {
+                    kind: ScanKind::Vertical("192.168.1.50".to_string()),
+                    scanner: "192.168.1.10".to_string(),
+                    protocol: Protocol::TCP,
+                    targets: 20,
+                    ports: (20, 39),
+                },
+            ]
+        );
+
+        // with lower thresholds, the slow scan is detected as well
+        let scans = port_scans_from_capture(
+            "port_scans.pcap",
+            ScanThresholds {
+                window: 60,
+                hosts: 50,
+                ports: 10,
+            },
+        );
+        assert_eq!(
+            scans,
+            vec![
+                PortScan {
+                    kind: ScanKind::Vertical("192.168.1.50".to_string()),
+                    scanner: "192.168.1.10".to_string(),
+                    protocol: Protocol::TCP,
+                    targets: 10,
+                    ports: (20, 29),
+                },
+                PortScan {
+                    kind: ScanKind::Vertical("192.168.1.10".to_string()),
+                    scanner: "198.51.100.200".to_string(),
+                    protocol: Protocol::TCP,
+                    targets: 10,
+                    ports: (1000, 1009),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_beacons_capture() {
+        let capture = TestCapture::load("beacons.pcap");
+        let mut info_traffic = InfoTraffic::new();
+        let mut detector = BeaconDetector::default();
+        for (timestamp, key, _, _) in analyzed_packets(&capture) {
+            let remote_address = if key.address1 == "192.168.1.10" {
+                &key.address2
+            } else {
+                &key.address1
+            };
+            update_beacon_info(&mut info_traffic, &mut detector, remote_address, timestamp);
+        }
+
+        // a new connection every minute and a keepalive every 30 seconds are reported once
+        let reported: Vec<&str> = info_traffic
+            .beacons_last_interval
+            .iter()
+            .map(|(address, _)| address.as_str())
+            .collect();
+        assert_eq!(reported, vec!["198.51.100.20", "203.0.113.80"]);
+
+        let beacon = info_traffic.beacons["203.0.113.80"];
+        assert!((59.5..=60.5).contains(&beacon.period_secs()));
+        assert_eq!(beacon.bursts, 12);
+        assert!(beacon.is_suspicious());
+        let beacon = info_traffic.beacons["198.51.100.20"];
+        assert!((29.9..=30.1).contains(&beacon.period_secs()));
+        assert_eq!(beacon.bursts, 24);
+        assert!(beacon.is_suspicious());
+
+        // irregular browsing isn't suspicious, and a continuous stream is a single burst
+        assert!(!info_traffic.beacons["93.184.216.34"].is_suspicious());
+        assert!(!info_traffic.beacons.contains_key("198.51.100.99"));
+    }
 }
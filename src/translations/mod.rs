@@ -2,4 +2,5 @@
 pub mod translations;
 pub mod translations_2;
 pub mod translations_3;
+pub mod translations_4;
 pub mod types;
@@ -0,0 +1,838 @@
+#![allow(clippy::match_same_arms)]
+
+use crate::translations::types::language::Language;
+
+pub fn tunnel_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Tunnel",
+        Language::IT => "Tunnel",
+        _ => "Tunnel",
+    }
+}
+
+pub fn tunnel_accounting_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Tunnelled traffic",
+        Language::IT => "Traffico incapsulato",
+        _ => "Tunnelled traffic",
+    }
+}
+
+pub fn outer_flows_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Outer",
+        Language::IT => "Esterno",
+        _ => "Outer",
+    }
+}
+
+pub fn inner_flows_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Inner",
+        Language::IT => "Interno",
+        _ => "Inner",
+    }
+}
+
+pub fn state_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "State",
+        Language::IT => "Stato",
+        _ => "State",
+    }
+}
+
+pub fn round_trip_time_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Round-trip time",
+        Language::IT => "Tempo di andata e ritorno",
+        _ => "Round-trip time",
+    }
+}
+
+pub fn handshake_rtt_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Handshake RTT",
+        Language::IT => "RTT dell'handshake",
+        _ => "Handshake RTT",
+    }
+}
+
+pub fn retransmissions_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Retransmissions",
+        Language::IT => "Ritrasmissioni",
+        _ => "Retransmissions",
+    }
+}
+
+pub fn out_of_order_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Out-of-order segments",
+        Language::IT => "Segmenti fuori ordine",
+        _ => "Out-of-order segments",
+    }
+}
+
+pub fn queried_domain_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Queried domain name",
+        Language::IT => "Nome di dominio richiesto",
+        _ => "Queried domain name",
+    }
+}
+
+pub fn top_queried_domains_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Top queried domains",
+        Language::IT => "Domini più richiesti",
+        _ => "Top queried domains",
+    }
+}
+
+pub fn queries_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Queries",
+        Language::IT => "Richieste",
+        _ => "Queries",
+    }
+}
+
+pub fn record_type_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Type",
+        Language::IT => "Tipo",
+        _ => "Type",
+    }
+}
+
+pub fn resolver_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Resolver",
+        Language::IT => "Resolver",
+        _ => "Resolver",
+    }
+}
+
+pub fn response_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Response",
+        Language::IT => "Risposta",
+        _ => "Response",
+    }
+}
+
+pub fn latency_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Latency",
+        Language::IT => "Latenza",
+        _ => "Latency",
+    }
+}
+
+pub fn average_latency_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Average latency",
+        Language::IT => "Latenza media",
+        _ => "Average latency",
+    }
+}
+
+pub fn no_dns_traffic_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "No DNS traffic observed yet",
+        Language::IT => "Nessun traffico DNS ancora osservato",
+        _ => "No DNS traffic observed yet",
+    }
+}
+
+pub fn server_name_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Server name",
+        Language::IT => "Nome del server",
+        _ => "Server name",
+    }
+}
+
+pub fn detected_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "detected",
+        Language::IT => "rilevato",
+        _ => "detected",
+    }
+}
+
+pub fn by_port_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "by port",
+        Language::IT => "dalla porta",
+        _ => "by port",
+    }
+}
+
+pub fn http_host_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "HTTP host",
+        Language::IT => "Host HTTP",
+        _ => "HTTP host",
+    }
+}
+
+pub fn http_requests_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "HTTP requests",
+        Language::IT => "Richieste HTTP",
+        _ => "HTTP requests",
+    }
+}
+
+pub fn dhcp_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when more than one DHCP server answers",
+        Language::IT => "Notificami quando risponde più di un server DHCP",
+        _ => "Notify me when more than one DHCP server answers",
+    }
+}
+
+pub fn multiple_dhcp_servers_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Multiple DHCP servers answered",
+        Language::IT => "Hanno risposto più server DHCP",
+        _ => "Multiple DHCP servers answered",
+    }
+}
+
+pub fn dhcp_servers_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "DHCP servers",
+        Language::IT => "Server DHCP",
+        _ => "DHCP servers",
+    }
+}
+
+pub fn devices_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Devices",
+        Language::IT => "Dispositivi",
+        _ => "Devices",
+    }
+}
+
+pub fn host_name_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Host name",
+        Language::IT => "Nome host",
+        _ => "Host name",
+    }
+}
+
+pub fn vendor_class_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Vendor class",
+        Language::IT => "Classe fornitore",
+        _ => "Vendor class",
+    }
+}
+
+pub fn lease_time_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Lease time",
+        Language::IT => "Durata lease",
+        _ => "Lease time",
+    }
+}
+
+pub fn no_dhcp_traffic_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "No DHCP exchange observed yet",
+        Language::IT => "Nessuno scambio DHCP ancora osservato",
+        _ => "No DHCP exchange observed yet",
+    }
+}
+
+pub fn dhcp_leases_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "DHCP leases",
+        Language::IT => "Lease DHCP",
+        _ => "DHCP leases",
+    }
+}
+
+pub fn announced_names_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Announced names",
+        Language::IT => "Nomi annunciati",
+        _ => "Announced names",
+    }
+}
+
+pub fn services_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Services",
+        Language::IT => "Servizi",
+        _ => "Services",
+    }
+}
+
+pub fn no_announced_names_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "No name announcement observed yet",
+        Language::IT => "Nessun annuncio di nomi ancora osservato",
+        _ => "No name announcement observed yet",
+    }
+}
+
+pub fn operating_system_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Operating system",
+        Language::IT => "Sistema operativo",
+        _ => "Operating system",
+    }
+}
+
+pub fn hop_distance_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Hop distance",
+        Language::IT => "Distanza in hop",
+        _ => "Hop distance",
+    }
+}
+
+pub fn packets_tab_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Packets",
+        Language::IT => "Pacchetti",
+        _ => "Packets",
+    }
+}
+
+pub fn no_packets_kept_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "No packet was kept for this connection",
+        Language::IT => "Nessun pacchetto è stato conservato per questa connessione",
+        _ => "No packet was kept for this connection",
+    }
+}
+
+pub fn truncated_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "truncated",
+        Language::IT => "troncato",
+        _ => "truncated",
+    }
+}
+
+pub fn process_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Process",
+        Language::IT => "Processo",
+        _ => "Process",
+    }
+}
+
+pub fn application_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Application",
+        Language::IT => "Applicazione",
+        _ => "Application",
+    }
+}
+
+pub fn container_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Container",
+        Language::IT => "Container",
+        _ => "Container",
+    }
+}
+
+pub fn network_namespace_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Network namespace",
+        Language::IT => "Namespace di rete",
+        _ => "Network namespace",
+    }
+}
+
+pub fn host_namespace_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Host (current namespace)",
+        Language::IT => "Host (namespace corrente)",
+        _ => "Host (current namespace)",
+    }
+}
+
+pub fn blocklists_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Blocklists",
+        Language::IT => "Blocklist",
+        _ => "Blocklists",
+    }
+}
+
+pub fn blocklists_loaded_translation(language: Language, lists: usize, entries: usize) -> String {
+    match language {
+        Language::EN => format!("{lists} lists loaded ({entries} entries)"),
+        Language::IT => format!("{lists} liste caricate ({entries} voci)"),
+        _ => format!("{lists} lists loaded ({entries} entries)"),
+    }
+}
+
+pub fn blocklist_hits_translation(language: Language, entries: usize, hits: usize) -> String {
+    match language {
+        Language::EN => format!("{entries} entries, {hits} hits"),
+        Language::IT => format!("{entries} voci, {hits} corrispondenze"),
+        _ => format!("{entries} entries, {hits} hits"),
+    }
+}
+
+pub fn reload_every_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Reload every",
+        Language::IT => "Ricarica ogni",
+        _ => "Reload every",
+    }
+}
+
+pub fn never_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Never",
+        Language::IT => "Mai",
+        _ => "Never",
+    }
+}
+
+pub fn blocklist_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when a remote address is in a blocklist",
+        Language::IT => "Notificami quando un indirizzo remoto è in una blocklist",
+        _ => "Notify me when a remote address is in a blocklist",
+    }
+}
+
+pub fn blocklist_matches_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Remote addresses found in a blocklist",
+        Language::IT => "Indirizzi remoti trovati in una blocklist",
+        _ => "Remote addresses found in a blocklist",
+    }
+}
+
+pub fn blocklisted_addresses_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Addresses (list)",
+        Language::IT => "Indirizzi (lista)",
+        _ => "Addresses (list)",
+    }
+}
+
+pub fn blocklisted_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "In blocklist",
+        Language::IT => "In blocklist",
+        _ => "In blocklist",
+    }
+}
+
+pub fn port_scan_detection_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Port scan detection",
+        Language::IT => "Rilevamento delle scansioni di porte",
+        _ => "Port scan detection",
+    }
+}
+
+pub fn time_window_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Time window",
+        Language::IT => "Finestra temporale",
+        _ => "Time window",
+    }
+}
+
+pub fn hosts_on_same_port_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Hosts on the same port",
+        Language::IT => "Host sulla stessa porta",
+        _ => "Hosts on the same port",
+    }
+}
+
+pub fn ports_on_same_host_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Ports on the same host",
+        Language::IT => "Porte sullo stesso host",
+        _ => "Ports on the same host",
+    }
+}
+
+pub fn port_scan_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when a port scan is detected",
+        Language::IT => "Notificami quando viene rilevata una scansione di porte",
+        _ => "Notify me when a port scan is detected",
+    }
+}
+
+pub fn port_scans_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Port scans detected",
+        Language::IT => "Scansioni di porte rilevate",
+        _ => "Port scans detected",
+    }
+}
+
+pub fn horizontal_scan_translation(
+    language: Language,
+    scanner: &str,
+    hosts: usize,
+    port: u16,
+) -> String {
+    match language {
+        Language::EN => format!("{scanner} → {hosts} hosts on port {port}"),
+        Language::IT => format!("{scanner} → {hosts} host sulla porta {port}"),
+        _ => format!("{scanner} → {hosts} hosts on port {port}"),
+    }
+}
+
+pub fn vertical_scan_translation(
+    language: Language,
+    scanner: &str,
+    target: &str,
+    ports: usize,
+    port_range: (u16, u16),
+) -> String {
+    let (first, last) = port_range;
+    match language {
+        Language::EN => format!("{scanner} → {target}: {ports} ports ({first}-{last})"),
+        Language::IT => format!("{scanner} → {target}: {ports} porte ({first}-{last})"),
+        _ => format!("{scanner} → {target}: {ports} ports ({first}-{last})"),
+    }
+}
+
+pub fn low_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Low",
+        Language::IT => "Bassa",
+        _ => "Low",
+    }
+}
+
+pub fn medium_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Medium",
+        Language::IT => "Media",
+        _ => "Medium",
+    }
+}
+
+pub fn high_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "High",
+        Language::IT => "Alta",
+        _ => "High",
+    }
+}
+
+pub fn sensitivity_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Sensitivity",
+        Language::IT => "Sensibilità",
+        _ => "Sensitivity",
+    }
+}
+
+pub fn anomaly_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when the traffic rate deviates from its usual values",
+        Language::IT => "Notificami quando il traffico si discosta dai suoi valori abituali",
+        _ => "Notify me when the traffic rate deviates from its usual values",
+    }
+}
+
+pub fn traffic_anomaly_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Unusual traffic rate",
+        Language::IT => "Traffico insolito",
+        _ => "Unusual traffic rate",
+    }
+}
+
+pub fn expected_observed_translation(language: Language, expected: &str, observed: &str) -> String {
+    match language {
+        Language::EN => format!("expected {expected}, observed {observed}"),
+        Language::IT => format!("atteso {expected}, osservato {observed}"),
+        _ => format!("expected {expected}, observed {observed}"),
+    }
+}
+
+pub fn beacon_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when a remote host is contacted at suspiciously regular intervals",
+        Language::IT => "Notificami quando un host remoto viene contattato a intervalli sospettosamente regolari",
+        _ => "Notify me when a remote host is contacted at suspiciously regular intervals",
+    }
+}
+
+pub fn periodic_hosts_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Hosts contacted at regular intervals",
+        Language::IT => "Host contattati a intervalli regolari",
+        _ => "Hosts contacted at regular intervals",
+    }
+}
+
+pub fn beacon_description_translation(language: Language, period: f64, score: u8) -> String {
+    match language {
+        Language::EN => format!("every {period:.1} s (score {score})"),
+        Language::IT => format!("ogni {period:.1} s (punteggio {score})"),
+        _ => format!("every {period:.1} s (score {score})"),
+    }
+}
+
+pub fn beacons_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Beacons",
+        Language::IT => "Beacon",
+        _ => "Beacons",
+    }
+}
+
+pub fn period_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Period",
+        Language::IT => "Periodo",
+        _ => "Period",
+    }
+}
+
+pub fn jitter_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Jitter",
+        Language::IT => "Jitter",
+        _ => "Jitter",
+    }
+}
+
+pub fn bursts_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Bursts",
+        Language::IT => "Raffiche",
+        _ => "Bursts",
+    }
+}
+
+pub fn score_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Score",
+        Language::IT => "Punteggio",
+        _ => "Score",
+    }
+}
+
+pub fn no_periodic_hosts_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "No host has been contacted repeatedly enough to estimate a period yet",
+        Language::IT => {
+            "Nessun host è stato ancora contattato abbastanza volte da stimarne un periodo"
+        }
+        _ => "No host has been contacted repeatedly enough to estimate a period yet",
+    }
+}
+
+pub fn exfiltration_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Possible DNS tunnelling or data exfiltration",
+        Language::IT => "Possibile tunneling DNS o esfiltrazione di dati",
+        _ => "Possible DNS tunnelling or data exfiltration",
+    }
+}
+
+pub fn suspicious_labels_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me about long or random-looking DNS query labels",
+        Language::IT => "Notificami riguardo etichette DNS lunghe o dall'aspetto casuale",
+        _ => "Notify me about long or random-looking DNS query labels",
+    }
+}
+
+pub fn unique_subdomains_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me about many unique subdomains queried under the same domain",
+        Language::IT => {
+            "Notificami riguardo molti sottodomini distinti richiesti per lo stesso dominio"
+        }
+        _ => "Notify me about many unique subdomains queried under the same domain",
+    }
+}
+
+pub fn large_records_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me about large TXT and NULL DNS responses",
+        Language::IT => "Notificami riguardo risposte DNS TXT e NULL di grandi dimensioni",
+        _ => "Notify me about large TXT and NULL DNS responses",
+    }
+}
+
+pub fn skewed_flows_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me about large connections sending much more than they receive",
+        Language::IT => {
+            "Notificami riguardo connessioni voluminose che inviano molto più di quanto ricevono"
+        }
+        _ => "Notify me about large connections sending much more than they receive",
+    }
+}
+
+pub fn suspicious_label_evidence_translation(
+    language: Language,
+    name: &str,
+    label_len: usize,
+    entropy: f64,
+) -> String {
+    match language {
+        Language::EN => {
+            format!("queried {name} (label of {label_len} characters, entropy {entropy:.1} bits)")
+        }
+        Language::IT => {
+            format!("ha richiesto {name} (etichetta di {label_len} caratteri, entropia {entropy:.1} bit)")
+        }
+        _ => format!("queried {name} (label of {label_len} characters, entropy {entropy:.1} bits)"),
+    }
+}
+
+pub fn unique_subdomains_evidence_translation(
+    language: Language,
+    parent: &str,
+    count: usize,
+) -> String {
+    match language {
+        Language::EN => format!("queried {count} unique subdomains of {parent}"),
+        Language::IT => format!("ha richiesto {count} sottodomini distinti di {parent}"),
+        _ => format!("queried {count} unique subdomains of {parent}"),
+    }
+}
+
+pub fn large_records_evidence_translation(
+    language: Language,
+    name: &str,
+    record_type: &str,
+    size: usize,
+) -> String {
+    match language {
+        Language::EN => format!("received {size} bytes of records for {name} ({record_type})"),
+        Language::IT => format!("ha ricevuto {size} byte di record per {name} ({record_type})"),
+        _ => format!("received {size} bytes of records for {name} ({record_type})"),
+    }
+}
+
+pub fn skewed_flow_evidence_translation(
+    language: Language,
+    remote: &str,
+    outgoing: &str,
+    incoming: &str,
+) -> String {
+    match language {
+        Language::EN => format!("sent {outgoing} to {remote}, receiving {incoming}"),
+        Language::IT => format!("ha inviato {outgoing} a {remote}, ricevendo {incoming}"),
+        _ => format!("sent {outgoing} to {remote}, receiving {incoming}"),
+    }
+}
+
+pub fn alert_rules_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Alert rules",
+        Language::IT => "Regole di allerta",
+        _ => "Alert rules",
+    }
+}
+
+pub fn rules_file_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Rules file",
+        Language::IT => "File delle regole",
+        _ => "Rules file",
+    }
+}
+
+pub fn select_rules_file_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Select a rules file",
+        Language::IT => "Seleziona un file di regole",
+        _ => "Select a rules file",
+    }
+}
+
+pub fn rules_loaded_translation(language: Language, rules: usize) -> String {
+    match language {
+        Language::EN => format!("{rules} rules loaded"),
+        Language::IT => format!("{rules} regole caricate"),
+        _ => format!("{rules} rules loaded"),
+    }
+}
+
+pub fn rule_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when a custom alert rule matches",
+        Language::IT => "Notificami quando una regola di allerta personalizzata è soddisfatta",
+        _ => "Notify me when a custom alert rule matches",
+    }
+}
+
+pub fn rule_matches_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Alert rules matched",
+        Language::IT => "Regole di allerta soddisfatte",
+        _ => "Alert rules matched",
+    }
+}
+
+pub fn rule_connection_evidence_translation(
+    language: Language,
+    protocol: &str,
+    source: &str,
+    destination: &str,
+) -> String {
+    match language {
+        Language::EN => format!("{protocol} connection from {source} to {destination}"),
+        Language::IT => format!("connessione {protocol} da {source} a {destination}"),
+        _ => format!("{protocol} connection from {source} to {destination}"),
+    }
+}
+
+pub fn rule_traffic_evidence_translation(
+    language: Language,
+    bytes: &str,
+    packets: u128,
+    window: u64,
+) -> String {
+    match language {
+        Language::EN => format!("{bytes} in {packets} packets within {window} s"),
+        Language::IT => format!("{bytes} in {packets} pacchetti entro {window} s"),
+        _ => format!("{bytes} in {packets} packets within {window} s"),
+    }
+}
+
+pub fn rule_new_host_evidence_translation(language: Language, host: &str) -> String {
+    match language {
+        Language::EN => format!("new host {host}"),
+        Language::IT => format!("nuovo host {host}"),
+        _ => format!("new host {host}"),
+    }
+}
+
+pub fn other_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Other",
+        Language::IT => "Altro",
+        _ => "Other",
+    }
+}
+
+pub fn applied_next_capture_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Applied from the next capture",
+        Language::IT => "Applicato dalla prossima cattura",
+        _ => "Applied from the next capture",
+    }
+}
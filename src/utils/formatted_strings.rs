@@ -24,6 +24,24 @@ pub fn get_percentage_string(observed: u128, filtered: u128) -> String {
     }
 }
 
+/// Formats a round-trip time expressed in microseconds
+pub fn get_formatted_rtt(rtt: i64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let rtt_ms = rtt as f64 / 1000.0;
+    format!("{rtt_ms:.1} ms")
+}
+
+/// Formats a duration expressed in seconds with the largest unit that divides it
+pub fn get_formatted_duration(seconds: u32) -> String {
+    match seconds {
+        0 => "0 s".to_string(),
+        s if s % 86400 == 0 => format!("{} d", s / 86400),
+        s if s % 3600 == 0 => format!("{} h", s / 3600),
+        s if s % 60 == 0 => format!("{} min", s / 60),
+        s => format!("{s} s"),
+    }
+}
+
 pub fn get_invalid_filters_string(filters: &Filters, language: Language) -> String {
     let mut ret_val = format!("{}:", invalid_filters_translation(language));
     if !filters.ip_version_valid() {
@@ -1,2 +1,4 @@
 pub mod formatted_strings;
+#[cfg(test)]
+pub mod test_captures;
 pub mod types;
@@ -0,0 +1,42 @@
+//! Module used by tests to read the capture files stored in `resources/test`.
+//!
+//! It only understands the classic PCAP format (microseconds resolution, little endian),
+//! which is enough for the fixtures and doesn't require libpcap to be installed.
+
+use std::fs;
+
+use pcap::Linktype;
+
+/// A capture file loaded in memory
+pub struct TestCapture {
+    /// Link type of the packets in the capture
+    pub link_type: Linktype,
+    /// Raw packets, each one with its timestamp expressed in microseconds
+    pub packets: Vec<(i64, Vec<u8>)>,
+}
+
+impl TestCapture {
+    /// Loads the capture file with the given name from the `resources/test` directory
+    pub fn load(file_name: &str) -> Self {
+        let path = format!("{}/resources/test/{file_name}", env!("CARGO_MANIFEST_DIR"));
+        let bytes = fs::read(path).unwrap();
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+
+        assert_eq!(read_u32(0), 0xa1b2_c3d4);
+        #[allow(clippy::cast_possible_wrap)]
+        let link_type = Linktype(read_u32(20) as i32);
+
+        let mut packets = Vec::new();
+        let mut offset = 24;
+        while offset + 16 <= bytes.len() {
+            let ts = i64::from(read_u32(offset)) * 1_000_000 + i64::from(read_u32(offset + 4));
+            let len = read_u32(offset + 8) as usize;
+            offset += 16;
+            packets.push((ts, bytes[offset..offset + len].to_vec()));
+            offset += len;
+        }
+
+        Self { link_type, packets }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::translations::translations_3::{
     database_from_file_translation, select_directory_translation, style_from_file_translation,
 };
+use crate::translations::translations_4::select_rules_file_translation;
 use crate::translations::types::language::Language;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -8,6 +9,7 @@ pub enum FileInfo {
     Style,
     Database,
     Directory,
+    Rules,
 }
 
 impl FileInfo {
@@ -16,6 +18,7 @@ pub fn get_extension(&self) -> &'static str {
             FileInfo::Style => "toml",
             FileInfo::Database => "mmdb",
             FileInfo::Directory => "",
+            FileInfo::Rules => "toml",
         }
     }
 
@@ -24,6 +27,7 @@ pub fn action_info(&self, language: Language) -> &'static str {
             FileInfo::Style => style_from_file_translation(language),
             FileInfo::Database => database_from_file_translation(language),
             FileInfo::Directory => select_directory_translation(language),
+            FileInfo::Rules => select_rules_file_translation(language),
         }
     }
 }